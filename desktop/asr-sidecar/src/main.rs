@@ -1,6 +1,8 @@
 use base64::Engine;
 use serde::{Deserialize, Serialize};
 use std::io::{self, BufRead, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{mpsc, Arc};
 use whisper_rs::{
     install_logging_hooks, FullParams, SamplingStrategy, WhisperContext, WhisperContextParameters,
     WhisperState,
@@ -12,6 +14,8 @@ const WHISPER_RS_DEP_VERSION: &str = "0.15";
 const REQUIRED_CAPABILITY_DECODE_F32LE: &str = "decode_window_f32le";
 const REQUIRED_CAPABILITY_PROGRESS_EVENTS: &str = "progress_events";
 const REQUIRED_CAPABILITY_MODE_SWITCH: &str = "mode_live_final";
+const CAPABILITY_WORD_TIMESTAMPS: &str = "word_timestamps";
+const CAPABILITY_CANCEL_DECODE: &str = "cancel_decode";
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -52,6 +56,11 @@ enum SidecarRequest {
         audio_b64: String,
         #[serde(default)]
         mode: Option<String>,
+        #[serde(default)]
+        word_timestamps: bool,
+    },
+    Cancel {
+        seq: u64,
     },
     Shutdown,
 }
@@ -80,6 +89,27 @@ struct SidecarSegment {
     t0_ms: i64,
     t1_ms: i64,
     text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confidence: Option<f32>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    words: Vec<SidecarWord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct SidecarWord {
+    t0_ms: i64,
+    t1_ms: i64,
+    text: String,
+    p: f32,
+}
+
+/// One decoded token, already shifted to absolute window time.
+#[derive(Debug, Clone)]
+struct TokenPiece {
+    text: String,
+    t0_ms: i64,
+    t1_ms: i64,
+    p: f32,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -94,19 +124,27 @@ struct SidecarState {
     state: Option<WhisperState>,
     language: Option<String>,
     sample_rate: u32,
+    /// Last decode `seq` the app asked to cancel; written by the stdin reader
+    /// while a decode runs.
+    cancelled_seq: Arc<AtomicU64>,
 }
 
 impl SidecarState {
-    fn new() -> Self {
+    fn new(cancelled_seq: Arc<AtomicU64>) -> Self {
         Self {
             initialized: false,
             context: None,
             state: None,
             language: None,
             sample_rate: 16_000,
+            cancelled_seq,
         }
     }
 
+    fn is_cancelled(&self, seq: u64) -> bool {
+        self.cancelled_seq.load(Ordering::SeqCst) == seq
+    }
+
     fn init(&mut self, model_path: &str, sample_rate: u32, language: &str) -> Result<(), String> {
         if sample_rate != 16_000 {
             return Err(format!("unsupported_sample_rate:{sample_rate}"));
@@ -137,6 +175,9 @@ impl SidecarState {
         if request.encoding != "f32le" {
             return Err(format!("unsupported_encoding:{}", request.encoding));
         }
+        if self.is_cancelled(request.seq) {
+            return Err("cancelled".to_string());
+        }
 
         let samples = decode_f32_audio(&request.audio_b64)?;
         if samples.is_empty() {
@@ -166,14 +207,22 @@ impl SidecarState {
             self.language.as_deref(),
             total_ms,
             request.seq,
+            request.word_timestamps,
+            self.cancelled_seq.clone(),
         );
         let state = self
             .state
             .as_mut()
             .ok_or_else(|| "state_missing".to_string())?;
-        state
-            .full(params, &samples)
-            .map_err(|e| format!("decode: {e}"))?;
+        let decoded = state.full(params, &samples);
+        if self.is_cancelled(request.seq) {
+            return Err("cancelled".to_string());
+        }
+        decoded.map_err(|e| format!("decode: {e}"))?;
+        let state = self
+            .state
+            .as_mut()
+            .ok_or_else(|| "state_missing".to_string())?;
 
         let mut segments = Vec::new();
         let window_start_ms = request.window_start_ms;
@@ -199,10 +248,30 @@ impl SidecarState {
             if text.is_empty() {
                 continue;
             }
+            let words = if request.word_timestamps {
+                let pieces: Vec<TokenPiece> = (0..segment.n_tokens())
+                    .filter_map(|idx| segment.get_token(idx))
+                    .filter_map(|token| {
+                        let data = token.token_data();
+                        let text = token.to_str_lossy().ok()?.into_owned();
+                        Some(TokenPiece {
+                            text,
+                            t0_ms: window_start_ms + data.t0 * 10,
+                            t1_ms: window_start_ms + data.t1 * 10,
+                            p: data.p,
+                        })
+                    })
+                    .collect();
+                group_words(&pieces, t0_ms, t1_ms)
+            } else {
+                Vec::new()
+            };
             segments.push(SidecarSegment {
                 t0_ms,
                 t1_ms,
                 text: text.to_string(),
+                confidence: mean_probability(&words),
+                words,
             });
         }
 
@@ -230,6 +299,7 @@ struct DecodeRequest {
     encoding: String,
     audio_b64: String,
     mode: DecodeMode,
+    word_timestamps: bool,
 }
 
 fn main() {
@@ -307,6 +377,8 @@ fn build_doctor_output() -> SidecarDoctorOutput {
             REQUIRED_CAPABILITY_DECODE_F32LE.to_string(),
             REQUIRED_CAPABILITY_PROGRESS_EVENTS.to_string(),
             REQUIRED_CAPABILITY_MODE_SWITCH.to_string(),
+            CAPABILITY_WORD_TIMESTAMPS.to_string(),
+            CAPABILITY_CANCEL_DECODE.to_string(),
         ],
         dependencies: SidecarDoctorDependencies {
             whisper_rs: WHISPER_RS_DEP_VERSION.to_string(),
@@ -331,39 +403,16 @@ fn target_triple() -> String {
 }
 
 fn run_server_loop() {
-    let stdin = io::stdin();
     let mut stdout = io::stdout();
-    let mut state = SidecarState::new();
-
-    for line in stdin.lock().lines() {
-        let line = match line {
-            Ok(line) => line,
-            Err(err) => {
-                emit(
-                    &mut stdout,
-                    SidecarResponse::Error {
-                        seq: None,
-                        message: format!("stdin_read: {err}"),
-                    },
-                );
-                break;
-            }
-        };
-        let trimmed = line.trim();
-        if trimmed.is_empty() {
-            continue;
-        }
+    let cancelled_seq = Arc::new(AtomicU64::new(0));
+    let mut state = SidecarState::new(cancelled_seq.clone());
+    let requests = spawn_stdin_reader(cancelled_seq);
 
-        let request: SidecarRequest = match serde_json::from_str(trimmed) {
+    for request in requests {
+        let request = match request {
             Ok(request) => request,
-            Err(err) => {
-                emit(
-                    &mut stdout,
-                    SidecarResponse::Error {
-                        seq: None,
-                        message: format!("parse: {err}"),
-                    },
-                );
+            Err(message) => {
+                emit(&mut stdout, SidecarResponse::Error { seq: None, message });
                 continue;
             }
         };
@@ -389,6 +438,7 @@ fn run_server_loop() {
                 encoding,
                 audio_b64,
                 mode,
+                word_timestamps,
             } => {
                 let mode = parse_mode(mode.as_deref());
                 let request = DecodeRequest {
@@ -399,6 +449,7 @@ fn run_server_loop() {
                     encoding,
                     audio_b64,
                     mode,
+                    word_timestamps,
                 };
                 match state.decode(request) {
                     Ok(segments) => emit(&mut stdout, SidecarResponse::Segments { seq, segments }),
@@ -411,6 +462,8 @@ fn run_server_loop() {
                     ),
                 };
             }
+            // Applied by the reader thread as soon as it arrives.
+            SidecarRequest::Cancel { .. } => {}
             SidecarRequest::Shutdown => {
                 break;
             }
@@ -418,6 +471,43 @@ fn run_server_loop() {
     }
 }
 
+/// Reads requests on a separate thread so a `cancel` reaches the running
+/// decode through `cancelled_seq` instead of waiting behind it.
+fn spawn_stdin_reader(
+    cancelled_seq: Arc<AtomicU64>,
+) -> mpsc::Receiver<Result<SidecarRequest, String>> {
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let stdin = io::stdin();
+        for line in stdin.lock().lines() {
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => {
+                    let _ = tx.send(Err(format!("stdin_read: {err}")));
+                    break;
+                }
+            };
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            let message = match serde_json::from_str(trimmed) {
+                Ok(SidecarRequest::Cancel { seq }) => {
+                    cancelled_seq.store(seq, Ordering::SeqCst);
+                    continue;
+                }
+                Ok(request) => Ok(request),
+                Err(err) => Err(format!("parse: {err}")),
+            };
+            let shutdown = matches!(message, Ok(SidecarRequest::Shutdown));
+            if tx.send(message).is_err() || shutdown {
+                break;
+            }
+        }
+    });
+    rx
+}
+
 fn parse_mode(mode: Option<&str>) -> DecodeMode {
     match mode {
         Some("final") => DecodeMode::Final,
@@ -457,6 +547,8 @@ fn build_params<'a>(
     language: Option<&'a str>,
     total_ms: i64,
     seq: u64,
+    word_timestamps: bool,
+    cancelled_seq: Arc<AtomicU64>,
 ) -> FullParams<'a, 'static> {
     let sampling = match mode {
         DecodeMode::Live => SamplingStrategy::Greedy { best_of: 1 },
@@ -475,12 +567,14 @@ fn build_params<'a>(
     params.set_print_realtime(false);
     params.set_print_timestamps(false);
     params.set_single_segment(false);
+    params.set_token_timestamps(word_timestamps);
 
     let threads = std::thread::available_parallelism()
         .map(|value| value.get() as i32)
         .unwrap_or(2)
         .clamp(1, 4);
     params.set_n_threads(threads);
+    params.set_abort_callback_safe(move || cancelled_seq.load(Ordering::SeqCst) == seq);
 
     if total_ms > 0 {
         params.set_progress_callback_safe(move |progress: i32| {
@@ -501,6 +595,56 @@ fn build_params<'a>(
     params
 }
 
+/// Merges subword tokens into words: a token starting with a space opens a
+/// new word, anything else (subwords, punctuation) extends the current one.
+/// Special tokens such as `[_BEG_]` or `<|endoftext|>` are dropped.
+fn group_words(pieces: &[TokenPiece], segment_t0_ms: i64, segment_t1_ms: i64) -> Vec<SidecarWord> {
+    let mut words: Vec<(SidecarWord, usize)> = Vec::new();
+    for piece in pieces {
+        if piece.text.starts_with("[_") || piece.text.starts_with("<|") {
+            continue;
+        }
+        let opens_word = piece.text.starts_with(char::is_whitespace);
+        let text = piece.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        let t0_ms = piece.t0_ms.clamp(segment_t0_ms, segment_t1_ms);
+        let t1_ms = piece.t1_ms.clamp(t0_ms, segment_t1_ms);
+        match words.last_mut() {
+            Some((word, count)) if !opens_word => {
+                word.text.push_str(text);
+                word.t1_ms = word.t1_ms.max(t1_ms);
+                word.p += piece.p;
+                *count += 1;
+            }
+            _ => words.push((
+                SidecarWord {
+                    t0_ms,
+                    t1_ms,
+                    text: text.to_string(),
+                    p: piece.p,
+                },
+                1,
+            )),
+        }
+    }
+    words
+        .into_iter()
+        .map(|(mut word, count)| {
+            word.p /= count as f32;
+            word
+        })
+        .collect()
+}
+
+fn mean_probability(words: &[SidecarWord]) -> Option<f32> {
+    if words.is_empty() {
+        return None;
+    }
+    Some(words.iter().map(|word| word.p).sum::<f32>() / words.len() as f32)
+}
+
 fn decode_f32_audio(encoded: &str) -> Result<Vec<f32>, String> {
     let bytes = base64::engine::general_purpose::STANDARD
         .decode(encoded)
@@ -562,6 +706,71 @@ mod tests {
         assert!(!doctor.target_triple.trim().is_empty());
     }
 
+    fn piece(text: &str, t0_ms: i64, t1_ms: i64, p: f32) -> TokenPiece {
+        TokenPiece {
+            text: text.to_string(),
+            t0_ms,
+            t1_ms,
+            p,
+        }
+    }
+
+    #[test]
+    fn tokens_group_into_timed_words() {
+        let pieces = [
+            piece("[_BEG_]", 1_000, 1_000, 1.0),
+            piece(" Hel", 1_000, 1_200, 0.9),
+            piece("lo", 1_200, 1_400, 0.7),
+            piece(",", 1_400, 1_400, 0.8),
+            piece(" world", 1_500, 1_900, 0.6),
+            piece(" again", 2_900, 3_400, 0.5),
+            piece("<|endoftext|>", 3_400, 3_400, 1.0),
+        ];
+        let words = group_words(&pieces, 1_000, 3_000);
+        assert_eq!(
+            words
+                .iter()
+                .map(|word| (word.text.as_str(), word.t0_ms, word.t1_ms))
+                .collect::<Vec<_>>(),
+            vec![
+                ("Hello,", 1_000, 1_400),
+                ("world", 1_500, 1_900),
+                ("again", 2_900, 3_000),
+            ]
+        );
+        assert!((words[0].p - 0.8).abs() < 1e-6);
+        assert!((mean_probability(&words).expect("confidence") - 0.6333).abs() < 1e-3);
+        assert_eq!(mean_probability(&[]), None);
+    }
+
+    #[test]
+    fn decode_request_word_timestamps_default_off() {
+        let request: SidecarRequest = serde_json::from_str(
+            r#"{"type":"decode","seq":1,"sample_rate":16000,"window_start_ms":0,"window_end_ms":10,"encoding":"f32le","audio_b64":""}"#,
+        )
+        .expect("parse");
+        assert!(matches!(
+            request,
+            SidecarRequest::Decode {
+                word_timestamps: false,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn cancel_request_targets_a_decode_seq() {
+        let request: SidecarRequest =
+            serde_json::from_str(r#"{"type":"cancel","seq":7}"#).expect("parse");
+        assert!(matches!(request, SidecarRequest::Cancel { seq: 7 }));
+
+        let state = SidecarState::new(Arc::new(AtomicU64::new(0)));
+        assert!(!state.is_cancelled(7));
+        state.cancelled_seq.store(7, Ordering::SeqCst);
+        assert!(state.is_cancelled(7));
+        assert!(!state.is_cancelled(8));
+    }
+
     #[test]
     fn decode_audio_round_trip() {
         let samples = [0.0f32, 0.5f32, -0.25f32];
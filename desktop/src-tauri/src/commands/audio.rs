@@ -11,9 +11,16 @@ use tauri::{Emitter, Manager, State};
 use crate::domain::asr;
 use crate::domain::asr::asr_live::LiveTranscriptState;
 use crate::domain::recorder;
+use crate::domain::recorder::drafts::{self, RecordingCheckpoint};
 use crate::domain::recorder::dsp;
-use crate::domain::recorder::recording::{LinearResampler, RingBuffer, WavWriter};
+use crate::domain::recorder::edit::{self as audio_edit_list, AudioEditSourcePayload};
+use crate::domain::recorder::import::{self, ImportTarget};
+use crate::domain::recorder::markers;
+use crate::domain::recorder::recording::{Resampler, ResamplerKind, RingBuffer, WavWriter};
+use crate::domain::recorder::storage::{self, AudioStorageFormat, RAW_AUDIO_ARTIFACT_TYPE};
 use crate::domain::recorder::vad::{VadConfig, VadState};
+use crate::domain::recorder::waveform;
+use crate::domain::run;
 use crate::kernel::models;
 use crate::platform::artifacts;
 use crate::platform::asr_sidecar;
@@ -31,6 +38,7 @@ const CLIPPING_REPEATED_FRAMES: u32 = 3;
 const CLIPPING_RELEASE_FRAMES: u32 = 8;
 const NOISY_ROOM_LEVEL_THRESHOLD: f32 = 0.03;
 const NOISY_ROOM_THRESHOLD_MS: u32 = 1800;
+const CHECKPOINT_INTERVAL_MS: u64 = 2000;
 
 const LIVE_WINDOW_MS: i64 = 12_000;
 const LIVE_STEP_MS: u64 = 800;
@@ -45,6 +53,7 @@ const ASR_SLOW_LOG_COOLDOWN_MS: u64 = 5000;
 const ASR_PARTIAL_EVENT: &str = "asr/partial/v1";
 const ASR_COMMIT_EVENT: &str = "asr/commit/v1";
 const RECORDING_TELEMETRY_EVENT: &str = "recording/telemetry/v1";
+const AUDIO_STORAGE_MIGRATION_PROGRESS_EVENT: &str = "audio/storage_migration_progress/v1";
 const RECORDING_TELEMETRY_STEP_MS: u64 = 200;
 const RECORDING_TELEMETRY_MAX_EVENT_RATE_HZ: f32 = 8.0;
 const RECORDING_TELEMETRY_MAX_PAYLOAD_BYTES: usize = 4096;
@@ -96,13 +105,85 @@ pub struct RecordingStopResult {
     pub bytes: u64,
     pub sha256: String,
     pub duration_ms: i64,
+    pub raw_artifact_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioStorageMigrationResult {
+    pub format: String,
+    pub converted: usize,
+    pub skipped: usize,
+    pub failed: Vec<String>,
+    pub bytes_before: u64,
+    pub bytes_after: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct AudioStorageMigrationProgressEvent {
+    pub schema_version: String,
+    pub profile_id: String,
+    pub processed: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioEditResult {
+    pub path: String,
+    pub artifact_id: String,
+    pub bytes: u64,
+    pub sha256: String,
+    pub duration_ms: i64,
+    pub transcript_id: Option<String>,
+    pub run_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioWaveformPeaksResult {
+    pub audio_artifact_id: String,
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub duration_ms: i64,
+    pub bin_ms: f64,
+    pub peaks: Vec<f32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioImportResult {
+    pub artifact_id: String,
+    pub path: String,
+    pub bytes: u64,
+    pub sha256: String,
+    pub duration_ms: i64,
+    pub source_format: String,
+    pub run_id: Option<String>,
+    pub attempt_id: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingDraft {
+    pub artifact_id: String,
+    pub path: String,
+    pub bytes: u64,
+    pub duration_ms: i64,
+    pub recording_id: Option<String>,
+    pub started_at: Option<String>,
+    pub updated_at: Option<String>,
 }
 
 struct RecordingState {
     writer: Option<WavWriter>,
-    resampler: LinearResampler,
+    raw_writer: Option<WavWriter>,
+    resampler: Resampler,
     ring: RingBuffer,
-    agc: dsp::Agc,
+    chain: dsp::DspChain,
+    // Normalizes what VAD and live ASR see; never written to disk.
+    detection_agc: dsp::Agc,
     vad: VadState,
     vad_config: VadConfig,
     last_vad: bool,
@@ -118,6 +199,7 @@ struct RecordingState {
     last_error: Option<String>,
     is_stopping: bool,
     scratch: Vec<f32>,
+    markers: Vec<models::RecordingMarker>,
 }
 
 #[derive(Debug, Serialize, Clone)]
@@ -169,6 +251,9 @@ struct RecordingController {
     recording_id: String,
     profile_id: String,
     draft: artifacts::ArtifactDraft,
+    raw_draft: Option<artifacts::ArtifactDraft>,
+    dsp_config: dsp::DspChainConfig,
+    resampler_kind: ResamplerKind,
     state: Arc<Mutex<RecordingState>>,
     command_tx: mpsc::Sender<RecordingCommand>,
     thread: thread::JoinHandle<()>,
@@ -188,12 +273,29 @@ struct RecordingTelemetryHandle {
 
 pub struct RecordingManager {
     session: Mutex<Option<RecordingController>>,
+    /// Drafts of stopped takes whose artifact is still being written.
+    finalizing: Mutex<Vec<String>>,
 }
 
 impl Default for RecordingManager {
     fn default() -> Self {
         Self {
             session: Mutex::new(None),
+            finalizing: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+/// Keeps a stopped take out of the orphan list until its artifact row exists.
+struct FinalizingDraft<'a> {
+    manager: &'a RecordingManager,
+    artifact_id: String,
+}
+
+impl Drop for FinalizingDraft<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut finalizing) = self.manager.finalizing.lock() {
+            finalizing.retain(|id| id != &self.artifact_id);
         }
     }
 }
@@ -206,6 +308,63 @@ impl RecordingManager {
             .map_err(|_| "recording_lock".to_string())?;
         Ok(guard.is_some())
     }
+
+    /// Drops a labelled marker at the current position of the active take;
+    /// paused time is not counted, so markers line up with the audio.
+    pub fn mark(
+        &self,
+        recording_id: &str,
+        label: Option<&str>,
+    ) -> Result<models::RecordingMarker, String> {
+        let label = markers::normalize_marker_label(label)?;
+        let guard = self
+            .session
+            .lock()
+            .map_err(|_| "recording_lock".to_string())?;
+        let session = guard
+            .as_ref()
+            .ok_or_else(|| "recording_missing".to_string())?;
+        if session.recording_id != recording_id {
+            return Err("recording_id_mismatch".to_string());
+        }
+        let mut state = session.state.lock().map_err(|_| "recording_lock")?;
+        if state.markers.len() >= markers::MAX_MARKERS {
+            return Err("recording_markers_full".to_string());
+        }
+        let marker = models::RecordingMarker {
+            t_ms: (state.total_samples as f64 / TARGET_SAMPLE_RATE as f64 * 1000.0).round() as i64,
+            label,
+        };
+        state.markers.push(marker.clone());
+        Ok(marker)
+    }
+
+    /// Drafts still written by this process: the active take and any take
+    /// being finalized.
+    fn busy_draft_ids(&self) -> Result<Vec<String>, String> {
+        let guard = self
+            .session
+            .lock()
+            .map_err(|_| "recording_lock".to_string())?;
+        let mut ids = self
+            .finalizing
+            .lock()
+            .map_err(|_| "recording_lock".to_string())?
+            .clone();
+        ids.extend(guard.as_ref().map(|session| session.draft.id.clone()));
+        Ok(ids)
+    }
+
+    fn mark_finalizing(&self, artifact_id: &str) -> Result<FinalizingDraft<'_>, String> {
+        self.finalizing
+            .lock()
+            .map_err(|_| "recording_lock".to_string())?
+            .push(artifact_id.to_string());
+        Ok(FinalizingDraft {
+            manager: self,
+            artifact_id: artifact_id.to_string(),
+        })
+    }
 }
 
 fn restore_recording_session(
@@ -230,9 +389,14 @@ pub fn recording_start(
     profile_id: String,
     asr_settings: Option<asr::RecordingAsrSettingsPayload>,
     input_device_id: Option<String>,
+    dsp_settings: Option<dsp::RecordingDspSettingsPayload>,
 ) -> Result<RecordingStartResult, String> {
     db::ensure_profile_exists(&app, &profile_id)?;
-    let asr_settings = asr::normalize_recording_settings(asr_settings);
+    let asr_settings =
+        asr::normalize_recording_settings(asr_settings, &asr::asr_models::model_specs(&app)?);
+    let resampler_kind = dsp::normalize_resampler_kind(dsp_settings.as_ref());
+    let dsp_config = dsp::normalize_dsp_settings(dsp_settings.clone())?;
+    let keep_raw = dsp::normalize_keep_raw(dsp_settings.as_ref(), dsp_config);
 
     let mut guard = state.session.lock().map_err(|_| "recording_lock")?;
     if guard.is_some() {
@@ -241,15 +405,34 @@ pub fn recording_start(
 
     let draft = artifacts::create_draft(&app, &profile_id, "audio", "wav")?;
     let draft_path = draft.abspath.clone();
+    let raw_draft = if keep_raw {
+        Some(artifacts::create_draft(
+            &app,
+            &profile_id,
+            RAW_AUDIO_ARTIFACT_TYPE,
+            "wav",
+        )?)
+    } else {
+        None
+    };
+    let raw_draft_path = raw_draft.as_ref().map(|draft| draft.abspath.clone());
+    let recording_id = crate::kernel::ids::new_id("rec");
+    let profile_dir = db::profile_dir(&app, &profile_id)?;
+    let mut checkpoint = RecordingCheckpoint::new(&recording_id, &draft.id, TARGET_SAMPLE_RATE, 1);
+    checkpoint.raw_artifact_id = raw_draft.as_ref().map(|raw_draft| raw_draft.id.clone());
+    drafts::write_checkpoint(&profile_dir, &checkpoint)?;
+    let checkpoint_dir = profile_dir.clone();
 
     let (cmd_tx, cmd_rx) = mpsc::channel::<RecordingCommand>();
     let (start_tx, start_rx) = mpsc::channel::<Result<RecordingStartInfo, String>>();
 
     let state = Arc::new(Mutex::new(RecordingState {
         writer: None,
-        resampler: LinearResampler::new(TARGET_SAMPLE_RATE, TARGET_SAMPLE_RATE),
+        raw_writer: None,
+        resampler: Resampler::new(resampler_kind, TARGET_SAMPLE_RATE, TARGET_SAMPLE_RATE),
         ring: RingBuffer::new((TARGET_SAMPLE_RATE * RING_SECONDS) as usize),
-        agc: dsp::Agc::new(0.1, 0.5, 8.0, 0.2),
+        chain: dsp::DspChain::new(dsp_config, TARGET_SAMPLE_RATE),
+        detection_agc: dsp::Agc::new(0.1, 0.5, 8.0, 0.2),
         vad: VadState::default(),
         vad_config: VadConfig::balanced(),
         last_vad: false,
@@ -265,6 +448,7 @@ pub fn recording_start(
         last_error: None,
         is_stopping: false,
         scratch: Vec::with_capacity(4096),
+        markers: Vec::new(),
     }));
 
     let state_clone = state.clone();
@@ -272,6 +456,8 @@ pub fn recording_start(
     let thread = thread::spawn(move || {
         if let Err(err) = run_recording_thread(
             draft_path,
+            raw_draft_path,
+            (checkpoint_dir, checkpoint),
             state_clone,
             cmd_rx,
             start_tx,
@@ -287,6 +473,8 @@ pub fn recording_start(
         Ok(Err(err)) => {
             let _ = thread.join();
             let _ = std::fs::remove_file(&draft.abspath);
+            remove_raw_draft(raw_draft.as_ref());
+            let _ = drafts::remove_checkpoint(&profile_dir, &draft.id);
             return Err(err);
         }
         Err(_) => {
@@ -296,12 +484,12 @@ pub fn recording_start(
             });
             let _ = thread.join();
             let _ = std::fs::remove_file(&draft.abspath);
+            remove_raw_draft(raw_draft.as_ref());
+            let _ = drafts::remove_checkpoint(&profile_dir, &draft.id);
             return Err("recording_start_timeout".to_string());
         }
     };
 
-    let recording_id = crate::kernel::ids::new_id("rec");
-
     let (live_tx, live_rx) = mpsc::channel::<()>();
     let live_state = state.clone();
     let live_app = app.clone();
@@ -321,6 +509,9 @@ pub fn recording_start(
         recording_id: recording_id.clone(),
         profile_id: profile_id.clone(),
         draft,
+        raw_draft,
+        dsp_config,
+        resampler_kind,
         state,
         command_tx: cmd_tx,
         thread,
@@ -420,7 +611,7 @@ pub fn recording_stop(
 ) -> Result<RecordingStopResult, String> {
     db::ensure_profile_exists(&app, &profile_id)?;
 
-    let session = {
+    let (session, _finalizing) = {
         let mut guard = state.session.lock().map_err(|_| "recording_lock")?;
         let session = guard
             .take()
@@ -433,7 +624,10 @@ pub fn recording_stop(
             *guard = Some(session);
             return Err("recording_profile_mismatch".to_string());
         }
-        session
+        // Marked before the session lock is released so listing never sees
+        // the draft as unowned.
+        let finalizing = state.mark_finalizing(&session.draft.id)?;
+        (session, finalizing)
     };
 
     let (reply_tx, reply_rx) = mpsc::channel::<Result<RecordingStopInfo, String>>();
@@ -476,13 +670,43 @@ pub fn recording_stop(
     let _ = session.telemetry.thread.join();
     let _ = session.thread.join();
 
-    let metadata = serde_json::json!({
-        "format": "wav",
-        "sample_rate_hz": TARGET_SAMPLE_RATE,
-        "channels": 1
-    });
-
-    let record = artifacts::finalize_draft(&app, &profile_id, session.draft, &metadata)?;
+    let markers = session
+        .state
+        .lock()
+        .map(|state| state.markers.clone())
+        .unwrap_or_default();
+    let format = storage::load_storage_format(&app, &profile_id)?;
+    // A failure leaves both drafts and the checkpoint for recovery.
+    let raw_artifact_id = match session.raw_draft {
+        Some(raw_draft) => {
+            let raw_metadata = storage::audio_metadata(
+                format,
+                &serde_json::json!({ "raw": true, "processed_artifact_id": session.draft.id }),
+            );
+            let raw_draft = storage::compress_draft(&app, &profile_id, raw_draft, format)?;
+            let record = artifacts::finalize_draft(&app, &profile_id, raw_draft, &raw_metadata)?;
+            Some(record.id)
+        }
+        None => None,
+    };
+    let metadata = storage::audio_metadata(
+        format,
+        &serde_json::json!({
+            "dsp": session.dsp_config.to_metadata(),
+            "resampler": session.resampler_kind.code(),
+            "raw_artifact_id": raw_artifact_id,
+            "markers": markers,
+        }),
+    );
+
+    let draft_id = session.draft.id.clone();
+    let draft = storage::compress_draft(&app, &profile_id, session.draft, format)?;
+    let record = artifacts::finalize_draft(&app, &profile_id, draft, &metadata)?;
+    let profile_dir = db::profile_dir(&app, &profile_id)?;
+    if let Err(err) = drafts::remove_checkpoint(&profile_dir, &draft_id) {
+        eprintln!("recording checkpoint cleanup error: {err}");
+    }
+    waveform::index_waveform_peaks(&app, &profile_id, &record.id);
 
     Ok(RecordingStopResult {
         path: record.abspath.to_string_lossy().to_string(),
@@ -490,9 +714,87 @@ pub fn recording_stop(
         bytes: record.bytes,
         sha256: record.sha256,
         duration_ms: stop_info.duration_ms,
+        raw_artifact_id,
     })
 }
 
+#[tauri::command]
+pub fn recording_drafts_list(
+    app: tauri::AppHandle,
+    state: State<RecordingManager>,
+    profile_id: String,
+) -> Result<Vec<RecordingDraft>, String> {
+    let busy_draft_ids = state.busy_draft_ids()?;
+    Ok(
+        drafts::list_orphan_drafts(&app, &profile_id, &busy_draft_ids)?
+            .into_iter()
+            .map(|draft| RecordingDraft {
+                artifact_id: draft.artifact_id,
+                path: draft.path.to_string_lossy().to_string(),
+                bytes: draft.bytes,
+                duration_ms: draft.duration_ms,
+                recording_id: draft.recording_id,
+                started_at: draft.started_at,
+                updated_at: draft.updated_at,
+            })
+            .collect(),
+    )
+}
+
+#[tauri::command]
+pub fn recording_draft_recover(
+    app: tauri::AppHandle,
+    state: State<RecordingManager>,
+    profile_id: String,
+    artifact_id: String,
+) -> Result<RecordingStopResult, String> {
+    let busy_draft_ids = state.busy_draft_ids()?;
+    let recovered = drafts::recover_orphan_draft(&app, &profile_id, &artifact_id, &busy_draft_ids)?;
+    waveform::index_waveform_peaks(&app, &profile_id, &recovered.artifact_id);
+    Ok(RecordingStopResult {
+        path: recovered.path.to_string_lossy().to_string(),
+        artifact_id: recovered.artifact_id,
+        bytes: recovered.bytes,
+        sha256: recovered.sha256,
+        duration_ms: recovered.duration_ms,
+        raw_artifact_id: recovered.raw_artifact_id,
+    })
+}
+
+#[tauri::command]
+pub fn recording_draft_discard(
+    app: tauri::AppHandle,
+    state: State<RecordingManager>,
+    profile_id: String,
+    artifact_id: String,
+) -> Result<(), String> {
+    let busy_draft_ids = state.busy_draft_ids()?;
+    drafts::discard_orphan_draft(&app, &profile_id, &artifact_id, &busy_draft_ids)
+}
+
+/// Called once at startup: nothing is recording yet, so every checkpointed
+/// draft without an artifact is left over from a crash.
+pub fn detect_orphan_drafts(app: &tauri::AppHandle) {
+    let profile_ids = match crate::domain::workspace::profile_ids(app) {
+        Ok(profiles) => profiles,
+        Err(err) => {
+            eprintln!("recording drafts not checked: {err}");
+            return;
+        }
+    };
+    for profile_id in profile_ids {
+        match drafts::list_orphan_drafts(app, &profile_id, &[]) {
+            Ok(found) if found.is_empty() => {}
+            Ok(found) => eprintln!(
+                "found {} recoverable recording draft(s) in profile {}",
+                found.len(),
+                profile_id
+            ),
+            Err(err) => eprintln!("recording drafts not checked for {profile_id}: {err}"),
+        }
+    }
+}
+
 #[tauri::command]
 pub fn recording_input_devices() -> Result<Vec<RecordingInputDevice>, String> {
     Ok(recorder::list_input_devices()?
@@ -520,6 +822,8 @@ pub fn recording_telemetry_budget() -> RecordingTelemetryBudgetResult {
 
 fn run_recording_thread(
     draft_path: PathBuf,
+    raw_draft_path: Option<PathBuf>,
+    (checkpoint_dir, mut checkpoint): (PathBuf, RecordingCheckpoint),
     state: Arc<Mutex<RecordingState>>,
     command_rx: mpsc::Receiver<RecordingCommand>,
     start_tx: mpsc::Sender<Result<RecordingStartInfo, String>>,
@@ -535,11 +839,19 @@ fn run_recording_thread(
     let input_channels = stream_config.channels;
 
     let writer = WavWriter::create(&draft_path, TARGET_SAMPLE_RATE, 1)?;
+    let raw_writer = raw_draft_path
+        .map(|path| WavWriter::create(&path, TARGET_SAMPLE_RATE, 1))
+        .transpose()?;
 
     {
         let mut guard = state.lock().map_err(|_| "recording_lock")?;
         guard.writer = Some(writer);
-        guard.resampler = LinearResampler::new(input_sample_rate, TARGET_SAMPLE_RATE);
+        guard.raw_writer = raw_writer;
+        guard.resampler = Resampler::new(
+            guard.resampler.kind(),
+            input_sample_rate,
+            TARGET_SAMPLE_RATE,
+        );
     }
 
     let state_clone = state.clone();
@@ -580,54 +892,105 @@ fn run_recording_thread(
         input_channels,
     }));
 
-    match command_rx.recv() {
-        Ok(RecordingCommand::Stop { respond_to }) => {
-            let mut error = None;
-            let duration_ms;
-
-            {
-                let mut guard = state.lock().map_err(|_| "recording_lock")?;
-                guard.is_stopping = true;
-                duration_ms = (guard.total_samples as f64 / TARGET_SAMPLE_RATE as f64 * 1000.0)
-                    .round() as i64;
-                if let Some(err) = guard.last_error.clone() {
-                    error = Some(err);
+    let respond_to = loop {
+        match command_rx.recv_timeout(Duration::from_millis(CHECKPOINT_INTERVAL_MS)) {
+            Ok(RecordingCommand::Stop { respond_to }) => break respond_to,
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                if let Err(err) = checkpoint_recording(&state, &checkpoint_dir, &mut checkpoint) {
+                    eprintln!("recording checkpoint error: {err}");
                 }
             }
+            Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+    };
 
-            drop(stream);
+    let mut error = None;
+    let duration_ms;
 
-            let writer = {
-                let mut guard = state.lock().map_err(|_| "recording_lock")?;
-                guard.writer.take()
-            };
+    {
+        let mut guard = state.lock().map_err(|_| "recording_lock")?;
+        guard.is_stopping = true;
+        duration_ms =
+            (guard.total_samples as f64 / TARGET_SAMPLE_RATE as f64 * 1000.0).round() as i64;
+        if let Some(err) = guard.last_error.clone() {
+            error = Some(err);
+        }
+    }
 
-            if let Some(err) = error {
-                let _ = respond_to.send(Err(err));
-                return Ok(());
-            }
+    drop(stream);
 
-            let writer = match writer {
-                Some(writer) => writer,
-                None => {
-                    let _ = respond_to.send(Err("recording_writer_missing".to_string()));
-                    return Ok(());
-                }
-            };
+    let (writer, raw_writer) = {
+        let mut guard = state.lock().map_err(|_| "recording_lock")?;
+        (guard.writer.take(), guard.raw_writer.take())
+    };
 
-            if let Err(err) = writer.finalize() {
-                let _ = respond_to.send(Err(err));
-                return Ok(());
-            }
+    if let Some(err) = error {
+        let _ = respond_to.send(Err(err));
+        return Ok(());
+    }
 
-            let _ = respond_to.send(Ok(RecordingStopInfo { duration_ms }));
+    let writer = match writer {
+        Some(writer) => writer,
+        None => {
+            let _ = respond_to.send(Err("recording_writer_missing".to_string()));
+            return Ok(());
+        }
+    };
+
+    if let Err(err) = writer.finalize() {
+        let _ = respond_to.send(Err(err));
+        return Ok(());
+    }
+    if let Some(raw_writer) = raw_writer {
+        if let Err(err) = raw_writer.finalize() {
+            let _ = respond_to.send(Err(err));
+            return Ok(());
         }
-        Err(_) => return Ok(()),
     }
 
+    let _ = respond_to.send(Ok(RecordingStopInfo { duration_ms }));
     Ok(())
 }
 
+#[tauri::command]
+pub fn recording_mark(
+    state: State<RecordingManager>,
+    recording_id: String,
+    label: Option<String>,
+) -> Result<models::RecordingMarker, String> {
+    // Same shape as the markers stored in audio metadata, transcripts and packs.
+    state.mark(&recording_id, label.as_deref())
+}
+
+fn remove_raw_draft(raw_draft: Option<&artifacts::ArtifactDraft>) {
+    if let Some(raw_draft) = raw_draft {
+        let _ = std::fs::remove_file(&raw_draft.abspath);
+    }
+}
+
+fn checkpoint_recording(
+    state: &Arc<Mutex<RecordingState>>,
+    checkpoint_dir: &Path,
+    checkpoint: &mut RecordingCheckpoint,
+) -> Result<(), String> {
+    let (data_bytes, markers) = {
+        let mut guard = state.lock().map_err(|_| "recording_lock")?;
+        if let Some(raw_writer) = guard.raw_writer.as_mut() {
+            raw_writer.checkpoint()?;
+        }
+        let data_bytes = match guard.writer.as_mut() {
+            Some(writer) => {
+                writer.checkpoint()?;
+                writer.data_bytes()
+            }
+            None => return Ok(()),
+        };
+        (data_bytes, guard.markers.clone())
+    };
+    checkpoint.advance(data_bytes, &markers);
+    drafts::write_checkpoint(checkpoint_dir, checkpoint)
+}
+
 fn run_live_asr(
     app: tauri::AppHandle,
     state: Arc<Mutex<RecordingState>>,
@@ -1001,6 +1364,16 @@ where
 
     guard.total_samples += processed.len() as u64;
 
+    if let Some(raw_writer) = guard.raw_writer.as_mut() {
+        if let Err(err) = raw_writer.write_samples(&processed) {
+            guard.last_error = Some(err);
+            guard.is_stopping = true;
+            return;
+        }
+    }
+
+    guard.chain.process(&mut processed);
+
     if let Some(writer) = guard.writer.as_mut() {
         if let Err(err) = writer.write_samples(&processed) {
             guard.last_error = Some(err);
@@ -1009,7 +1382,7 @@ where
         }
     }
 
-    guard.agc.process(&mut processed);
+    guard.detection_agc.process(&mut processed);
 
     let frame_ms = ((processed.len() as f32 / TARGET_SAMPLE_RATE as f32) * 1000.0).round() as u32;
     if frame_ms > 0 {
@@ -1078,7 +1451,7 @@ pub fn audio_trim_wav(
     let profile_dir = db::profile_dir(&app, &profile_id)?;
     let source_path = profile_dir.join(&artifact.relpath);
     let source_bytes = std::fs::read(&source_path).map_err(|e| format!("audio_read: {e}"))?;
-    let source_samples = recorder::decode_wav_pcm16_mono_16k(&source_bytes)?;
+    let source_samples = recorder::decode_audio_pcm16_mono_16k(&source_bytes)?;
     let (start_idx, end_idx, start_ms, end_ms) = recorder::resolve_trim_sample_range(
         source_samples.len(),
         start_ms,
@@ -1086,21 +1459,34 @@ pub fn audio_trim_wav(
         TARGET_SAMPLE_RATE,
     )?;
     let trimmed_samples = &source_samples[start_idx..end_idx];
-    let trimmed_bytes = recorder::encode_wav_pcm16_mono(TARGET_SAMPLE_RATE, 1, trimmed_samples)?;
+    let format = storage::load_storage_format(&app, &profile_id)?;
+    let trimmed_bytes = format.encode(trimmed_samples)?;
     let duration_ms =
         recorder::duration_ms_from_sample_count(trimmed_samples.len(), TARGET_SAMPLE_RATE);
 
-    let metadata = serde_json::json!({
-        "format": "wav",
-        "sample_rate_hz": TARGET_SAMPLE_RATE,
-        "channels": 1,
-        "source_audio_artifact_id": audio_artifact_id,
-        "trim_kind": "range_ms",
-        "trim_start_ms": start_ms,
-        "trim_end_ms": end_ms,
-    });
-    let record =
-        artifacts::store_bytes(&app, &profile_id, "audio", "wav", &trimmed_bytes, &metadata)?;
+    let metadata = storage::audio_metadata(
+        format,
+        &serde_json::json!({
+            "source_audio_artifact_id": audio_artifact_id,
+            "trim_kind": "range_ms",
+            "trim_start_ms": start_ms,
+            "trim_end_ms": end_ms,
+            "markers": markers::trim_markers(
+                &markers::audio_markers(&app, &profile_id, &audio_artifact_id),
+                start_ms,
+                end_ms,
+            ),
+        }),
+    );
+    let record = artifacts::store_bytes(
+        &app,
+        &profile_id,
+        "audio",
+        format.code(),
+        &trimmed_bytes,
+        &metadata,
+    )?;
+    waveform::index_waveform_peaks(&app, &profile_id, &record.id);
 
     Ok(RecordingStopResult {
         path: record.abspath.to_string_lossy().to_string(),
@@ -1108,6 +1494,149 @@ pub fn audio_trim_wav(
         bytes: record.bytes,
         sha256: record.sha256,
         duration_ms,
+        raw_artifact_id: None,
+    })
+}
+
+#[tauri::command]
+pub fn audio_edit(
+    app: tauri::AppHandle,
+    profile_id: String,
+    sources: Vec<AudioEditSourcePayload>,
+) -> Result<AudioEditResult, String> {
+    let edited = audio_edit_list::apply_edit_list(&app, &profile_id, &sources)?;
+    let source_ids: Vec<&str> = sources
+        .iter()
+        .map(|source| source.audio_artifact_id.as_str())
+        .collect();
+    let created = run::run_create_from_edit(
+        &app,
+        &profile_id,
+        &source_ids,
+        &edited.artifact_id,
+        edited.transcript_id.as_deref(),
+    );
+    let run_id = match created {
+        Ok(run_id) => run_id,
+        Err(err) => {
+            let mut orphans = vec![edited.artifact_id.as_str()];
+            orphans.extend(edited.transcript_id.as_deref());
+            if let Err(cleanup) = artifacts::delete_artifacts(&app, &profile_id, &orphans) {
+                return Err(format!("{err}; {cleanup}"));
+            }
+            return Err(err);
+        }
+    };
+    waveform::index_waveform_peaks(&app, &profile_id, &edited.artifact_id);
+    Ok(AudioEditResult {
+        path: edited.path.to_string_lossy().to_string(),
+        artifact_id: edited.artifact_id,
+        bytes: edited.bytes,
+        sha256: edited.sha256,
+        duration_ms: edited.duration_ms,
+        transcript_id: edited.transcript_id,
+        run_id,
+    })
+}
+
+#[tauri::command]
+pub fn audio_waveform_peaks(
+    app: tauri::AppHandle,
+    profile_id: String,
+    audio_artifact_id: String,
+    start_ms: Option<i64>,
+    end_ms: Option<i64>,
+    bins: usize,
+) -> Result<AudioWaveformPeaksResult, String> {
+    db::ensure_profile_exists(&app, &profile_id)?;
+    let peaks = waveform::ensure_waveform_peaks(&app, &profile_id, &audio_artifact_id)?;
+    let range = waveform::waveform_range(&peaks, start_ms, end_ms, bins)?;
+    Ok(AudioWaveformPeaksResult {
+        audio_artifact_id,
+        start_ms: range.start_ms,
+        end_ms: range.end_ms,
+        duration_ms: range.duration_ms,
+        bin_ms: range.bin_ms,
+        peaks: range.peaks,
+    })
+}
+
+#[tauri::command]
+pub async fn audio_storage_migrate(
+    app: tauri::AppHandle,
+    profile_id: String,
+    format: Option<String>,
+) -> Result<AudioStorageMigrationResult, String> {
+    let format = match format {
+        Some(code) => AudioStorageFormat::from_code(&code)
+            .ok_or_else(|| "audio_format_invalid".to_string())?,
+        None => AudioStorageFormat::Flac,
+    };
+    crate::commands::assert_valid_event_name(AUDIO_STORAGE_MIGRATION_PROGRESS_EVENT);
+    let summary = tauri::async_runtime::spawn_blocking(move || {
+        storage::migrate_audio_artifacts(&app, &profile_id, format, |processed, total| {
+            let _ = app.emit(
+                AUDIO_STORAGE_MIGRATION_PROGRESS_EVENT,
+                AudioStorageMigrationProgressEvent {
+                    schema_version: "1.0.0".to_string(),
+                    profile_id: profile_id.clone(),
+                    processed,
+                    total,
+                },
+            );
+        })
+    })
+    .await
+    .map_err(|e| format!("audio_migration_join: {e}"))??;
+    Ok(AudioStorageMigrationResult {
+        format: format.code().to_string(),
+        converted: summary.converted,
+        skipped: summary.skipped,
+        failed: summary.failed,
+        bytes_before: summary.bytes_before,
+        bytes_after: summary.bytes_after,
+    })
+}
+
+#[tauri::command]
+pub async fn audio_import(
+    app: tauri::AppHandle,
+    profile_id: String,
+    path: String,
+    project_id: String,
+    quest_code: Option<String>,
+    resampler: Option<String>,
+) -> Result<AudioImportResult, String> {
+    let resampler = match resampler {
+        Some(code) => {
+            ResamplerKind::from_code(&code).ok_or_else(|| "audio_resampler_invalid".to_string())?
+        }
+        None => ResamplerKind::Sinc,
+    };
+    let target = match quest_code {
+        Some(quest_code) => ImportTarget::QuestAttempt {
+            project_id,
+            quest_code,
+        },
+        None => ImportTarget::Run { project_id },
+    };
+    let imported = tauri::async_runtime::spawn_blocking(move || {
+        let imported =
+            import::import_audio_file(&app, &profile_id, Path::new(&path), target, resampler)?;
+        waveform::index_waveform_peaks(&app, &profile_id, &imported.artifact_id);
+        Ok::<_, String>(imported)
+    })
+    .await
+    .map_err(|e| format!("audio_import_join: {e}"))??;
+    Ok(AudioImportResult {
+        artifact_id: imported.artifact_id,
+        path: imported.path.to_string_lossy().to_string(),
+        bytes: imported.bytes,
+        sha256: imported.sha256,
+        duration_ms: imported.duration_ms,
+        source_format: imported.source_format.code().to_string(),
+        run_id: imported.run_id,
+        attempt_id: imported.attempt_id,
     })
 }
 
@@ -1206,9 +1735,15 @@ mod audio_tests {
     fn base_state() -> RecordingState {
         RecordingState {
             writer: None,
-            resampler: LinearResampler::new(TARGET_SAMPLE_RATE, TARGET_SAMPLE_RATE),
+            raw_writer: None,
+            resampler: Resampler::new(
+                ResamplerKind::Linear,
+                TARGET_SAMPLE_RATE,
+                TARGET_SAMPLE_RATE,
+            ),
             ring: RingBuffer::new(256),
-            agc: dsp::Agc::new(0.1, 0.5, 8.0, 0.2),
+            chain: dsp::DspChain::new(dsp::DspChainConfig::voice(), TARGET_SAMPLE_RATE),
+            detection_agc: dsp::Agc::new(0.1, 0.5, 8.0, 0.2),
             vad: VadState::default(),
             vad_config: VadConfig::balanced(),
             last_vad: false,
@@ -1224,6 +1759,7 @@ mod audio_tests {
             last_error: None,
             is_stopping: false,
             scratch: Vec::new(),
+            markers: Vec::new(),
         }
     }
 
@@ -1,7 +1,10 @@
 use crate::domain::feedback as feedback_core;
 use crate::kernel::models;
 
-pub use feedback_core::{AnalyzeResponse, FeedbackContext, FeedbackTimelineItem};
+pub use feedback_core::{
+    AnalyzeResponse, FeedbackAnalyzerStatus, FeedbackComparison, FeedbackContext,
+    FeedbackLexiconTerm, FeedbackRevision, FeedbackRevisionDiff, FeedbackTimelineItem,
+};
 
 #[tauri::command]
 pub fn feedback_timeline_list(
@@ -31,6 +34,24 @@ pub fn feedback_get(
     feedback_core::feedback_get(&app, &profile_id, &feedback_id)
 }
 
+#[tauri::command]
+pub fn feedback_analyzers_list(
+    app: tauri::AppHandle,
+    profile_id: String,
+) -> Result<Vec<FeedbackAnalyzerStatus>, String> {
+    feedback_core::feedback_analyzers_list(&app, &profile_id)
+}
+
+#[tauri::command]
+pub fn feedback_compare(
+    app: tauri::AppHandle,
+    profile_id: String,
+    subject_type: String,
+    subject_ids: Vec<String>,
+) -> Result<FeedbackComparison, String> {
+    feedback_core::feedback_compare(&app, &profile_id, &subject_type, &subject_ids)
+}
+
 #[tauri::command]
 pub fn feedback_context_get(
     app: tauri::AppHandle,
@@ -40,6 +61,47 @@ pub fn feedback_context_get(
     feedback_core::feedback_context_get(&app, &profile_id, &feedback_id)
 }
 
+#[tauri::command]
+pub fn feedback_lexicon_list(
+    app: tauri::AppHandle,
+    profile_id: String,
+    language: Option<String>,
+    kind: Option<String>,
+) -> Result<Vec<FeedbackLexiconTerm>, String> {
+    feedback_core::feedback_lexicon_list(&app, &profile_id, language.as_deref(), kind.as_deref())
+}
+
+#[tauri::command]
+pub fn feedback_lexicon_add(
+    app: tauri::AppHandle,
+    profile_id: String,
+    language: String,
+    kind: String,
+    term: String,
+) -> Result<FeedbackLexiconTerm, String> {
+    feedback_core::feedback_lexicon_add(&app, &profile_id, &language, &kind, &term)
+}
+
+#[tauri::command]
+pub fn feedback_lexicon_remove(
+    app: tauri::AppHandle,
+    profile_id: String,
+    language: String,
+    kind: String,
+    term: String,
+) -> Result<(), String> {
+    feedback_core::feedback_lexicon_remove(&app, &profile_id, &language, &kind, &term)
+}
+
+#[tauri::command]
+pub fn feedback_lexicon_reset(
+    app: tauri::AppHandle,
+    profile_id: String,
+    language: String,
+) -> Result<Vec<FeedbackLexiconTerm>, String> {
+    feedback_core::feedback_lexicon_reset(&app, &profile_id, &language)
+}
+
 #[tauri::command]
 pub fn feedback_note_get(
     app: tauri::AppHandle,
@@ -58,3 +120,28 @@ pub fn feedback_note_set(
 ) -> Result<(), String> {
     feedback_core::feedback_note_set(&app, &profile_id, &feedback_id, &note)
 }
+
+#[tauri::command]
+pub fn feedback_revisions_list(
+    app: tauri::AppHandle,
+    profile_id: String,
+    subject_type: String,
+    subject_id: String,
+) -> Result<Vec<FeedbackRevision>, String> {
+    feedback_core::feedback_revisions_list(&app, &profile_id, &subject_type, &subject_id)
+}
+
+#[tauri::command]
+pub fn feedback_revisions_diff(
+    app: tauri::AppHandle,
+    profile_id: String,
+    base_feedback_id: String,
+    target_feedback_id: String,
+) -> Result<FeedbackRevisionDiff, String> {
+    feedback_core::feedback_revisions_diff(
+        &app,
+        &profile_id,
+        &base_feedback_id,
+        &target_feedback_id,
+    )
+}
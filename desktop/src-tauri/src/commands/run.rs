@@ -67,3 +67,12 @@ pub fn run_analyze(
 ) -> Result<RunAnalyzeResponse, String> {
     run_core::run_analyze(&app, &profile_id, &run_id)
 }
+
+#[tauri::command]
+pub fn run_reanalyze(
+    app: tauri::AppHandle,
+    profile_id: String,
+    run_id: String,
+) -> Result<RunAnalyzeResponse, String> {
+    run_core::run_reanalyze(&app, &profile_id, &run_id)
+}
@@ -1,9 +1,11 @@
 use crate::domain::asr::transcript;
 use crate::domain::asr::{self, asr_models};
+use crate::domain::recorder::markers;
 use crate::kernel::{ids, models};
 use crate::platform::artifacts;
 use crate::platform::{asr_sidecar, db};
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicBool, Ordering};
 use tauri::Emitter;
 use tauri::Manager;
 
@@ -14,7 +16,7 @@ const EVENT_ASR_FINAL_PROGRESS: &str = "asr/final_progress/v1";
 const EVENT_ASR_FINAL_RESULT: &str = "asr/final_result/v1";
 const EVENT_MODEL_DOWNLOAD_PROGRESS: &str = "asr/model_download_progress/v1";
 
-const KNOWN_ASR_ERROR_SIGNATURES: [&str; 8] = [
+const KNOWN_ASR_ERROR_SIGNATURES: [&str; 10] = [
     "sidecar_missing",
     "sidecar_doctor_failed",
     "sidecar_doctor_invalid",
@@ -23,6 +25,8 @@ const KNOWN_ASR_ERROR_SIGNATURES: [&str; 8] = [
     "model_missing",
     "sidecar_init_timeout",
     "sidecar_decode_timeout",
+    "sidecar_exited",
+    "transcription_cancelled",
 ];
 
 #[derive(Debug, Serialize)]
@@ -110,7 +114,8 @@ pub async fn transcribe_audio(
     asr_settings: Option<asr::TranscriptionAsrSettingsPayload>,
 ) -> Result<TranscribeResponse, String> {
     db::ensure_profile_exists(&app, &profile_id)?;
-    let asr_settings = asr::normalize_transcription_settings(asr_settings);
+    let asr_settings =
+        asr::normalize_transcription_settings(asr_settings, &asr_models::model_specs(&app)?);
     let artifact = artifacts::get_artifact(&app, &profile_id, &audio_artifact_id)?;
     if artifact.artifact_type != "audio" {
         return Err("artifact_not_audio".to_string());
@@ -119,32 +124,89 @@ pub async fn transcribe_audio(
     let job_id = ids::new_id("job");
     emit_progress(&app, &job_id, "transcribe", 5, Some("queued".to_string()))?;
 
-    let app_handle = app.clone();
-    let profile_id_for_job = profile_id.clone();
-    let audio_artifact_id_for_job = audio_artifact_id.clone();
-    let audio_relpath_for_job = artifact.relpath;
-    let asr_settings_for_job = asr_settings.clone();
-    let job_id_for_job = job_id.clone();
-
-    let result = tauri::async_runtime::spawn_blocking(move || {
-        transcribe_audio_blocking(
-            &app_handle,
-            &profile_id_for_job,
-            &audio_artifact_id_for_job,
-            &audio_relpath_for_job,
-            asr_settings_for_job,
-            &job_id_for_job,
-        )
+    let outcome = app.state::<asr::TranscriptionQueue>().enqueue(
+        &app,
+        asr::TranscriptionJob {
+            id: job_id.clone(),
+            profile_id,
+            audio_artifact_id,
+            settings: asr_settings,
+        },
+        run_transcription_job,
+    )?;
+    let transcript_id = tauri::async_runtime::spawn_blocking(move || {
+        outcome
+            .recv()
+            .unwrap_or_else(|_| Err("transcription_queue_closed".to_string()))
     })
     .await
-    .map_err(|e| format!("transcribe_join: {e}"))?;
+    .map_err(|e| format!("transcribe_join: {e}"))??;
+
+    Ok(TranscribeResponse {
+        transcript_id,
+        job_id,
+    })
+}
+
+#[tauri::command]
+pub fn transcription_cancel(app: tauri::AppHandle, job_id: String) -> Result<(), String> {
+    app.state::<asr::TranscriptionQueue>().cancel(&app, &job_id)
+}
+
+#[tauri::command]
+pub fn transcription_jobs_list(
+    app: tauri::AppHandle,
+    profile_id: Option<String>,
+) -> Result<Vec<asr::TranscriptionJobStatus>, String> {
+    app.state::<asr::TranscriptionQueue>()
+        .list(&app, profile_id.as_deref())
+}
 
-    if let Err(err) = result {
-        let _ = emit_failed(&app, &job_id, "transcription_failed", &err);
-        return Err(err);
+/// Called once at startup so jobs cut short by the previous session finish.
+pub fn resume_transcription_jobs(app: &tauri::AppHandle) {
+    match app
+        .state::<asr::TranscriptionQueue>()
+        .resume_interrupted(app, run_transcription_job)
+    {
+        Ok(0) => {}
+        Ok(count) => eprintln!("resumed {count} interrupted transcription job(s)"),
+        Err(err) => eprintln!("interrupted transcription jobs not resumed: {err}"),
     }
+}
 
-    result
+fn run_transcription_job(
+    app: &tauri::AppHandle,
+    job: &asr::TranscriptionJob,
+    cancel: &AtomicBool,
+) -> Result<String, String> {
+    let result = artifacts::get_artifact(app, &job.profile_id, &job.audio_artifact_id).and_then(
+        |artifact| {
+            if artifact.artifact_type != "audio" {
+                return Err("artifact_not_audio".to_string());
+            }
+            transcribe_audio_blocking(
+                app,
+                &job.profile_id,
+                &job.audio_artifact_id,
+                &artifact.relpath,
+                job.settings.clone(),
+                &job.id,
+                cancel,
+            )
+        },
+    );
+    match result {
+        Ok(response) => Ok(response.transcript_id),
+        Err(err) => {
+            let error_code = if err == asr_sidecar::DECODE_CANCELLED {
+                asr_sidecar::DECODE_CANCELLED
+            } else {
+                "transcription_failed"
+            };
+            let _ = emit_failed(app, &job.id, error_code, &err);
+            Err(err)
+        }
+    }
 }
 
 fn transcribe_audio_blocking(
@@ -154,6 +216,7 @@ fn transcribe_audio_blocking(
     audio_relpath: &str,
     asr_settings: asr::AsrRuntimeSettings,
     job_id: &str,
+    cancel: &AtomicBool,
 ) -> Result<TranscribeResponse, String> {
     let audio_path = artifacts::resolve_profile_relpath_for_read(app, profile_id, audio_relpath)?;
     let audio_bytes = std::fs::read(&audio_path).map_err(|e| format!("audio_read: {e}"))?;
@@ -173,10 +236,14 @@ fn transcribe_audio_blocking(
         &asr_settings,
         &samples,
         total_ms,
+        cancel,
         |processed, total| {
             let _ = emit_final_progress(app, processed, total);
         },
     )?;
+    if cancel.load(Ordering::SeqCst) {
+        return Err(asr_sidecar::DECODE_CANCELLED.to_string());
+    }
     let segments = if asr_settings.spoken_punctuation {
         transcript::apply_spoken_punctuation(&segments, &asr_settings.language)
     } else {
@@ -188,6 +255,7 @@ fn transcribe_audio_blocking(
         model_id: Some(asr_settings.model_id.clone()),
         duration_ms: Some(duration_ms),
         segments,
+        markers: markers::audio_markers(app, profile_id, audio_artifact_id),
     };
 
     emit_progress(app, job_id, "transcribe", 70, Some("serialize".to_string()))?;
@@ -278,21 +346,10 @@ pub fn asr_models_list(app: tauri::AppHandle) -> Result<Vec<models::AsrModelStat
 
 #[tauri::command]
 pub fn asr_model_remove(app: tauri::AppHandle, model_id: String) -> Result<(), String> {
-    let spec = asr_models::model_spec(&model_id).ok_or_else(|| "model_unknown".to_string())?;
-    let dir = asr_models::models_dir(&app)?;
-    let final_path = dir.join(spec.filename);
-    let tmp_path = dir.join(format!("{}.download", spec.filename));
-    let manifest_path = dir.join(format!("{}.manifest.json", spec.filename));
-
-    if final_path.exists() {
-        std::fs::remove_file(&final_path).map_err(|e| format!("model_remove: {e}"))?;
-    }
-    if tmp_path.exists() {
-        let _ = std::fs::remove_file(&tmp_path);
-    }
-    if manifest_path.exists() {
-        let _ = std::fs::remove_file(&manifest_path);
-    }
+    let spec = asr_models::model_spec(&app, &model_id)?;
+    asr_models::remove_model_files(&app, &spec)?;
+    app.state::<asr_sidecar::SidecarPool>()
+        .evict_model(&model_id);
 
     Ok(())
 }
@@ -331,6 +388,20 @@ pub fn asr_model_verify(
     asr_models::verify_model(&app, &model_id)
 }
 
+#[tauri::command]
+pub async fn asr_model_register(
+    app: tauri::AppHandle,
+    path: String,
+    sha256: String,
+    label: Option<String>,
+) -> Result<models::AsrModelStatus, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        asr_models::register_model(&app, std::path::Path::new(&path), label.as_deref(), &sha256)
+    })
+    .await
+    .map_err(|e| format!("model_register_join: {e}"))?
+}
+
 #[tauri::command]
 pub async fn asr_model_download(
     app: tauri::AppHandle,
@@ -565,7 +636,9 @@ mod transcription_tests {
                 t_end_ms: 3210,
                 text: "bonjour".to_string(),
                 confidence: Some(0.8),
+                words: Vec::new(),
             }],
+            markers: Vec::new(),
         };
 
         let metadata = transcript::build_transcript_edit_metadata("tr-source-1", &source);
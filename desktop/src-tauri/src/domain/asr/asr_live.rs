@@ -54,6 +54,7 @@ impl LiveTranscriptState {
                     t_end_ms,
                     text: text.to_string(),
                     confidence: segment.confidence,
+                    words: segment.words.clone(),
                 })
             })
             .collect();
@@ -130,6 +131,7 @@ mod tests {
             t_end_ms: t1,
             text: text.to_string(),
             confidence: None,
+            words: Vec::new(),
         }
     }
 
@@ -1,11 +1,12 @@
 mod integrity;
+mod registry;
 mod specs;
 
 use crate::kernel::models;
 use std::path::{Path, PathBuf};
 use tauri::Manager;
 
-pub use specs::{model_spec, model_specs, AsrModelSpec};
+pub use specs::{catalog_specs, AsrModelSpec};
 
 pub fn models_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
     let app_dir = app
@@ -17,23 +18,40 @@ pub fn models_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
     Ok(dir)
 }
 
+/// The built-in catalog followed by the user-registered models.
+pub fn model_specs(app: &tauri::AppHandle) -> Result<Vec<AsrModelSpec>, String> {
+    let dir = models_dir(app)?;
+    let mut specs = catalog_specs().to_vec();
+    specs.extend(registry::load_registry(&dir)?.custom);
+    Ok(specs)
+}
+
+pub fn model_spec(app: &tauri::AppHandle, model_id: &str) -> Result<AsrModelSpec, String> {
+    model_specs(app)?
+        .into_iter()
+        .find(|spec| spec.id == model_id)
+        .ok_or_else(|| "model_unknown".to_string())
+}
+
 pub fn list_models(app: &tauri::AppHandle) -> Result<Vec<models::AsrModelStatus>, String> {
     let dir = models_dir(app)?;
     let mut out = Vec::new();
 
-    for spec in model_specs() {
-        let path = dir.join(spec.filename);
+    for spec in model_specs(app)? {
+        let path = dir.join(&spec.filename);
+        let manifest = integrity::read_manifest(&manifest_path(&dir, &spec.filename));
+        let expected_sha256 = spec.sha256.clone();
         if path.exists() {
             let size_bytes = std::fs::metadata(&path).map(|meta| meta.len()).ok();
-            let manifest = integrity::read_manifest(&manifest_path(&dir, spec.filename));
             let mut installed = false;
             let mut checksum_ok = None;
 
             if let Some(actual_size) = size_bytes {
-                if actual_size != spec.size_bytes {
+                if spec.size_bytes != Some(actual_size) {
                     checksum_ok = Some(false);
                 } else if let Some(manifest) = manifest {
-                    let ok = manifest.sha256 == spec.sha256 && manifest.size_bytes == actual_size;
+                    let ok = Some(manifest.sha256.as_str()) == expected_sha256.as_deref()
+                        && manifest.size_bytes == actual_size;
                     checksum_ok = Some(ok);
                     installed = ok;
                 } else {
@@ -43,31 +61,23 @@ pub fn list_models(app: &tauri::AppHandle) -> Result<Vec<models::AsrModelStatus>
                 checksum_ok = Some(false);
             }
 
-            out.push(models::AsrModelStatus {
-                id: spec.id.to_string(),
-                label: spec.label.to_string(),
-                bundled: spec.bundled,
+            out.push(model_status(
+                &spec,
                 installed,
-                expected_bytes: spec.size_bytes,
-                expected_sha256: spec.sha256.to_string(),
-                source_url: spec.url.to_string(),
-                path: Some(path.to_string_lossy().to_string()),
+                expected_sha256,
+                Some(&path),
                 size_bytes,
                 checksum_ok,
-            });
+            ));
         } else {
-            out.push(models::AsrModelStatus {
-                id: spec.id.to_string(),
-                label: spec.label.to_string(),
-                bundled: spec.bundled,
-                installed: false,
-                expected_bytes: spec.size_bytes,
-                expected_sha256: spec.sha256.to_string(),
-                source_url: spec.url.to_string(),
-                path: None,
-                size_bytes: None,
-                checksum_ok: None,
-            });
+            out.push(model_status(
+                &spec,
+                false,
+                expected_sha256,
+                None,
+                None,
+                None,
+            ));
         }
     }
 
@@ -78,31 +88,68 @@ pub fn verify_model(
     app: &tauri::AppHandle,
     model_id: &str,
 ) -> Result<models::AsrModelStatus, String> {
-    let spec = model_spec(model_id).ok_or_else(|| "model_unknown".to_string())?;
+    let spec = model_spec(app, model_id)?;
     let dir = models_dir(app)?;
-    let path = dir.join(spec.filename);
+    let path = dir.join(&spec.filename);
 
     if !path.exists() {
         return Err("model_missing".to_string());
     }
 
     let (sha256, size_bytes) = integrity::sha256_file(&path)?;
-    store_manifest(&dir, spec.filename, &sha256, size_bytes)?;
+    store_manifest(&dir, &spec.filename, &sha256, size_bytes)?;
 
-    let checksum_ok = sha256 == spec.sha256 && size_bytes == spec.size_bytes;
+    let checksum_ok =
+        spec.sha256.as_deref() == Some(sha256.as_str()) && spec.size_bytes == Some(size_bytes);
 
-    Ok(models::AsrModelStatus {
-        id: spec.id.to_string(),
-        label: spec.label.to_string(),
-        bundled: spec.bundled,
-        installed: checksum_ok,
-        expected_bytes: spec.size_bytes,
-        expected_sha256: spec.sha256.to_string(),
-        source_url: spec.url.to_string(),
-        path: Some(path.to_string_lossy().to_string()),
-        size_bytes: Some(size_bytes),
-        checksum_ok: Some(checksum_ok),
-    })
+    Ok(model_status(
+        &spec,
+        checksum_ok,
+        spec.sha256.clone(),
+        Some(&path),
+        Some(size_bytes),
+        Some(checksum_ok),
+    ))
+}
+
+pub fn register_model(
+    app: &tauri::AppHandle,
+    source: &Path,
+    label: Option<&str>,
+    sha256: &str,
+) -> Result<models::AsrModelStatus, String> {
+    let dir = models_dir(app)?;
+    let spec = registry::register_custom_model(&dir, source, label, sha256)?;
+    let path = dir.join(&spec.filename);
+    Ok(model_status(
+        &spec,
+        true,
+        spec.sha256.clone(),
+        Some(&path),
+        spec.size_bytes,
+        Some(true),
+    ))
+}
+
+pub fn remove_model_files(app: &tauri::AppHandle, spec: &AsrModelSpec) -> Result<(), String> {
+    let dir = models_dir(app)?;
+    let final_path = dir.join(&spec.filename);
+    let tmp_path = dir.join(format!("{}.download", spec.filename));
+    let manifest_path = manifest_path(&dir, &spec.filename);
+
+    if final_path.exists() {
+        std::fs::remove_file(&final_path).map_err(|e| format!("model_remove: {e}"))?;
+    }
+    if tmp_path.exists() {
+        let _ = std::fs::remove_file(&tmp_path);
+    }
+    if manifest_path.exists() {
+        let _ = std::fs::remove_file(&manifest_path);
+    }
+    if spec.custom {
+        registry::unregister_custom_model(&dir, &spec.id)?;
+    }
+    Ok(())
 }
 
 pub fn store_manifest(
@@ -123,3 +170,27 @@ pub fn store_manifest(
 fn manifest_path(dir: &Path, filename: &str) -> PathBuf {
     dir.join(format!("{filename}.manifest.json"))
 }
+
+fn model_status(
+    spec: &AsrModelSpec,
+    installed: bool,
+    expected_sha256: Option<String>,
+    path: Option<&Path>,
+    size_bytes: Option<u64>,
+    checksum_ok: Option<bool>,
+) -> models::AsrModelStatus {
+    models::AsrModelStatus {
+        id: spec.id.clone(),
+        label: spec.label.clone(),
+        bundled: spec.bundled,
+        installed,
+        expected_bytes: spec.size_bytes,
+        expected_sha256,
+        source_url: spec.url.clone(),
+        english_only: spec.english_only,
+        custom: spec.custom,
+        path: path.map(|path| path.to_string_lossy().to_string()),
+        size_bytes,
+        checksum_ok,
+    }
+}
@@ -0,0 +1,235 @@
+use super::integrity;
+use super::specs::{is_valid_model_id, is_valid_sha256, AsrModelSpec};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+const REGISTRY_FILE: &str = "registry.json";
+const CUSTOM_ID_PREFIX: &str = "custom-";
+const MAX_SLUG_LEN: usize = 40;
+/// `0x67676d6c` as written little-endian at the start of whisper GGML files.
+const GGML_MAGIC: [u8; 4] = *b"lmgg";
+
+/// User-supplied models, kept next to the model files.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(super) struct ModelRegistry {
+    #[serde(default)]
+    pub(super) custom: Vec<AsrModelSpec>,
+}
+
+pub(super) fn load_registry(dir: &Path) -> Result<ModelRegistry, String> {
+    let path = dir.join(REGISTRY_FILE);
+    if !path.exists() {
+        return Ok(ModelRegistry::default());
+    }
+    let data = std::fs::read_to_string(&path).map_err(|e| format!("model_registry_read: {e}"))?;
+    let mut registry: ModelRegistry =
+        serde_json::from_str(&data).map_err(|e| format!("model_registry_parse: {e}"))?;
+    // The file is user-editable; entries must not point outside the models dir.
+    registry.custom.retain(|spec| {
+        spec.custom && is_valid_model_id(&spec.id) && spec.filename == format!("{}.bin", spec.id)
+    });
+    Ok(registry)
+}
+
+fn save_registry(dir: &Path, registry: &ModelRegistry) -> Result<(), String> {
+    let payload =
+        serde_json::to_string_pretty(registry).map_err(|e| format!("model_registry_json: {e}"))?;
+    let tmp_path = dir.join(format!("{REGISTRY_FILE}.tmp"));
+    std::fs::write(&tmp_path, payload).map_err(|e| format!("model_registry_write: {e}"))?;
+    std::fs::rename(&tmp_path, dir.join(REGISTRY_FILE))
+        .map_err(|e| format!("model_registry_write: {e}"))
+}
+
+/// Checks the file is a GGML model matching `sha256`, copies it into the
+/// models directory and records it in the registry.
+pub(super) fn register_custom_model(
+    dir: &Path,
+    source: &Path,
+    label: Option<&str>,
+    sha256: &str,
+) -> Result<AsrModelSpec, String> {
+    let is_bin = source
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("bin"));
+    if !is_bin || !source.is_file() {
+        return Err("model_path_invalid".to_string());
+    }
+    let expected_sha256 = sha256.trim().to_ascii_lowercase();
+    if !is_valid_sha256(&expected_sha256) {
+        return Err("model_checksum_invalid".to_string());
+    }
+    if !has_ggml_magic(source)? {
+        return Err("model_format_invalid".to_string());
+    }
+    let (actual_sha256, size_bytes) = integrity::sha256_file(source)?;
+    if actual_sha256 != expected_sha256 {
+        return Err("model_checksum_mismatch".to_string());
+    }
+
+    let mut registry = load_registry(dir)?;
+    if registry
+        .custom
+        .iter()
+        .any(|spec| spec.sha256.as_deref() == Some(expected_sha256.as_str()))
+    {
+        return Err("model_already_registered".to_string());
+    }
+    let stem = source
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let label = label
+        .map(str::trim)
+        .filter(|label| !label.is_empty())
+        .unwrap_or(&stem)
+        .to_string();
+    let id = unique_custom_id(&registry, &label);
+    let filename = format!("{id}.bin");
+
+    let tmp_path = dir.join(format!("{filename}.import"));
+    let copied = std::fs::copy(source, &tmp_path)
+        .map_err(|e| format!("model_import: {e}"))
+        .and_then(|_| {
+            std::fs::rename(&tmp_path, dir.join(&filename))
+                .map_err(|e| format!("model_import: {e}"))
+        });
+    if let Err(err) = copied {
+        let _ = std::fs::remove_file(&tmp_path);
+        return Err(err);
+    }
+    super::store_manifest(dir, &filename, &actual_sha256, size_bytes)?;
+
+    let spec = AsrModelSpec {
+        id,
+        label,
+        filename,
+        url: None,
+        sha256: Some(actual_sha256),
+        size_bytes: Some(size_bytes),
+        bundled: false,
+        english_only: false,
+        custom: true,
+    };
+    registry.custom.push(spec.clone());
+    save_registry(dir, &registry)?;
+    Ok(spec)
+}
+
+pub(super) fn unregister_custom_model(dir: &Path, model_id: &str) -> Result<(), String> {
+    let mut registry = load_registry(dir)?;
+    let before = registry.custom.len();
+    registry.custom.retain(|spec| spec.id != model_id);
+    if registry.custom.len() == before {
+        return Ok(());
+    }
+    save_registry(dir, &registry)
+}
+
+fn has_ggml_magic(path: &Path) -> Result<bool, String> {
+    let mut file = File::open(path).map_err(|e| format!("model_open: {e}"))?;
+    let mut magic = [0u8; 4];
+    match file.read_exact(&mut magic) {
+        Ok(()) => Ok(magic == GGML_MAGIC),
+        Err(err) if err.kind() == std::io::ErrorKind::UnexpectedEof => Ok(false),
+        Err(err) => Err(format!("model_read: {err}")),
+    }
+}
+
+fn unique_custom_id(registry: &ModelRegistry, label: &str) -> String {
+    let mut slug = String::new();
+    for ch in label.chars().flat_map(char::to_lowercase) {
+        if ch.is_ascii_alphanumeric() {
+            slug.push(ch);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.truncate(MAX_SLUG_LEN);
+    let slug = slug.trim_end_matches('-');
+    let base = if slug.is_empty() {
+        format!("{CUSTOM_ID_PREFIX}model")
+    } else {
+        format!("{CUSTOM_ID_PREFIX}{slug}")
+    };
+    let taken = |id: &str| registry.custom.iter().any(|spec| spec.id == id);
+    if !taken(&base) {
+        return base;
+    }
+    (2..)
+        .map(|n| format!("{base}-{n}"))
+        .find(|id| !taken(id))
+        .unwrap_or(base)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_models_dir(name: &str) -> std::path::PathBuf {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or(0);
+        let dir = std::env::temp_dir().join(format!("lepupitre-models-{name}-{nonce}"));
+        std::fs::create_dir_all(&dir).expect("models dir");
+        dir
+    }
+
+    #[test]
+    fn custom_models_are_checked_copied_and_persisted() {
+        let dir = temp_models_dir("register");
+        let source = dir.join("My Fine-Tuned.bin");
+        let mut bytes = GGML_MAGIC.to_vec();
+        bytes.extend_from_slice(&[7u8; 128]);
+        std::fs::write(&source, &bytes).expect("source");
+        let (sha256, size_bytes) = integrity::sha256_file(&source).expect("hash");
+
+        assert_eq!(
+            register_custom_model(&dir, &source, None, &"0".repeat(64)).unwrap_err(),
+            "model_checksum_mismatch"
+        );
+        assert_eq!(
+            register_custom_model(&dir, &source, None, "abc").unwrap_err(),
+            "model_checksum_invalid"
+        );
+        let not_ggml = dir.join("notes.bin");
+        std::fs::write(&not_ggml, b"hello world").expect("not ggml");
+        let (not_ggml_sha, _) = integrity::sha256_file(&not_ggml).expect("hash");
+        assert_eq!(
+            register_custom_model(&dir, &not_ggml, None, &not_ggml_sha).unwrap_err(),
+            "model_format_invalid"
+        );
+
+        let spec =
+            register_custom_model(&dir, &source, None, &sha256.to_uppercase()).expect("register");
+        assert_eq!(spec.id, "custom-my-fine-tuned");
+        assert_eq!(spec.filename, "custom-my-fine-tuned.bin");
+        assert_eq!(spec.size_bytes, Some(size_bytes));
+        assert!(spec.custom);
+        assert_eq!(
+            std::fs::read(dir.join(&spec.filename)).expect("copy"),
+            bytes
+        );
+        assert!(dir.join("custom-my-fine-tuned.bin.manifest.json").exists());
+        assert_eq!(
+            register_custom_model(&dir, &source, Some("Again"), &sha256).unwrap_err(),
+            "model_already_registered"
+        );
+
+        let registry = load_registry(&dir).expect("registry");
+        assert_eq!(registry.custom.len(), 1);
+        assert_eq!(registry.custom[0].sha256.as_deref(), Some(sha256.as_str()));
+        assert_eq!(
+            unique_custom_id(&registry, "my fine tuned"),
+            "custom-my-fine-tuned-2"
+        );
+        assert_eq!(unique_custom_id(&registry, "***"), "custom-model");
+
+        unregister_custom_model(&dir, &spec.id).expect("unregister");
+        assert!(load_registry(&dir).expect("registry").custom.is_empty());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -1,40 +1,48 @@
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+const CATALOG_JSON: &str = include_str!("catalog.json");
+
+/// A whisper GGML model. Only models with a pinned checksum and size can be
+/// downloaded or reported as installed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AsrModelSpec {
-    pub id: &'static str,
-    pub label: &'static str,
-    pub filename: &'static str,
-    pub url: &'static str,
-    pub sha256: &'static str,
-    pub size_bytes: u64,
+    pub id: String,
+    pub label: String,
+    pub filename: String,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub sha256: Option<String>,
+    #[serde(default)]
+    pub size_bytes: Option<u64>,
+    #[serde(default)]
     pub bundled: bool,
+    #[serde(default)]
+    pub english_only: bool,
+    #[serde(default)]
+    pub custom: bool,
+}
+
+pub fn catalog_specs() -> &'static [AsrModelSpec] {
+    static CATALOG: OnceLock<Vec<AsrModelSpec>> = OnceLock::new();
+    CATALOG.get_or_init(|| serde_json::from_str(CATALOG_JSON).expect("asr model catalog"))
 }
 
-const MODEL_SPECS: [AsrModelSpec; 2] = [
-    AsrModelSpec {
-        id: "tiny",
-        label: "Tiny",
-        filename: "ggml-tiny.bin",
-        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-tiny.bin",
-        sha256: "be07e048e1e599ad46341c8d2a135645097a538221678b7acdd1b1919c6e1b21",
-        size_bytes: 77_691_713,
-        bundled: false,
-    },
-    AsrModelSpec {
-        id: "base",
-        label: "Base",
-        filename: "ggml-base.bin",
-        url: "https://huggingface.co/ggerganov/whisper.cpp/resolve/main/ggml-base.bin",
-        sha256: "60ed5bc3dd14eea856493d334349b405782ddcaf0028d4b5df4088345fba2efe",
-        size_bytes: 147_951_465,
-        bundled: false,
-    },
-];
-
-pub fn model_specs() -> &'static [AsrModelSpec] {
-    &MODEL_SPECS
+pub fn is_valid_model_id(model_id: &str) -> bool {
+    !model_id.is_empty()
+        && model_id.len() <= 64
+        && model_id
+            .chars()
+            .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '.' || ch == '-')
+        && !model_id.starts_with(['.', '-'])
 }
 
-pub fn model_spec(model_id: &str) -> Option<&'static AsrModelSpec> {
-    MODEL_SPECS.iter().find(|spec| spec.id == model_id)
+pub fn is_valid_sha256(value: &str) -> bool {
+    value.len() == 64
+        && value
+            .chars()
+            .all(|ch| ch.is_ascii_digit() || ('a'..='f').contains(&ch))
 }
 
 #[cfg(test)]
@@ -43,14 +51,49 @@ mod tests {
 
     #[test]
     fn model_specs_are_consistent() {
-        let specs = model_specs();
+        let specs = catalog_specs();
         let mut ids = std::collections::HashSet::new();
         for spec in specs {
-            assert!(ids.insert(spec.id), "duplicate model id: {}", spec.id);
+            assert!(
+                ids.insert(spec.id.as_str()),
+                "duplicate model id: {}",
+                spec.id
+            );
+            assert!(is_valid_model_id(&spec.id), "invalid model id: {}", spec.id);
             assert!(spec.filename.ends_with(".bin"));
-            assert!(spec.url.starts_with("https://"));
-            assert!(spec.size_bytes > 0);
-            assert_eq!(spec.sha256.len(), 64);
+            assert!(spec
+                .url
+                .as_deref()
+                .is_some_and(|url| url.starts_with("https://")));
+            assert!(!spec.custom);
+            assert!(spec.sha256.is_some(), "unpinned checksum: {}", spec.id);
+            assert!(spec.size_bytes.is_some(), "unpinned size: {}", spec.id);
+            assert!(spec.size_bytes.is_some_and(|size| size > 0));
+            assert!(spec.sha256.as_deref().is_some_and(is_valid_sha256));
+            assert_eq!(spec.english_only, spec.id.ends_with(".en"));
         }
+        for id in [
+            "tiny",
+            "tiny.en",
+            "base",
+            "base.en",
+            "small",
+            "small.en",
+            "medium",
+            "medium.en",
+            "large-v3-turbo",
+        ] {
+            assert!(ids.contains(id), "missing catalog model: {id}");
+        }
+    }
+
+    #[test]
+    fn model_ids_are_filename_safe() {
+        assert!(is_valid_model_id("large-v3-turbo"));
+        assert!(is_valid_model_id("custom-my-model"));
+        assert!(!is_valid_model_id(""));
+        assert!(!is_valid_model_id("../tiny"));
+        assert!(!is_valid_model_id("Tiny"));
+        assert!(!is_valid_model_id(".hidden"));
     }
 }
@@ -0,0 +1,363 @@
+use crate::domain::recorder::{dsp, pauses, vad};
+use crate::kernel::models::{TranscriptSegment, TranscriptWord};
+
+const CHUNK_MS: i64 = 12_000;
+const CUT_SEARCH_MS: i64 = 3_000;
+const OVERLAP_MS: i64 = 1_500;
+const FRAME_MS: u32 = 20;
+const SEAM_DEDUPE_MS: i64 = 400;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkWindow {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    /// Output before this point belongs to the previous window.
+    pub keep_from_ms: i64,
+}
+
+/// Splits the recording into decode windows of about 12 s. A window ends in
+/// the longest silence found near its target length; when speech runs
+/// through, it ends at the target and the next window overlaps it.
+pub fn plan_chunks(samples: &[f32], sample_rate: u32) -> Vec<ChunkWindow> {
+    let total_ms = (samples.len() as i64 * 1000) / sample_rate.max(1) as i64;
+    let quiet = quiet_frames(samples, sample_rate);
+    let mut windows = Vec::new();
+    let mut start_ms = 0i64;
+    let mut keep_from_ms = 0i64;
+
+    while start_ms < total_ms {
+        let target_ms = start_ms + CHUNK_MS;
+        if target_ms >= total_ms {
+            windows.push(ChunkWindow {
+                start_ms,
+                end_ms: total_ms,
+                keep_from_ms,
+            });
+            break;
+        }
+        match silence_cut(&quiet, target_ms - CUT_SEARCH_MS, target_ms) {
+            Some(cut_ms) => {
+                windows.push(ChunkWindow {
+                    start_ms,
+                    end_ms: cut_ms,
+                    keep_from_ms,
+                });
+                start_ms = cut_ms;
+                keep_from_ms = cut_ms;
+            }
+            None => {
+                windows.push(ChunkWindow {
+                    start_ms,
+                    end_ms: target_ms,
+                    keep_from_ms,
+                });
+                start_ms = target_ms - OVERLAP_MS;
+                keep_from_ms = target_ms - OVERLAP_MS / 2;
+            }
+        }
+    }
+
+    windows
+}
+
+/// Appends one window's segments, dropping words the previous window
+/// already covered and a word repeated across the seam.
+pub fn stitch_segments(
+    segments: &mut Vec<TranscriptSegment>,
+    incoming: Vec<TranscriptSegment>,
+    keep_from_ms: i64,
+) {
+    let kept: Vec<TranscriptSegment> = std::mem::take(segments)
+        .into_iter()
+        .filter_map(|segment| trim_segment(segment, |mid_ms| mid_ms < keep_from_ms))
+        .collect();
+    *segments = kept;
+
+    let mut incoming = incoming
+        .into_iter()
+        .filter_map(|segment| trim_segment(segment, |mid_ms| mid_ms >= keep_from_ms));
+    if let Some(first) = incoming.next() {
+        let first = match segments.last() {
+            Some(previous) => drop_seam_duplicate(previous, first),
+            None => Some(first),
+        };
+        segments.extend(first);
+    }
+    segments.extend(incoming);
+}
+
+fn quiet_frames(samples: &[f32], sample_rate: u32) -> Vec<bool> {
+    let frame_len = (sample_rate as usize * FRAME_MS as usize) / 1000;
+    if frame_len == 0 || samples.len() < frame_len {
+        return Vec::new();
+    }
+    let energies: Vec<f32> = samples.chunks_exact(frame_len).map(dsp::rms).collect();
+    let threshold = pauses::energy_threshold(&energies);
+    let config = vad::VadConfig::pause_analysis(threshold);
+    let mut state = vad::VadState::default();
+    energies
+        .iter()
+        .map(|energy| {
+            let decision = state.update(*energy, FRAME_MS, &config);
+            !decision.in_speech && *energy < threshold
+        })
+        .collect()
+}
+
+/// Middle of the longest quiet run inside the search range.
+fn silence_cut(quiet: &[bool], from_ms: i64, to_ms: i64) -> Option<i64> {
+    let frame_ms = FRAME_MS as i64;
+    let first = (from_ms.max(0) / frame_ms) as usize;
+    let last = ((to_ms.max(0) / frame_ms) as usize).min(quiet.len());
+    let mut best: Option<(usize, usize)> = None;
+    let mut run_start: Option<usize> = None;
+    let range = quiet.get(first..last).unwrap_or_default();
+    for (offset, is_quiet) in range.iter().chain(std::iter::once(&false)).enumerate() {
+        let idx = first + offset;
+        match (*is_quiet, run_start) {
+            (true, None) => run_start = Some(idx),
+            (false, Some(start)) => {
+                if best.is_none_or(|(best_start, best_end)| idx - start > best_end - best_start) {
+                    best = Some((start, idx));
+                }
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+    best.map(|(start, end)| (start + end) as i64 * frame_ms / 2)
+}
+
+fn trim_segment(
+    mut segment: TranscriptSegment,
+    keep: impl Fn(i64) -> bool,
+) -> Option<TranscriptSegment> {
+    if segment.words.is_empty() {
+        return keep((segment.t_start_ms + segment.t_end_ms) / 2).then_some(segment);
+    }
+    let total = segment.words.len();
+    segment
+        .words
+        .retain(|word| keep((word.t_start_ms + word.t_end_ms) / 2));
+    if segment.words.len() == total {
+        return Some(segment);
+    }
+    rebuild_from_words(segment)
+}
+
+fn drop_seam_duplicate(
+    previous: &TranscriptSegment,
+    mut segment: TranscriptSegment,
+) -> Option<TranscriptSegment> {
+    let (Some(last), Some(first)) = (previous.words.last(), segment.words.first()) else {
+        return Some(segment);
+    };
+    let repeated = normalize_word(&last.text) == normalize_word(&first.text)
+        && first.t_start_ms < last.t_end_ms + SEAM_DEDUPE_MS;
+    if !repeated {
+        return Some(segment);
+    }
+    segment.words.remove(0);
+    rebuild_from_words(segment)
+}
+
+fn rebuild_from_words(mut segment: TranscriptSegment) -> Option<TranscriptSegment> {
+    segment.t_start_ms = segment.words.first()?.t_start_ms;
+    segment.t_end_ms = segment.words.last()?.t_end_ms;
+    segment.text = segment
+        .words
+        .iter()
+        .map(|word| word.text.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    segment.confidence = mean_confidence(&segment.words).or(segment.confidence);
+    Some(segment)
+}
+
+fn mean_confidence(words: &[TranscriptWord]) -> Option<f32> {
+    let values: Vec<f32> = words.iter().filter_map(|word| word.confidence).collect();
+    if values.is_empty() {
+        return None;
+    }
+    Some(values.iter().sum::<f32>() / values.len() as f32)
+}
+
+fn normalize_word(text: &str) -> String {
+    text.chars()
+        .filter(|ch| ch.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use std::path::PathBuf;
+
+    #[derive(Deserialize)]
+    struct BoundaryFixture {
+        sample_rate: u32,
+        duration_ms: i64,
+        words: Vec<TranscriptWord>,
+    }
+
+    fn boundary_fixture() -> BoundaryFixture {
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join("fixtures")
+            .join("chunk_boundary_words.json");
+        let raw = std::fs::read_to_string(path).expect("fixture json");
+        serde_json::from_str(&raw).expect("fixture parse")
+    }
+
+    /// A tone wherever the script has a word, silence elsewhere.
+    fn fixture_samples(fixture: &BoundaryFixture) -> Vec<f32> {
+        let per_ms = fixture.sample_rate as usize / 1000;
+        let mut samples = vec![0.0f32; fixture.duration_ms as usize * per_ms];
+        for word in &fixture.words {
+            let start = word.t_start_ms as usize * per_ms;
+            let end = (word.t_end_ms as usize * per_ms).min(samples.len());
+            for (idx, sample) in samples[start..end].iter_mut().enumerate() {
+                *sample = 0.3 * (idx as f32 * 0.07).sin();
+            }
+        }
+        samples
+    }
+
+    /// Stands in for the decoder: words cut by a window edge come back
+    /// garbled and are lost.
+    fn decode_window(fixture: &BoundaryFixture, window: &ChunkWindow) -> Vec<TranscriptSegment> {
+        let words: Vec<TranscriptWord> = fixture
+            .words
+            .iter()
+            .filter(|word| word.t_start_ms >= window.start_ms && word.t_end_ms <= window.end_ms)
+            .cloned()
+            .collect();
+        if words.is_empty() {
+            return Vec::new();
+        }
+        rebuild_from_words(TranscriptSegment {
+            t_start_ms: 0,
+            t_end_ms: 0,
+            text: String::new(),
+            confidence: None,
+            words,
+        })
+        .into_iter()
+        .collect()
+    }
+
+    /// Reference words missing from the output plus extra words it added.
+    fn boundary_word_loss(fixture: &BoundaryFixture, windows: &[ChunkWindow]) -> usize {
+        let mut segments = Vec::new();
+        for window in windows {
+            stitch_segments(
+                &mut segments,
+                decode_window(fixture, window),
+                window.keep_from_ms,
+            );
+        }
+        let decoded: Vec<&str> = segments
+            .iter()
+            .flat_map(|segment| segment.words.iter().map(|word| word.text.as_str()))
+            .collect();
+        let expected: Vec<&str> = fixture
+            .words
+            .iter()
+            .map(|word| word.text.as_str())
+            .collect();
+        let matched = expected
+            .iter()
+            .zip(decoded.iter())
+            .take_while(|(left, right)| left == right)
+            .count();
+        (expected.len() - matched) + (decoded.len() - matched)
+    }
+
+    fn fixed_chunks(duration_ms: i64) -> Vec<ChunkWindow> {
+        (0..duration_ms)
+            .step_by(CHUNK_MS as usize)
+            .map(|start_ms| ChunkWindow {
+                start_ms,
+                end_ms: (start_ms + CHUNK_MS).min(duration_ms),
+                keep_from_ms: start_ms,
+            })
+            .collect()
+    }
+
+    fn word(text: &str, t_start_ms: i64, t_end_ms: i64) -> TranscriptWord {
+        TranscriptWord {
+            t_start_ms,
+            t_end_ms,
+            text: text.to_string(),
+            confidence: Some(0.8),
+        }
+    }
+
+    #[test]
+    fn planned_chunks_lose_no_boundary_words() {
+        let fixture = boundary_fixture();
+        assert!(boundary_word_loss(&fixture, &fixed_chunks(fixture.duration_ms)) > 0);
+
+        let windows = plan_chunks(&fixture_samples(&fixture), fixture.sample_rate);
+        assert_eq!(boundary_word_loss(&fixture, &windows), 0);
+        assert_eq!(windows.first().map(|w| w.start_ms), Some(0));
+        assert_eq!(windows.last().map(|w| w.end_ms), Some(fixture.duration_ms));
+        assert!(windows
+            .iter()
+            .all(|w| w.end_ms - w.start_ms <= CHUNK_MS && w.keep_from_ms >= w.start_ms));
+        assert!(
+            windows
+                .iter()
+                .any(|w| w.keep_from_ms == w.start_ms && w.start_ms > 0),
+            "a cut should land in a pause"
+        );
+        assert!(
+            windows.iter().any(|w| w.keep_from_ms > w.start_ms),
+            "continuous speech should fall back to overlap"
+        );
+    }
+
+    #[test]
+    fn stitching_drops_overlap_and_seam_repeats() {
+        let mut segments = vec![TranscriptSegment {
+            t_start_ms: 9_000,
+            t_end_ms: 11_480,
+            text: "nous allons voir".to_string(),
+            confidence: Some(0.8),
+            words: vec![
+                word("nous", 9_000, 9_500),
+                word("allons", 10_400, 10_900),
+                word("voir", 11_000, 11_480),
+            ],
+        }];
+        let incoming = vec![
+            TranscriptSegment {
+                t_start_ms: 10_500,
+                t_end_ms: 12_600,
+                text: "allons voir ensemble".to_string(),
+                confidence: Some(0.8),
+                words: vec![
+                    word("allons", 10_500, 10_900),
+                    word("Voir,", 11_060, 11_520),
+                    word("ensemble", 12_100, 12_600),
+                ],
+            },
+            TranscriptSegment {
+                t_start_ms: 13_000,
+                t_end_ms: 13_500,
+                text: "ce soir".to_string(),
+                confidence: None,
+                words: Vec::new(),
+            },
+        ];
+
+        stitch_segments(&mut segments, incoming, 11_250);
+
+        let texts: Vec<&str> = segments.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["nous allons voir", "ensemble", "ce soir"]);
+        assert_eq!(segments[0].t_end_ms, 11_480);
+        assert_eq!(segments[1].t_start_ms, 12_100);
+    }
+}
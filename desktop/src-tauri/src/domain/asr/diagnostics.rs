@@ -42,9 +42,9 @@ pub struct AsrDiagnosticsModel {
     pub installed: bool,
     pub checksum_ok: Option<bool>,
     pub size_bytes: Option<u64>,
-    pub expected_bytes: u64,
-    pub expected_sha256: String,
-    pub source_url: String,
+    pub expected_bytes: Option<u64>,
+    pub expected_sha256: Option<String>,
+    pub source_url: Option<String>,
     pub path_hint: Option<String>,
 }
 
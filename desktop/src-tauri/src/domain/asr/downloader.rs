@@ -17,9 +17,17 @@ pub fn download_model_blocking<F>(
 where
     F: FnMut(u64, u64),
 {
-    let spec = asr_models::model_spec(model_id).ok_or_else(|| "model_unknown".to_string())?;
+    let spec = asr_models::model_spec(app, model_id)?;
+    let url = spec
+        .url
+        .clone()
+        .ok_or_else(|| "model_not_downloadable".to_string())?;
+    let (Some(expected_sha256), Some(expected_bytes)) = (spec.sha256.clone(), spec.size_bytes)
+    else {
+        return Err("model_checksum_unpinned".to_string());
+    };
     let dir = asr_models::models_dir(app)?;
-    let final_path = dir.join(spec.filename);
+    let final_path = dir.join(&spec.filename);
 
     if final_path.exists() {
         let models = asr_models::list_models(app)?;
@@ -27,7 +35,7 @@ where
             .iter()
             .find(|model| model.id == model_id && model.installed)
         {
-            let bytes = model.size_bytes.unwrap_or(model.expected_bytes);
+            let bytes = model.size_bytes.or(model.expected_bytes).unwrap_or(0);
             return Ok(models::AsrModelDownloadResult {
                 model_id: model_id.to_string(),
                 path: model
@@ -35,7 +43,7 @@ where
                     .clone()
                     .unwrap_or_else(|| final_path.to_string_lossy().to_string()),
                 bytes,
-                sha256: model.expected_sha256.clone(),
+                sha256: model.expected_sha256.clone().unwrap_or_default(),
             });
         }
     }
@@ -48,14 +56,14 @@ where
             .build()
             .map_err(|e| format!("download_client: {e}"))?;
         let mut response = client
-            .get(spec.url)
+            .get(&url)
             .send()
             .map_err(|e| format!("download_request: {e}"))?;
         if !response.status().is_success() {
             return Err(format!("download_status: {}", response.status()));
         }
 
-        let total_bytes = response.content_length().unwrap_or(spec.size_bytes);
+        let total_bytes = response.content_length().unwrap_or(expected_bytes);
         on_progress(0, total_bytes);
 
         let mut file = File::create(&tmp_path).map_err(|e| format!("download_create: {e}"))?;
@@ -88,17 +96,17 @@ where
 
         file.flush().map_err(|e| format!("download_flush: {e}"))?;
 
-        if spec.size_bytes > 0 && downloaded != spec.size_bytes {
+        if downloaded != expected_bytes {
             return Err("download_size_mismatch".to_string());
         }
 
         let sha256 = to_hex(&hasher.finalize());
-        if sha256 != spec.sha256 {
+        if sha256 != expected_sha256 {
             return Err("download_checksum_mismatch".to_string());
         }
 
         std::fs::rename(&tmp_path, &final_path).map_err(|e| format!("download_finalize: {e}"))?;
-        asr_models::store_manifest(&dir, spec.filename, &sha256, downloaded)?;
+        asr_models::store_manifest(&dir, &spec.filename, &sha256, downloaded)?;
         on_progress(downloaded, total_bytes);
 
         Ok(models::AsrModelDownloadResult {
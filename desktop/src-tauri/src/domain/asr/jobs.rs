@@ -0,0 +1,278 @@
+mod repo;
+
+use super::AsrRuntimeSettings;
+use crate::kernel::time;
+use crate::platform::{asr_sidecar, db};
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread;
+use tauri::AppHandle;
+
+const MAX_INTERRUPTIONS: i64 = 3;
+const LIST_LIMIT: i64 = 50;
+
+#[derive(Debug, Clone)]
+pub struct TranscriptionJob {
+    pub id: String,
+    pub profile_id: String,
+    pub audio_artifact_id: String,
+    pub settings: AsrRuntimeSettings,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscriptionJobStatus {
+    pub job_id: String,
+    pub profile_id: String,
+    pub audio_artifact_id: String,
+    pub model_id: Option<String>,
+    pub language: Option<String>,
+    /// `queued`, `running`, `completed`, `failed` or `cancelled`.
+    pub status: String,
+    pub transcript_id: Option<String>,
+    pub error_code: Option<String>,
+    pub interrupted_count: i64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Runs one job to completion and returns the transcript artifact id; the
+/// flag is raised when the job is cancelled.
+pub type JobRunner = fn(&AppHandle, &TranscriptionJob, &AtomicBool) -> Result<String, String>;
+
+type JobOutcome = Result<String, String>;
+
+#[derive(Default)]
+struct QueueState {
+    pending: VecDeque<TranscriptionJob>,
+    running: Option<(String, Arc<AtomicBool>)>,
+    waiters: HashMap<String, Sender<JobOutcome>>,
+    worker_started: bool,
+}
+
+impl QueueState {
+    fn remove_pending(&mut self, job_id: &str) -> bool {
+        let before = self.pending.len();
+        self.pending.retain(|job| job.id != job_id);
+        self.pending.len() != before
+    }
+}
+
+/// Transcription jobs run one at a time in submission order. Every state
+/// change is written to the global database so jobs cut short by an app
+/// restart are reported and queued again on the next launch.
+#[derive(Default)]
+pub struct TranscriptionQueue {
+    shared: Arc<(Mutex<QueueState>, Condvar)>,
+}
+
+impl TranscriptionQueue {
+    pub fn enqueue(
+        &self,
+        app: &AppHandle,
+        job: TranscriptionJob,
+        runner: JobRunner,
+    ) -> Result<Receiver<JobOutcome>, String> {
+        let settings_json =
+            serde_json::to_string(&job.settings).map_err(|e| format!("job_settings: {e}"))?;
+        let conn = db::open_global(app)?;
+        repo::insert_job(
+            &conn,
+            &job.id,
+            &job.profile_id,
+            &job.audio_artifact_id,
+            &settings_json,
+            &time::now_rfc3339(),
+        )?;
+
+        let (tx, rx) = mpsc::channel();
+        let (lock, ready) = &*self.shared;
+        let mut state = lock.lock().map_err(|_| "transcription_queue_lock")?;
+        state.waiters.insert(job.id.clone(), tx);
+        state.pending.push_back(job);
+        self.ensure_worker(&mut state, app, runner);
+        ready.notify_one();
+        Ok(rx)
+    }
+
+    /// Queues again the jobs an earlier session left unfinished.
+    pub fn resume_interrupted(&self, app: &AppHandle, runner: JobRunner) -> Result<usize, String> {
+        let mut conn = db::open_global(app)?;
+        let now = time::now_rfc3339();
+        let rows = repo::recover_interrupted_jobs(&mut conn, MAX_INTERRUPTIONS, &now)?;
+        let mut jobs = Vec::with_capacity(rows.len());
+        for row in rows {
+            match serde_json::from_str::<AsrRuntimeSettings>(&row.settings_json) {
+                Ok(settings) => jobs.push(TranscriptionJob {
+                    id: row.id,
+                    profile_id: row.profile_id,
+                    audio_artifact_id: row.audio_artifact_id,
+                    settings,
+                }),
+                Err(_) => repo::update_job_status(
+                    &conn,
+                    &row.id,
+                    "failed",
+                    None,
+                    Some("job_settings_invalid"),
+                    &now,
+                )?,
+            }
+        }
+        let resumed = jobs.len();
+        if resumed == 0 {
+            return Ok(0);
+        }
+
+        let (lock, ready) = &*self.shared;
+        let mut state = lock.lock().map_err(|_| "transcription_queue_lock")?;
+        state.pending.extend(jobs);
+        self.ensure_worker(&mut state, app, runner);
+        ready.notify_one();
+        Ok(resumed)
+    }
+
+    /// A queued job is dropped right away; a running one is asked to stop
+    /// and reports `transcription_cancelled` once it has.
+    pub fn cancel(&self, app: &AppHandle, job_id: &str) -> Result<(), String> {
+        let (lock, _) = &*self.shared;
+        let mut state = lock.lock().map_err(|_| "transcription_queue_lock")?;
+        if let Some((running_id, cancel)) = &state.running {
+            if running_id == job_id {
+                cancel.store(true, Ordering::SeqCst);
+                return Ok(());
+            }
+        }
+        if state.remove_pending(job_id) {
+            let waiter = state.waiters.remove(job_id);
+            drop(state);
+            let conn = db::open_global(app)?;
+            repo::update_job_status(
+                &conn,
+                job_id,
+                "cancelled",
+                None,
+                Some(asr_sidecar::DECODE_CANCELLED),
+                &time::now_rfc3339(),
+            )?;
+            if let Some(waiter) = waiter {
+                let _ = waiter.send(Err(asr_sidecar::DECODE_CANCELLED.to_string()));
+            }
+            return Ok(());
+        }
+        drop(state);
+
+        let conn = db::open_global(app)?;
+        match repo::select_job_status(&conn, job_id)? {
+            Some(_) => Err("job_not_active".to_string()),
+            None => Err("job_not_found".to_string()),
+        }
+    }
+
+    pub fn list(
+        &self,
+        app: &AppHandle,
+        profile_id: Option<&str>,
+    ) -> Result<Vec<TranscriptionJobStatus>, String> {
+        let conn = db::open_global(app)?;
+        repo::select_jobs(&conn, profile_id, LIST_LIMIT)
+    }
+
+    fn ensure_worker(&self, state: &mut QueueState, app: &AppHandle, runner: JobRunner) {
+        if state.worker_started {
+            return;
+        }
+        state.worker_started = true;
+        let shared = self.shared.clone();
+        let app = app.clone();
+        thread::spawn(move || run_worker(&shared, &app, runner));
+    }
+}
+
+fn run_worker(shared: &(Mutex<QueueState>, Condvar), app: &AppHandle, runner: JobRunner) {
+    let (lock, ready) = shared;
+    loop {
+        let (job, cancel) = {
+            let Ok(mut state) = lock.lock() else {
+                return;
+            };
+            let job = loop {
+                if let Some(job) = state.pending.pop_front() {
+                    break job;
+                }
+                state = match ready.wait(state) {
+                    Ok(state) => state,
+                    Err(_) => return,
+                };
+            };
+            let cancel = Arc::new(AtomicBool::new(false));
+            state.running = Some((job.id.clone(), cancel.clone()));
+            (job, cancel)
+        };
+
+        record_status(app, &job.id, "running", None, None);
+        let outcome = runner(app, &job, &cancel);
+        match &outcome {
+            Ok(transcript_id) => {
+                record_status(app, &job.id, "completed", Some(transcript_id), None);
+            }
+            Err(err) if err == asr_sidecar::DECODE_CANCELLED => {
+                record_status(app, &job.id, "cancelled", None, Some(err));
+            }
+            Err(err) => record_status(app, &job.id, "failed", None, Some(error_code(err))),
+        }
+
+        let waiter = lock.lock().ok().and_then(|mut state| {
+            state.running = None;
+            state.waiters.remove(&job.id)
+        });
+        if let Some(waiter) = waiter {
+            let _ = waiter.send(outcome);
+        }
+    }
+}
+
+fn record_status(
+    app: &AppHandle,
+    job_id: &str,
+    status: &str,
+    transcript_id: Option<&str>,
+    error_code: Option<&str>,
+) {
+    let result = db::open_global(app).and_then(|conn| {
+        repo::update_job_status(
+            &conn,
+            job_id,
+            status,
+            transcript_id,
+            error_code,
+            &time::now_rfc3339(),
+        )
+    });
+    if let Err(err) = result {
+        eprintln!("transcription job {job_id} not recorded as {status}: {err}");
+    }
+}
+
+/// Error strings often carry details after a colon; the stored code keeps
+/// only the stable prefix.
+fn error_code(err: &str) -> &str {
+    err.split(':').next().unwrap_or(err).trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_codes_drop_details() {
+        assert_eq!(
+            error_code("sidecar_decode: decode: failed"),
+            "sidecar_decode"
+        );
+        assert_eq!(error_code("model_missing"), "model_missing");
+    }
+}
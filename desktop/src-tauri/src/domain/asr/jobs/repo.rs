@@ -0,0 +1,206 @@
+use super::TranscriptionJobStatus;
+use rusqlite::{params, Connection, OptionalExtension};
+
+pub struct PendingJobRow {
+    pub id: String,
+    pub profile_id: String,
+    pub audio_artifact_id: String,
+    pub settings_json: String,
+}
+
+pub fn insert_job(
+    conn: &Connection,
+    job_id: &str,
+    profile_id: &str,
+    audio_artifact_id: &str,
+    settings_json: &str,
+    now: &str,
+) -> Result<(), String> {
+    conn.execute(
+        "INSERT INTO transcription_jobs (id, profile_id, audio_artifact_id, settings_json, status, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, 'queued', ?5, ?5)",
+        params![job_id, profile_id, audio_artifact_id, settings_json, now],
+    )
+    .map_err(|e| format!("job_insert: {e}"))?;
+    Ok(())
+}
+
+pub fn update_job_status(
+    conn: &Connection,
+    job_id: &str,
+    status: &str,
+    transcript_id: Option<&str>,
+    error_code: Option<&str>,
+    now: &str,
+) -> Result<(), String> {
+    conn.execute(
+        "UPDATE transcription_jobs
+         SET status = ?2, transcript_id = ?3, error_code = ?4, updated_at = ?5
+         WHERE id = ?1",
+        params![job_id, status, transcript_id, error_code, now],
+    )
+    .map_err(|e| format!("job_update: {e}"))?;
+    Ok(())
+}
+
+/// Jobs left queued or running by a previous session count one more
+/// interruption; those interrupted too often fail, the others are queued
+/// again and returned oldest first.
+pub fn recover_interrupted_jobs(
+    conn: &mut Connection,
+    max_interruptions: i64,
+    now: &str,
+) -> Result<Vec<PendingJobRow>, String> {
+    let tx = conn.transaction().map_err(|e| format!("tx: {e}"))?;
+    tx.execute(
+        "UPDATE transcription_jobs
+         SET interrupted_count = interrupted_count + 1, updated_at = ?1
+         WHERE status IN ('queued', 'running')",
+        [now],
+    )
+    .map_err(|e| format!("job_interrupt: {e}"))?;
+    tx.execute(
+        "UPDATE transcription_jobs
+         SET status = 'failed', error_code = 'job_interrupted'
+         WHERE status IN ('queued', 'running') AND interrupted_count >= ?1",
+        [max_interruptions],
+    )
+    .map_err(|e| format!("job_abandon: {e}"))?;
+    tx.execute(
+        "UPDATE transcription_jobs SET status = 'queued' WHERE status = 'running'",
+        [],
+    )
+    .map_err(|e| format!("job_requeue: {e}"))?;
+    let rows = {
+        let mut stmt = tx
+            .prepare(
+                "SELECT id, profile_id, audio_artifact_id, settings_json
+                 FROM transcription_jobs
+                 WHERE status = 'queued'
+                 ORDER BY created_at ASC, id ASC",
+            )
+            .map_err(|e| format!("job_prepare: {e}"))?;
+        let rows = stmt
+            .query_map([], |row| {
+                Ok(PendingJobRow {
+                    id: row.get(0)?,
+                    profile_id: row.get(1)?,
+                    audio_artifact_id: row.get(2)?,
+                    settings_json: row.get(3)?,
+                })
+            })
+            .map_err(|e| format!("job_query: {e}"))?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| format!("job_row: {e}"))?
+    };
+    tx.commit().map_err(|e| format!("commit: {e}"))?;
+    Ok(rows)
+}
+
+pub fn select_job_status(conn: &Connection, job_id: &str) -> Result<Option<String>, String> {
+    conn.query_row(
+        "SELECT status FROM transcription_jobs WHERE id = ?1",
+        [job_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(|e| format!("job_query: {e}"))
+}
+
+pub fn select_jobs(
+    conn: &Connection,
+    profile_id: Option<&str>,
+    limit: i64,
+) -> Result<Vec<TranscriptionJobStatus>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, profile_id, audio_artifact_id, settings_json, status, transcript_id,
+                    error_code, interrupted_count, created_at, updated_at
+             FROM transcription_jobs
+             WHERE ?1 IS NULL OR profile_id = ?1
+             ORDER BY created_at DESC, id DESC
+             LIMIT ?2",
+        )
+        .map_err(|e| format!("job_prepare: {e}"))?;
+    let rows = stmt
+        .query_map(params![profile_id, limit], |row| {
+            let settings_json: String = row.get(3)?;
+            let settings: Option<serde_json::Value> = serde_json::from_str(&settings_json).ok();
+            let setting = |key: &str| {
+                settings
+                    .as_ref()
+                    .and_then(|settings| settings.get(key))
+                    .and_then(|value| value.as_str())
+                    .map(ToOwned::to_owned)
+            };
+            Ok(TranscriptionJobStatus {
+                job_id: row.get(0)?,
+                profile_id: row.get(1)?,
+                audio_artifact_id: row.get(2)?,
+                model_id: setting("model_id"),
+                language: setting("language"),
+                status: row.get(4)?,
+                transcript_id: row.get(5)?,
+                error_code: row.get(6)?,
+                interrupted_count: row.get(7)?,
+                created_at: row.get(8)?,
+                updated_at: row.get(9)?,
+            })
+        })
+        .map_err(|e| format!("job_query: {e}"))?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("job_row: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jobs_db() -> Connection {
+        let conn = Connection::open_in_memory().expect("open");
+        conn.execute_batch(include_str!(
+            "../../../../../../migrations/global/0002_transcription_jobs.sql"
+        ))
+        .expect("schema");
+        conn
+    }
+
+    #[test]
+    fn interrupted_jobs_are_requeued_until_abandoned() {
+        let mut conn = jobs_db();
+        let settings = r#"{"model_id":"tiny","language":"fr","spoken_punctuation":false}"#;
+        for (id, at) in [("job_a", "t1"), ("job_b", "t2"), ("job_c", "t3")] {
+            insert_job(&conn, id, "prof_1", "audio_1", settings, at).expect("insert");
+        }
+        update_job_status(&conn, "job_a", "running", None, None, "t4").expect("running");
+        update_job_status(&conn, "job_c", "completed", Some("tr_1"), None, "t5").expect("done");
+
+        let pending = recover_interrupted_jobs(&mut conn, 2, "t6").expect("recover");
+        let ids: Vec<&str> = pending.iter().map(|row| row.id.as_str()).collect();
+        assert_eq!(ids, vec!["job_a", "job_b"]);
+        assert_eq!(pending[0].settings_json, settings);
+        assert_eq!(
+            select_job_status(&conn, "job_a")
+                .expect("status")
+                .as_deref(),
+            Some("queued")
+        );
+
+        let pending = recover_interrupted_jobs(&mut conn, 2, "t7").expect("recover");
+        assert!(pending.is_empty());
+        let jobs = select_jobs(&conn, Some("prof_1"), 10).expect("jobs");
+        assert_eq!(jobs.len(), 3);
+        assert_eq!(jobs[0].job_id, "job_c");
+        assert_eq!(jobs[0].transcript_id.as_deref(), Some("tr_1"));
+        assert_eq!(jobs[0].model_id.as_deref(), Some("tiny"));
+        let abandoned = &jobs[2];
+        assert_eq!(abandoned.status, "failed");
+        assert_eq!(abandoned.error_code.as_deref(), Some("job_interrupted"));
+        assert_eq!(abandoned.interrupted_count, 2);
+
+        assert!(select_jobs(&conn, Some("prof_2"), 10)
+            .expect("jobs")
+            .is_empty());
+        assert_eq!(select_job_status(&conn, "job_x").expect("status"), None);
+    }
+}
@@ -135,6 +135,7 @@ impl LiveDecoder for MockAsrDecoder {
                 t_end_ms: end,
                 text: format!("(speech {speech_index}.{segment_index})"),
                 confidence: None,
+                words: Vec::new(),
             });
             segment_index += 1;
             cursor = end;
@@ -1,16 +1,20 @@
 pub mod asr_live;
 pub mod asr_models;
+mod chunking;
 mod diagnostics;
 mod downloader;
+mod jobs;
 mod live_decoder;
 mod settings;
 pub mod transcript;
 
+use crate::domain::recorder;
 use crate::kernel::models;
 use crate::platform::asr_sidecar;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Instant;
-use tauri::AppHandle;
+use tauri::{AppHandle, Manager};
 
 const SIDECAR_ENV_PATH: &str = "LEPUPITRE_ASR_SIDECAR";
 const SIDECAR_MODEL_ENV_PATH: &str = "LEPUPITRE_ASR_MODEL_PATH";
@@ -23,6 +27,7 @@ pub use diagnostics::{
     AsrDiagnosticsSidecar,
 };
 pub use downloader::download_model_blocking;
+pub use jobs::{TranscriptionJob, TranscriptionJobStatus, TranscriptionQueue};
 pub use live_decoder::{benchmark_live_sidecar, LiveDecoder, MockAsrDecoder, SidecarLiveDecoder};
 
 pub use settings::{
@@ -31,6 +36,16 @@ pub use settings::{
 };
 
 pub fn decode_wav_mono_16k(bytes: &[u8]) -> Result<(Vec<f32>, i64), String> {
+    if recorder::storage::AudioStorageFormat::detect(bytes)
+        == recorder::storage::AudioStorageFormat::Flac
+    {
+        let samples: Vec<f32> = recorder::flac::decode_flac_pcm16_mono_16k(bytes)?
+            .into_iter()
+            .map(|sample| sample as f32 / 32768.0)
+            .collect();
+        let duration_ms = recorder::duration_ms_from_sample_count(samples.len(), 16_000);
+        return Ok((samples, duration_ms));
+    }
     if bytes.len() < 44 {
         return Err("wav_header".to_string());
     }
@@ -70,6 +85,7 @@ pub fn decode_with_sidecar<F>(
     settings: &AsrRuntimeSettings,
     samples: &[f32],
     duration_ms: i64,
+    cancel: &AtomicBool,
     mut on_progress: F,
 ) -> Result<Vec<models::TranscriptSegment>, String>
 where
@@ -78,77 +94,88 @@ where
     let sidecar_path = resolve_sidecar_path(app)?;
     let model_path = resolve_model_path(app, &settings.model_id)?;
 
-    let mut decoder =
-        asr_sidecar::SidecarDecoder::spawn(&sidecar_path, &model_path, &settings.language)?;
-
-    let total_ms = duration_ms.max(0);
-    let chunk_ms: i64 = 12_000;
-    let sample_rate = 16_000i64;
-    let mut segments = Vec::new();
-    let mut cursor_ms = 0i64;
+    let pool = app.state::<asr_sidecar::SidecarPool>();
+    pool.run(
+        &settings.model_id,
+        &settings.language,
+        &sidecar_path,
+        &model_path,
+        |decoder| {
+            let total_ms = duration_ms.max(0);
+            let sample_rate = 16_000i64;
+            let mut segments = Vec::new();
+            let mut reported_ms = 0i64;
 
-    on_progress(0, total_ms);
+            on_progress(0, total_ms);
 
-    while cursor_ms < total_ms {
-        let end_ms = (cursor_ms + chunk_ms).min(total_ms);
-        let start_idx = (cursor_ms * sample_rate / 1000).max(0) as usize;
-        let end_idx = (end_ms * sample_rate / 1000).max(0) as usize;
-        if start_idx >= samples.len() {
-            break;
-        }
-        let end_idx = end_idx.min(samples.len());
-        let chunk = &samples[start_idx..end_idx];
-        let chunk_ms = (end_ms - cursor_ms).max(0) as f64;
-        let decode_start = Instant::now();
-        let mut chunk_segments = decoder.decode_window_with_progress(
-            chunk,
-            cursor_ms,
-            end_ms,
-            asr_sidecar::DecodeMode::Final,
-            |processed_ms: i64, total_chunk_ms: i64| {
-                if total_chunk_ms <= 0 {
-                    return;
+            for window in chunking::plan_chunks(samples, sample_rate as u32) {
+                if cancel.load(Ordering::SeqCst) {
+                    return Err(asr_sidecar::DECODE_CANCELLED.to_string());
                 }
-                let clamped = processed_ms.clamp(0, total_chunk_ms);
-                let absolute_ms = (cursor_ms + clamped).min(total_ms);
-                on_progress(absolute_ms, total_ms);
-            },
-        )?;
-        if chunk_segments.len() > MAX_FINAL_SEGMENTS_PER_CHUNK {
-            eprintln!(
-                "asr final decode truncated: {} segments in chunk (limit {})",
-                chunk_segments.len(),
-                MAX_FINAL_SEGMENTS_PER_CHUNK
-            );
-            chunk_segments.truncate(MAX_FINAL_SEGMENTS_PER_CHUNK);
-        }
-        if chunk_ms > 0.0 {
-            let elapsed_ms = decode_start.elapsed().as_millis() as f64;
-            let ratio = elapsed_ms / chunk_ms;
-            if ratio > FINAL_SLOW_DECODE_RATIO {
-                eprintln!(
-                    "asr final decode slow: {:.2}x ({}ms for {}ms window)",
-                    ratio,
-                    elapsed_ms.round() as i64,
-                    chunk_ms.round() as i64
-                );
+                let start_idx = (window.start_ms * sample_rate / 1000).max(0) as usize;
+                let end_idx = (window.end_ms * sample_rate / 1000).max(0) as usize;
+                if start_idx >= samples.len() {
+                    break;
+                }
+                let end_idx = end_idx.min(samples.len());
+                let chunk = &samples[start_idx..end_idx];
+                let chunk_ms = (window.end_ms - window.start_ms).max(0) as f64;
+                let decode_start = Instant::now();
+                let mut chunk_segments = decoder.decode_window_cancellable(
+                    chunk,
+                    window.start_ms,
+                    window.end_ms,
+                    asr_sidecar::DecodeMode::Final,
+                    cancel,
+                    |processed_ms: i64, total_chunk_ms: i64| {
+                        if total_chunk_ms <= 0 {
+                            return;
+                        }
+                        let clamped = processed_ms.clamp(0, total_chunk_ms);
+                        let absolute_ms = (window.start_ms + clamped).min(total_ms);
+                        if absolute_ms > reported_ms {
+                            reported_ms = absolute_ms;
+                            on_progress(absolute_ms, total_ms);
+                        }
+                    },
+                )?;
+                if chunk_segments.len() > MAX_FINAL_SEGMENTS_PER_CHUNK {
+                    eprintln!(
+                        "asr final decode truncated: {} segments in chunk (limit {})",
+                        chunk_segments.len(),
+                        MAX_FINAL_SEGMENTS_PER_CHUNK
+                    );
+                    chunk_segments.truncate(MAX_FINAL_SEGMENTS_PER_CHUNK);
+                }
+                if chunk_ms > 0.0 {
+                    let elapsed_ms = decode_start.elapsed().as_millis() as f64;
+                    let ratio = elapsed_ms / chunk_ms;
+                    if ratio > FINAL_SLOW_DECODE_RATIO {
+                        eprintln!(
+                            "asr final decode slow: {:.2}x ({}ms for {}ms window)",
+                            ratio,
+                            elapsed_ms.round() as i64,
+                            chunk_ms.round() as i64
+                        );
+                    }
+                }
+                chunking::stitch_segments(&mut segments, chunk_segments, window.keep_from_ms);
+                if segments.len() > MAX_FINAL_SEGMENTS_TOTAL {
+                    eprintln!(
+                        "asr final decode capped: {} segments total (limit {})",
+                        segments.len(),
+                        MAX_FINAL_SEGMENTS_TOTAL
+                    );
+                    segments.truncate(MAX_FINAL_SEGMENTS_TOTAL);
+                    break;
+                }
+                reported_ms = reported_ms.max(window.end_ms);
+                on_progress(reported_ms, total_ms);
             }
-        }
-        segments.append(&mut chunk_segments);
-        if segments.len() > MAX_FINAL_SEGMENTS_TOTAL {
-            eprintln!(
-                "asr final decode capped: {} segments total (limit {})",
-                segments.len(),
-                MAX_FINAL_SEGMENTS_TOTAL
-            );
-            segments.truncate(MAX_FINAL_SEGMENTS_TOTAL);
-            break;
-        }
-        on_progress(end_ms, total_ms);
-        cursor_ms = end_ms;
-    }
 
-    Ok(segments)
+            Ok(segments)
+        },
+    )
 }
 
 fn resolve_sidecar_path(app: &AppHandle) -> Result<PathBuf, String> {
@@ -167,9 +194,9 @@ fn resolve_model_path(app: &AppHandle, model_id: &str) -> Result<PathBuf, String
         return Ok(PathBuf::from(path));
     }
 
-    let spec = asr_models::model_spec(model_id).ok_or_else(|| "model_unknown".to_string())?;
+    let spec = asr_models::model_spec(app, model_id)?;
     let dir = asr_models::models_dir(app)?;
-    let path = dir.join(spec.filename);
+    let path = dir.join(&spec.filename);
     if !path.exists() {
         return Err("model_missing".to_string());
     }
@@ -192,11 +219,14 @@ mod tests {
 
     #[test]
     fn normalize_recording_settings_enables_auto_benchmark_for_tiny_auto() {
-        let settings = normalize_recording_settings(Some(RecordingAsrSettingsPayload {
-            model: Some("tiny".to_string()),
-            mode: Some("auto".to_string()),
-            language: Some("en".to_string()),
-        }));
+        let settings = normalize_recording_settings(
+            Some(RecordingAsrSettingsPayload {
+                model: Some("tiny".to_string()),
+                mode: Some("auto".to_string()),
+                language: Some("en".to_string()),
+            }),
+            asr_models::catalog_specs(),
+        );
         assert_eq!(settings.model_id, "tiny");
         assert_eq!(settings.language, "en");
         assert!(settings.live_enabled);
@@ -205,14 +235,53 @@ mod tests {
 
     #[test]
     fn normalize_recording_settings_disables_live_for_final_only() {
-        let settings = normalize_recording_settings(Some(RecordingAsrSettingsPayload {
-            model: Some("base".to_string()),
-            mode: Some("final-only".to_string()),
-            language: Some("fr".to_string()),
-        }));
+        let settings = normalize_recording_settings(
+            Some(RecordingAsrSettingsPayload {
+                model: Some("base".to_string()),
+                mode: Some("final-only".to_string()),
+                language: Some("fr".to_string()),
+            }),
+            asr_models::catalog_specs(),
+        );
         assert_eq!(settings.model_id, "base");
         assert_eq!(settings.language, "fr");
         assert!(!settings.live_enabled);
         assert!(!settings.auto_benchmark);
     }
+
+    #[test]
+    fn normalize_transcription_settings_accepts_catalog_models() {
+        let catalog = asr_models::catalog_specs();
+        let settings = normalize_transcription_settings(
+            Some(TranscriptionAsrSettingsPayload {
+                model: Some("base".to_string()),
+                language: Some("fr".to_string()),
+                spoken_punctuation: None,
+            }),
+            catalog,
+        );
+        assert_eq!(settings.model_id, "base");
+        assert_eq!(settings.language, "fr");
+
+        let settings = normalize_transcription_settings(
+            Some(TranscriptionAsrSettingsPayload {
+                model: Some("small.en".to_string()),
+                language: Some("fr".to_string()),
+                spoken_punctuation: None,
+            }),
+            catalog,
+        );
+        assert_eq!(settings.model_id, "small.en");
+        assert_eq!(settings.language, "en");
+
+        let settings = normalize_transcription_settings(
+            Some(TranscriptionAsrSettingsPayload {
+                model: Some("custom-unknown".to_string()),
+                language: None,
+                spoken_punctuation: None,
+            }),
+            catalog,
+        );
+        assert_eq!(settings.model_id, "tiny");
+    }
 }
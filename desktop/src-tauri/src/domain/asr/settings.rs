@@ -1,4 +1,5 @@
-use serde::Deserialize;
+use super::asr_models::AsrModelSpec;
+use serde::{Deserialize, Serialize};
 
 const DEFAULT_MODEL_ID: &str = "tiny";
 
@@ -20,7 +21,7 @@ pub struct RecordingAsrSettingsPayload {
     pub language: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AsrRuntimeSettings {
     pub model_id: String,
     pub language: String,
@@ -37,6 +38,7 @@ pub struct RecordingAsrRuntimeSettings {
 
 pub fn normalize_transcription_settings(
     payload: Option<TranscriptionAsrSettingsPayload>,
+    models: &[AsrModelSpec],
 ) -> AsrRuntimeSettings {
     let mut model_id = DEFAULT_MODEL_ID.to_string();
     let mut language = "auto".to_string();
@@ -44,7 +46,7 @@ pub fn normalize_transcription_settings(
 
     if let Some(payload) = payload {
         if let Some(model) = payload.model.as_deref() {
-            if model == "tiny" || model == "base" {
+            if models.iter().any(|spec| spec.id == model) {
                 model_id = model.to_string();
             }
         }
@@ -58,6 +60,10 @@ pub fn normalize_transcription_settings(
         }
     }
 
+    if is_english_only(models, &model_id) {
+        language = "en".to_string();
+    }
+
     AsrRuntimeSettings {
         model_id,
         language,
@@ -67,6 +73,7 @@ pub fn normalize_transcription_settings(
 
 pub fn normalize_recording_settings(
     payload: Option<RecordingAsrSettingsPayload>,
+    models: &[AsrModelSpec],
 ) -> RecordingAsrRuntimeSettings {
     let mut model_id = DEFAULT_MODEL_ID.to_string();
     let mut language = "auto".to_string();
@@ -75,7 +82,7 @@ pub fn normalize_recording_settings(
 
     if let Some(payload) = payload {
         if let Some(model) = payload.model.as_deref() {
-            if model == "tiny" || model == "base" {
+            if models.iter().any(|spec| spec.id == model) {
                 model_id = model.to_string();
             }
         }
@@ -93,6 +100,10 @@ pub fn normalize_recording_settings(
         }
     }
 
+    if is_english_only(models, &model_id) {
+        language = "en".to_string();
+    }
+
     RecordingAsrRuntimeSettings {
         model_id,
         language,
@@ -100,3 +111,9 @@ pub fn normalize_recording_settings(
         auto_benchmark,
     }
 }
+
+fn is_english_only(models: &[AsrModelSpec], model_id: &str) -> bool {
+    models
+        .iter()
+        .any(|spec| spec.id == model_id && spec.english_only)
+}
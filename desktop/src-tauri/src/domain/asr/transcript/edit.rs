@@ -44,6 +44,7 @@ pub fn build_edited_transcript(
                 t_end_ms: end.max(cursor + 1),
                 text: line,
                 confidence: None,
+                words: Vec::new(),
             });
             cursor = end.max(cursor + 1);
         }
@@ -81,6 +82,7 @@ pub fn build_edited_transcript(
                 t_end_ms: end_ms,
                 text: line,
                 confidence: None,
+                words: Vec::new(),
             });
             previous_end = end_ms;
         }
@@ -92,6 +94,7 @@ pub fn build_edited_transcript(
         model_id: source.model_id.clone(),
         duration_ms: Some(duration_ms),
         segments,
+        markers: source.markers.clone(),
     })
 }
 
@@ -125,7 +128,9 @@ mod tests {
                 t_end_ms: 4000,
                 text: "hello".to_string(),
                 confidence: Some(0.8),
+                words: Vec::new(),
             }],
+            markers: Vec::new(),
         };
         let edited = build_edited_transcript(&source, "line one\nline two").expect("edited");
         assert_eq!(edited.language, "en");
@@ -149,26 +154,31 @@ mod tests {
                     t_end_ms: 1_000,
                     text: "a".to_string(),
                     confidence: Some(0.8),
+                    words: Vec::new(),
                 },
                 models::TranscriptSegment {
                     t_start_ms: 1_000,
                     t_end_ms: 2_000,
                     text: "b".to_string(),
                     confidence: Some(0.8),
+                    words: Vec::new(),
                 },
                 models::TranscriptSegment {
                     t_start_ms: 2_000,
                     t_end_ms: 3_000,
                     text: "c".to_string(),
                     confidence: Some(0.8),
+                    words: Vec::new(),
                 },
                 models::TranscriptSegment {
                     t_start_ms: 3_000,
                     t_end_ms: 6_000,
                     text: "d".to_string(),
                     confidence: Some(0.8),
+                    words: Vec::new(),
                 },
             ],
+            markers: Vec::new(),
         };
 
         let edited = build_edited_transcript(&source, "line one\nline two").expect("edited");
@@ -191,7 +201,9 @@ mod tests {
                 t_end_ms: 1000,
                 text: "hello".to_string(),
                 confidence: None,
+                words: Vec::new(),
             }],
+            markers: Vec::new(),
         };
         let err = build_edited_transcript(&source, " \n ").expect_err("empty");
         assert_eq!(err, "transcript_empty");
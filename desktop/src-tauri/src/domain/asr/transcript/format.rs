@@ -45,7 +45,11 @@ pub fn transcript_to_vtt(transcript: &models::TranscriptV1) -> Result<String, St
         return Err("transcript_empty".to_string());
     }
     let mut out = String::from("WEBVTT\n\n");
+    let mut markers = transcript.markers.iter().peekable();
     for segment in &transcript.segments {
+        while let Some(marker) = markers.next_if(|marker| marker.t_ms < segment.t_start_ms) {
+            push_vtt_marker_note(&mut out, marker);
+        }
         let start = format_vtt_timestamp(segment.t_start_ms);
         let end = format_vtt_timestamp(segment.t_end_ms);
         out.push_str(&format!("{start} --> {end}\n"));
@@ -53,9 +57,22 @@ pub fn transcript_to_vtt(transcript: &models::TranscriptV1) -> Result<String, St
         out.push('\n');
         out.push('\n');
     }
+    for marker in markers {
+        push_vtt_marker_note(&mut out, marker);
+    }
     Ok(out)
 }
 
+/// Recording markers become NOTE blocks, which players ignore.
+fn push_vtt_marker_note(out: &mut String, marker: &models::RecordingMarker) {
+    let label = marker.label.replace("-->", "->").replace('\n', " ");
+    out.push_str(&format!(
+        "NOTE {} {}\n\n",
+        format_vtt_timestamp(marker.t_ms),
+        label.trim()
+    ));
+}
+
 fn format_srt_timestamp(ms: i64) -> String {
     let total_ms = ms.max(0);
     let hours = total_ms / 3_600_000;
@@ -90,7 +107,9 @@ mod tests {
                 t_end_ms: 1200,
                 text: "Hello".to_string(),
                 confidence: None,
+                words: Vec::new(),
             }],
+            markers: Vec::new(),
         };
         let srt = transcript_to_srt(&transcript).expect("srt");
         assert!(srt.contains("00:00:00,000 --> 00:00:01,200"));
@@ -108,9 +127,40 @@ mod tests {
                 t_end_ms: 1200,
                 text: "Hello".to_string(),
                 confidence: None,
+                words: Vec::new(),
             }],
+            markers: Vec::new(),
         };
         let vtt = transcript_to_vtt(&transcript).expect("vtt");
         assert!(vtt.contains("00:00:00.000 --> 00:00:01.200"));
     }
+
+    #[test]
+    fn transcript_to_vtt_interleaves_marker_notes() {
+        let segment = |t_start_ms: i64, t_end_ms: i64, text: &str| models::TranscriptSegment {
+            t_start_ms,
+            t_end_ms,
+            text: text.to_string(),
+            confidence: None,
+            words: Vec::new(),
+        };
+        let marker = |t_ms: i64, label: &str| models::RecordingMarker {
+            t_ms,
+            label: label.to_string(),
+        };
+        let transcript = models::TranscriptV1 {
+            schema_version: "1.0.0".to_string(),
+            language: "en".to_string(),
+            model_id: None,
+            duration_ms: Some(5_000),
+            segments: vec![segment(0, 1_000, "Hello"), segment(2_000, 3_000, "Next")],
+            markers: vec![marker(1_500, "new slide"), marker(4_000, "a --> b")],
+        };
+        let vtt = transcript_to_vtt(&transcript).expect("vtt");
+        let hello = vtt.find("Hello").expect("hello");
+        let note = vtt.find("NOTE 00:00:01.500 new slide").expect("note");
+        let next = vtt.find("Next").expect("next");
+        assert!(hello < note && note < next);
+        assert!(vtt.ends_with("NOTE 00:00:04.000 a -> b\n\n"));
+    }
 }
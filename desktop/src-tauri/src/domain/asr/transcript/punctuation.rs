@@ -24,6 +24,7 @@ pub fn apply_spoken_punctuation(
                 t_end_ms: segment.t_end_ms,
                 text,
                 confidence: segment.confidence,
+                words: segment.words.clone(),
             }
         })
         .collect()
@@ -113,6 +114,7 @@ mod tests {
             t_end_ms: 1000,
             text: "bonjour virgule retour a la ligne point".to_string(),
             confidence: None,
+            words: Vec::new(),
         }];
         let updated = apply_spoken_punctuation(&segments, "fr");
         assert_eq!(updated[0].text, "bonjour,\n.");
@@ -125,6 +127,7 @@ mod tests {
             t_end_ms: 1000,
             text: "hello comma new line period".to_string(),
             confidence: None,
+            words: Vec::new(),
         }];
         let updated = apply_spoken_punctuation(&segments, "en");
         assert_eq!(updated[0].text, "hello,\n.");
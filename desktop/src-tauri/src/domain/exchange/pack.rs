@@ -1,9 +1,13 @@
 use crate::domain::asr::transcript;
+use crate::domain::recorder;
+use crate::domain::recorder::markers;
+use crate::domain::recorder::storage::AudioStorageFormat;
 use crate::kernel::models;
 use crate::kernel::{ids, time};
 use crate::platform::artifacts;
 use crate::platform::db;
 use std::fs::File;
+use std::io::Read;
 use std::path::PathBuf;
 use zip::write::FileOptions;
 use zip::{CompressionMethod, ZipWriter};
@@ -35,6 +39,8 @@ pub fn pack_export(
     let audio = repo::load_artifact(&conn, &audio_id, "audio")?;
     let transcript_artifact = repo::load_artifact(&conn, &transcript_id, "transcript")?;
 
+    let audio_path = artifact_path(&app, &profile_id, &audio.relpath)?;
+    let expanded_audio = expand_flac_audio(&audio_path)?;
     let transcript_path = artifact_path(&app, &profile_id, &transcript_artifact.relpath)?;
     let transcript_doc = transcript::load_transcript(&app, &profile_id, &transcript_id)?;
     let duration_ms = transcript_doc
@@ -53,8 +59,12 @@ pub fn pack_export(
         PackFileEntry {
             path: "run/audio.wav".to_string(),
             role: "audio".to_string(),
-            sha256: audio.sha256.clone(),
-            bytes: audio.bytes,
+            sha256: expanded_audio
+                .as_deref()
+                .map_or_else(|| audio.sha256.clone(), archive::sha256_hex),
+            bytes: expanded_audio
+                .as_ref()
+                .map_or(audio.bytes, |bytes| bytes.len() as u64),
             mime: "audio/wav".to_string(),
         },
         PackFileEntry {
@@ -106,6 +116,7 @@ pub fn pack_export(
         run: PackRun {
             run_id: run_id.clone(),
             duration_ms,
+            markers: markers::audio_markers(&app, &profile_id, &audio_id),
         },
         files,
     };
@@ -123,12 +134,10 @@ pub fn pack_export(
     let stored = FileOptions::default().compression_method(CompressionMethod::Stored);
     let deflated = FileOptions::default().compression_method(CompressionMethod::Deflated);
 
-    archive::write_file_from_disk(
-        &mut zip,
-        "run/audio.wav",
-        &artifact_path(&app, &profile_id, &audio.relpath)?,
-        stored,
-    )?;
+    match &expanded_audio {
+        Some(bytes) => archive::write_bytes(&mut zip, "run/audio.wav", bytes, stored)?,
+        None => archive::write_file_from_disk(&mut zip, "run/audio.wav", &audio_path, stored)?,
+    }
     archive::write_file_from_disk(&mut zip, "run/transcript.json", &transcript_path, deflated)?;
     archive::write_bytes(&mut zip, "run/outline.md", &outline_bytes, deflated)?;
     archive::write_bytes(&mut zip, "rubric/rubric.json", &rubric_bytes, deflated)?;
@@ -171,3 +180,22 @@ pub(super) fn artifact_path(
 ) -> Result<PathBuf, String> {
     artifacts::resolve_profile_relpath_for_read(app, profile_id, relpath)
 }
+
+/// Packs always ship `run/audio.wav` so reviewers can open them anywhere.
+/// FLAC-stored runs are expanded back to PCM16 WAV in memory; `None` means
+/// the artifact already is WAV and is streamed from disk as stored.
+fn expand_flac_audio(path: &std::path::Path) -> Result<Option<Vec<u8>>, String> {
+    let mut magic = Vec::with_capacity(4);
+    File::open(path)
+        .and_then(|file| file.take(4).read_to_end(&mut magic))
+        .map_err(|e| format!("pack_audio_read: {e}"))?;
+    if magic == b"RIFF" {
+        return Ok(None);
+    }
+    let bytes = std::fs::read(path).map_err(|e| format!("pack_audio_read: {e}"))?;
+    if AudioStorageFormat::detect(&bytes) == AudioStorageFormat::Wav {
+        return Ok(None);
+    }
+    let samples = recorder::decode_audio_pcm16_mono_16k(&bytes)?;
+    AudioStorageFormat::Wav.encode(&samples).map(Some)
+}
@@ -1,5 +1,6 @@
 use super::archive;
 use super::types::PackManifestV1;
+use crate::kernel::models;
 use crate::platform::db;
 use serde::Serialize;
 use std::collections::HashMap;
@@ -28,6 +29,7 @@ pub struct PackInspectResponse {
     pub project_id: String,
     pub run_id: String,
     pub duration_ms: i64,
+    pub markers: Vec<models::RecordingMarker>,
     pub reviewer_tag: Option<String>,
     pub files: Vec<PackFileSummary>,
 }
@@ -123,6 +125,7 @@ pub fn pack_inspect(
         project_id: manifest.project_id,
         run_id: manifest.run.run_id,
         duration_ms: manifest.run.duration_ms,
+        markers: manifest.run.markers,
         reviewer_tag,
         files,
     })
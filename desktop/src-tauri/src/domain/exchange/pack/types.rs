@@ -1,3 +1,4 @@
+use crate::kernel::models::RecordingMarker;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -16,6 +17,8 @@ pub(super) struct PackManifestV1 {
 pub(super) struct PackRun {
     pub(super) run_id: String,
     pub(super) duration_ms: i64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(super) markers: Vec<RecordingMarker>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
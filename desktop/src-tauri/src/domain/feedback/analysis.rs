@@ -1,146 +1,105 @@
+use super::analyzers::{AnalysisInput, Analyzer, CandidateAction, FeedbackDraft};
+use super::language;
+use super::lexicon::Lexicon;
 use crate::kernel::models;
 use std::collections::{HashMap, HashSet};
 
-pub fn build_feedback_from_text(text: &str, estimated_sec: i64) -> models::FeedbackV1 {
-    let tokens = tokenize(text);
-    let word_count = tokens.len() as f64;
-    let sentence_count_val = sentence_count(text).max(1) as f64;
-    let avg_sentence_words = if word_count > 0.0 {
-        word_count / sentence_count_val
-    } else {
-        0.0
-    };
-
-    let duration_min = (estimated_sec.max(30) as f64) / 60.0;
-    let wpm = if duration_min > 0.0 {
-        word_count / duration_min
-    } else {
-        0.0
-    };
-
-    let filler_count = count_fillers(text, &tokens) as f64;
-    let filler_per_min = if duration_min > 0.0 {
-        filler_count / duration_min
-    } else {
-        0.0
-    };
-
-    let pause_count = sentence_count(text).saturating_sub(1) as i64;
-    let repeat_terms = top_repeats(&tokens, 5);
-    let jargon_terms = extract_jargon_terms(text);
-    let density_score = (avg_sentence_words * 4.0).min(100.0);
-
-    let mut overall_score = 90.0;
-    overall_score -= filler_per_min * 2.5;
-    if avg_sentence_words > 18.0 {
-        overall_score -= (avg_sentence_words - 18.0) * 1.5;
-    }
-    if repeat_terms.len() > 3 {
-        overall_score -= (repeat_terms.len() as f64 - 3.0) * 1.5;
-    }
-    let overall_score = overall_score.clamp(0.0, 100.0).round() as i64;
-
-    let mut actions = Vec::new();
-    if filler_per_min > 2.0 {
-        actions.push(models::FeedbackAction {
-            action_id: "reduce_fillers".to_string(),
-            title: "Reduce filler words".to_string(),
-            why_it_matters: "Fillers dilute clarity and confidence.".to_string(),
-            how_to_fix: "Pause silently instead of using fillers.".to_string(),
-            target_quest_codes: vec!["D01".to_string()],
-        });
-    }
-    if avg_sentence_words > 18.0 {
-        actions.push(models::FeedbackAction {
-            action_id: "shorten_sentences".to_string(),
-            title: "Shorten long sentences".to_string(),
-            why_it_matters: "Shorter sentences keep ideas crisp.".to_string(),
-            how_to_fix: "Break each idea into a single line.".to_string(),
-            target_quest_codes: vec!["A01".to_string()],
-        });
-    }
-    if actions.len() < 2 && !repeat_terms.is_empty() {
-        actions.push(models::FeedbackAction {
-            action_id: "vary_terms".to_string(),
-            title: "Vary repeated terms".to_string(),
-            why_it_matters: "Repetition weakens perceived structure.".to_string(),
-            how_to_fix: "Swap repeated words with simpler alternatives.".to_string(),
-            target_quest_codes: vec!["A01".to_string()],
-        });
+pub const FEEDBACK_SCHEMA_VERSION: &str = "1.1.0";
+
+const BASE_SCORE: f64 = 90.0;
+const MAX_ACTIONS: usize = 2;
+const MAX_COMMENTS: usize = 7;
+
+pub struct TalkTarget {
+    pub duration_target_sec: Option<i64>,
+    pub outline_md: Option<String>,
+}
+
+pub fn build_feedback(
+    input: &AnalysisInput,
+    analyzers: &[Box<dyn Analyzer>],
+) -> models::FeedbackV1 {
+    let mut draft = FeedbackDraft::default();
+    for analyzer in analyzers {
+        analyzer.analyze(input, &mut draft);
     }
-    actions.truncate(2);
+    let mut feedback = merge(
+        draft,
+        analyzers
+            .iter()
+            .map(|analyzer| analyzer.id().to_string())
+            .collect(),
+    );
+    // Markers are the speaker's own notes, shown next to comments rather than
+    // competing with them for the comment slots.
+    feedback.markers = input.markers.to_vec();
+    feedback
+}
 
+fn merge(draft: FeedbackDraft, analyzer_ids: Vec<String>) -> models::FeedbackV1 {
+    let overall_score = (BASE_SCORE - draft.score_penalty).clamp(0.0, 100.0).round() as i64;
     models::FeedbackV1 {
-        schema_version: "1.0.0".to_string(),
+        schema_version: FEEDBACK_SCHEMA_VERSION.to_string(),
         overall_score,
-        top_actions: actions,
-        comments: Vec::new(),
-        metrics: models::FeedbackMetrics {
-            wpm,
-            filler_per_min,
-            pause_count,
-            avg_sentence_words,
-            repeat_terms,
-            jargon_terms,
-            density_score,
-        },
+        top_actions: select_actions(draft.actions),
+        comments: finalize_comments(draft.comments),
+        metrics: draft.metrics,
+        analyzers: analyzer_ids,
+        markers: Vec::new(),
     }
 }
 
-fn tokenize(text: &str) -> Vec<String> {
-    let mut out = Vec::new();
-    let mut current = String::new();
-    for ch in text.chars() {
-        if ch.is_ascii_alphanumeric() {
-            current.push(ch.to_ascii_lowercase());
-        } else if !current.is_empty() {
-            out.push(current.clone());
-            current.clear();
+fn select_actions(mut candidates: Vec<CandidateAction>) -> Vec<models::FeedbackAction> {
+    candidates.sort_by_key(|candidate| std::cmp::Reverse(candidate.priority));
+    let mut actions: Vec<models::FeedbackAction> = Vec::new();
+    for candidate in candidates {
+        if actions.len() >= MAX_ACTIONS {
+            break;
+        }
+        if actions
+            .iter()
+            .any(|action| action.action_id == candidate.action.action_id)
+        {
+            continue;
         }
+        actions.push(candidate.action);
     }
-    if !current.is_empty() {
-        out.push(current);
+    actions
+}
+
+fn finalize_comments(mut comments: Vec<models::FeedbackComment>) -> Vec<models::FeedbackComment> {
+    comments.sort_by(|a, b| {
+        severity_rank(&b.severity)
+            .cmp(&severity_rank(&a.severity))
+            .then_with(|| a.t_start_ms.cmp(&b.t_start_ms))
+    });
+    comments.truncate(MAX_COMMENTS);
+    comments.sort_by(|a, b| {
+        a.t_start_ms
+            .cmp(&b.t_start_ms)
+            .then_with(|| a.t_end_ms.cmp(&b.t_end_ms))
+    });
+    comments
+}
+
+fn severity_rank(severity: &str) -> u8 {
+    match severity {
+        "high" => 2,
+        "medium" => 1,
+        _ => 0,
     }
-    out
 }
 
-fn sentence_count(text: &str) -> usize {
+pub(super) fn sentence_count(text: &str) -> usize {
     text.split(['.', '!', '?', '\n'])
         .filter(|chunk| !chunk.trim().is_empty())
         .count()
 }
 
-fn count_fillers(text: &str, tokens: &[String]) -> usize {
-    let fillers = [
-        "um",
-        "uh",
-        "like",
-        "actually",
-        "basically",
-        "literally",
-        "sort",
-        "kind",
-        "okay",
-        "euh",
-        "bah",
-        "genre",
-    ];
-    let phrases = ["you know", "du coup"];
-    let mut count = tokens
-        .iter()
-        .filter(|token| fillers.contains(&token.as_str()))
-        .count();
-    let lower = text.to_ascii_lowercase();
-    for phrase in phrases {
-        count += lower.matches(phrase).count();
-    }
-    count
-}
-
-fn top_repeats(tokens: &[String], max: usize) -> Vec<String> {
+pub(super) fn top_repeats(tokens: &[String], max: usize, lexicon: &Lexicon) -> Vec<String> {
     let mut counts: HashMap<&str, usize> = HashMap::new();
     for token in tokens {
-        if token.len() < 3 || is_stopword(token) {
+        if token.chars().count() < 3 || lexicon.is_stopword(token) || lexicon.is_ignored(token) {
             continue;
         }
         *counts.entry(token.as_str()).or_insert(0) += 1;
@@ -154,29 +113,15 @@ fn top_repeats(tokens: &[String], max: usize) -> Vec<String> {
         .collect()
 }
 
-fn is_stopword(token: &str) -> bool {
-    const STOPWORDS_EN: &[&str] = &[
-        "the", "a", "an", "and", "or", "but", "to", "of", "in", "on", "for", "with", "is", "are",
-        "was", "were", "be", "this", "that", "it", "we", "you", "i", "our", "your", "my", "they",
-        "their", "as", "at", "by", "from",
-    ];
-    const STOPWORDS_FR: &[&str] = &[
-        "le", "la", "les", "un", "une", "des", "et", "ou", "mais", "de", "du", "en", "dans", "sur",
-        "pour", "avec", "est", "sont", "etre", "ce", "cette", "ces", "je", "tu", "il", "elle",
-        "nous", "vous", "ils", "elles", "mon", "ma", "mes", "notre", "votre", "leur", "aux", "au",
-    ];
-    STOPWORDS_EN.contains(&token) || STOPWORDS_FR.contains(&token)
-}
-
-fn extract_jargon_terms(text: &str) -> Vec<String> {
+pub(super) fn extract_jargon_terms(text: &str, lexicon: &Lexicon) -> Vec<String> {
     let mut seen = HashSet::new();
     let mut terms = Vec::new();
-    for raw in text.split(|c: char| !c.is_ascii_alphanumeric()) {
-        if raw.len() < 3 {
+    for raw in language::raw_words(text) {
+        if raw.chars().count() < 3 || lexicon.is_ignored(raw) {
             continue;
         }
-        let is_upper = raw.chars().all(|c| !c.is_ascii_lowercase());
-        let has_digit = raw.chars().any(|c| c.is_ascii_digit());
+        let is_upper = raw.chars().all(|c| !c.is_lowercase());
+        let has_digit = raw.chars().any(|c| c.is_numeric());
         if is_upper || has_digit {
             let token = raw.to_string();
             if seen.insert(token.clone()) {
@@ -186,3 +131,307 @@ fn extract_jargon_terms(text: &str) -> Vec<String> {
     }
     terms
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::feedback::analyzers::{registry, AnalyzerSelection};
+    use crate::domain::feedback::language::Language;
+    use crate::domain::recorder::pauses::PauseSpan;
+
+    fn segment(t_start_ms: i64, t_end_ms: i64, text: &str) -> models::TranscriptSegment {
+        models::TranscriptSegment {
+            t_start_ms,
+            t_end_ms,
+            text: text.to_string(),
+            confidence: None,
+            words: Vec::new(),
+        }
+    }
+
+    fn transcript(segments: Vec<models::TranscriptSegment>) -> models::TranscriptV1 {
+        models::TranscriptV1 {
+            schema_version: "1.0.0".to_string(),
+            language: "en".to_string(),
+            model_id: None,
+            duration_ms: None,
+            segments,
+            markers: Vec::new(),
+        }
+    }
+
+    fn analyze_transcript(
+        doc: &models::TranscriptV1,
+        estimated_sec: i64,
+        audio_pauses: Option<&[PauseSpan]>,
+        talk: Option<&TalkTarget>,
+    ) -> Result<models::FeedbackV1, String> {
+        let mut input = AnalysisInput::from_transcript(
+            doc,
+            estimated_sec,
+            Lexicon::defaults(Language::from_code(&doc.language)),
+        )?;
+        input.audio_pauses = audio_pauses;
+        input.talk = talk;
+        Ok(build_feedback(&input, &registry()))
+    }
+
+    #[test]
+    fn text_feedback_keeps_baseline_scoring() {
+        let text = "Um so we basically ship it. Um like the team will ship it again.";
+        let feedback = build_feedback(
+            &AnalysisInput::from_text(text, 30, Lexicon::defaults(Language::Unknown)),
+            &registry(),
+        );
+        assert_eq!(feedback.overall_score, 70);
+        assert_eq!(feedback.metrics.pause_count, 1);
+        assert_eq!(feedback.top_actions[0].action_id, "reduce_fillers");
+        assert_eq!(feedback.top_actions[1].action_id, "vary_terms");
+        assert!(feedback.metrics.pauses.is_none());
+    }
+
+    #[test]
+    fn french_elisions_are_not_counted_as_words() {
+        let text = "L'équipe sait qu'on l'aime.";
+        let feedback = build_feedback(
+            &AnalysisInput::from_text(text, 60, Lexicon::defaults(Language::Fr)),
+            &registry(),
+        );
+        assert_eq!(feedback.metrics.wpm, 4.0);
+        assert_eq!(feedback.metrics.avg_sentence_words, 4.0);
+    }
+
+    #[test]
+    fn disabled_analyzers_leave_no_trace() {
+        let text = "Um so we basically ship it. Um like the team will ship it again.";
+        let selection = AnalyzerSelection::parse(Some(r#"{"fillers":false}"#));
+        let feedback = build_feedback(
+            &AnalysisInput::from_text(text, 30, Lexicon::defaults(Language::Unknown)),
+            &selection.apply(registry()),
+        );
+        assert_eq!(feedback.overall_score, 90);
+        assert_eq!(feedback.metrics.filler_per_min, 0.0);
+        assert!(feedback
+            .top_actions
+            .iter()
+            .all(|action| action.action_id != "reduce_fillers"));
+        assert!(!feedback.analyzers.contains(&"fillers".to_string()));
+    }
+
+    #[test]
+    fn duplicate_candidate_actions_are_merged() {
+        let action = || models::FeedbackAction {
+            action_id: "same".to_string(),
+            title: "Same".to_string(),
+            why_it_matters: String::new(),
+            how_to_fix: String::new(),
+            target_quest_codes: Vec::new(),
+        };
+        let mut draft = FeedbackDraft::default();
+        draft.propose(10, action());
+        draft.propose(60, action());
+        draft.penalize(200.0);
+        let feedback = merge(draft, Vec::new());
+        assert_eq!(feedback.top_actions.len(), 1);
+        assert_eq!(feedback.overall_score, 0);
+    }
+
+    #[test]
+    fn filler_cluster_points_at_segment_range() {
+        let doc = transcript(vec![
+            segment(0, 4_000, "Welcome to the talk."),
+            segment(4_000, 9_000, "So um, uh, basically um we ship it."),
+        ]);
+        let feedback = analyze_transcript(&doc, 9, None, None).expect("feedback");
+        let comment = feedback
+            .comments
+            .iter()
+            .find(|c| c.label == "Filler cluster")
+            .expect("filler comment");
+        assert_eq!((comment.t_start_ms, comment.t_end_ms), (4_000, 9_000));
+        assert_eq!(comment.severity, "medium");
+    }
+
+    #[test]
+    fn overlong_sentence_spans_multiple_segments() {
+        let long = "word ".repeat(20);
+        let doc = transcript(vec![
+            segment(0, 5_000, &long),
+            segment(5_000, 10_000, &format!("{long}. Short one.")),
+        ]);
+        let feedback = analyze_transcript(&doc, 10, None, None).expect("feedback");
+        let comment = feedback
+            .comments
+            .iter()
+            .find(|c| c.label == "Overlong sentence")
+            .expect("sentence comment");
+        assert_eq!((comment.t_start_ms, comment.t_end_ms), (0, 10_000));
+    }
+
+    #[test]
+    fn speed_burst_flags_fast_segment_only() {
+        let fast = "word ".repeat(20);
+        let doc = transcript(vec![
+            segment(0, 10_000, "A calm opening sentence."),
+            segment(10_000, 15_000, &fast),
+        ]);
+        let feedback = analyze_transcript(&doc, 15, None, None).expect("feedback");
+        let bursts: Vec<_> = feedback
+            .comments
+            .iter()
+            .filter(|c| c.label == "Speed burst")
+            .collect();
+        assert_eq!(bursts.len(), 1);
+        assert_eq!(bursts[0].t_start_ms, 10_000);
+        assert_eq!(bursts[0].severity, "high");
+    }
+
+    #[test]
+    fn comments_are_capped_and_sorted_by_time() {
+        let segments = (0..12)
+            .map(|i| segment(i * 3_000, i * 3_000 + 2_500, "um uh um uh um"))
+            .collect();
+        let feedback = analyze_transcript(&transcript(segments), 36, None, None).expect("feedback");
+        assert_eq!(feedback.comments.len(), MAX_COMMENTS);
+        assert!(feedback
+            .comments
+            .windows(2)
+            .all(|pair| pair[0].t_start_ms <= pair[1].t_start_ms));
+    }
+
+    #[test]
+    fn pauses_prefer_audio_spans_over_segment_gaps() {
+        let doc = transcript(vec![
+            segment(0, 2_000, "First idea."),
+            segment(3_000, 5_000, "Second idea."),
+        ]);
+        let from_segments = analyze_transcript(&doc, 5, None, None).expect("feedback");
+        let pauses = from_segments.metrics.pauses.expect("pause metrics");
+        assert_eq!(pauses.source, "segments");
+        assert_eq!(from_segments.metrics.pause_count, 1);
+        assert_eq!(pauses.max_ms, 1_000);
+
+        let audio = [
+            PauseSpan {
+                t_start_ms: 2_000,
+                t_end_ms: 2_600,
+            },
+            PauseSpan {
+                t_start_ms: 3_900,
+                t_end_ms: 4_300,
+            },
+        ];
+        let from_audio = analyze_transcript(&doc, 5, Some(&audio), None).expect("feedback");
+        assert_eq!(from_audio.metrics.pause_count, 2);
+        assert_eq!(
+            from_audio.metrics.pauses.map(|p| p.source),
+            Some("audio".to_string())
+        );
+    }
+
+    #[test]
+    fn rushed_ending_becomes_comment_and_top_action() {
+        let mut segments: Vec<_> = (0..9)
+            .map(|i| segment(i * 10_000, (i + 1) * 10_000, &"word ".repeat(20)))
+            .collect();
+        segments
+            .extend((9..12).map(|i| segment(i * 10_000, (i + 1) * 10_000, &"fast ".repeat(40))));
+        let feedback =
+            analyze_transcript(&transcript(segments), 120, None, None).expect("feedback");
+
+        assert_eq!(feedback.schema_version, FEEDBACK_SCHEMA_VERSION);
+        assert!(feedback.metrics.pace.is_some());
+        assert!(feedback
+            .comments
+            .iter()
+            .any(|c| c.label == "Rushed section"));
+        assert_eq!(feedback.top_actions[0].action_id, "steady_rushed_pace");
+        assert!(feedback.top_actions.len() <= MAX_ACTIONS);
+    }
+
+    #[test]
+    fn overrun_against_target_leads_top_actions() {
+        let doc = transcript(vec![
+            segment(0, 200_000, "Opening story about our team."),
+            segment(
+                200_000,
+                500_000,
+                "The benchmarks and latency results in detail.",
+            ),
+        ]);
+        let talk = TalkTarget {
+            duration_target_sec: Some(300),
+            outline_md: Some("# Talk\n## Opening\n- story\n## Results\n- benchmarks".to_string()),
+        };
+        let feedback = analyze_transcript(&doc, 500, None, Some(&talk)).expect("feedback");
+        let duration = feedback
+            .metrics
+            .duration
+            .as_ref()
+            .expect("duration metrics");
+        assert_eq!(duration.status, "overrun");
+        assert_eq!(duration.delta_sec, 200);
+        assert_eq!(feedback.top_actions[0].title, "Cut 200 seconds");
+        assert!(feedback.top_actions[0]
+            .how_to_fix
+            .contains("\"Results\" (5m00s)"));
+    }
+
+    #[test]
+    fn legacy_feedback_without_new_metrics_still_parses() {
+        let legacy = serde_json::json!({
+            "schema_version": "1.0.0",
+            "overall_score": 80,
+            "top_actions": [],
+            "comments": [],
+            "metrics": {
+                "wpm": 120.0,
+                "filler_per_min": 1.0,
+                "pause_count": 2,
+                "avg_sentence_words": 12.0,
+                "repeat_terms": [],
+                "jargon_terms": [],
+                "density_score": 48.0
+            }
+        });
+        let parsed: models::FeedbackV1 = serde_json::from_value(legacy).expect("legacy parse");
+        assert!(parsed.metrics.pace.is_none());
+        assert!(parsed.metrics.pauses.is_none());
+    }
+
+    #[test]
+    fn french_transcript_keeps_accented_terms_whole() {
+        let mut doc = transcript(vec![
+            segment(0, 5_000, "Euh, évidemment l'équipe a déjà livré l'API."),
+            segment(
+                5_000,
+                10_000,
+                "Du coup l'équipe livre évidemment qu'on déploie l'API.",
+            ),
+        ]);
+        doc.language = "fr".to_string();
+        let feedback = analyze_transcript(&doc, 30, None, None).expect("feedback");
+        assert_eq!(
+            feedback.metrics.repeat_terms,
+            vec!["api", "équipe", "évidemment"]
+        );
+        assert!((feedback.metrics.filler_per_min - 4.0).abs() < 1e-9);
+        assert_eq!(feedback.metrics.jargon_terms, vec!["API"]);
+    }
+
+    #[test]
+    fn ignored_terms_skip_jargon_and_repeats() {
+        let text = "Kubernetes runs on Kubernetes nodes. The API talks to Kubernetes.";
+        let lexicon = Lexicon::new(Language::En, ["um"], ["the", "on", "to"], ["kubernetes"]);
+        let feedback = build_feedback(&AnalysisInput::from_text(text, 30, lexicon), &registry());
+        assert_eq!(feedback.metrics.jargon_terms, vec!["API"]);
+        assert!(feedback.metrics.repeat_terms.is_empty());
+    }
+
+    #[test]
+    fn empty_transcript_is_rejected() {
+        let err = analyze_transcript(&transcript(Vec::new()), 10, None, None)
+            .expect_err("empty transcript");
+        assert_eq!(err, "transcript_empty");
+    }
+}
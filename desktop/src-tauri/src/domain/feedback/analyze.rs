@@ -1,4 +1,8 @@
 use super::analysis;
+use super::analyzers;
+use super::audio;
+use super::language::Language;
+use super::lexicon;
 use super::repo;
 use super::types::AnalyzeResponse;
 use crate::domain::asr::transcript;
@@ -14,21 +18,44 @@ pub fn analyze_attempt(
     db::ensure_profile_exists(app, profile_id)?;
     let mut conn = db::open_profile(app, profile_id)?;
 
-    let (output_text, transcript_id, estimated_sec) =
-        repo::select_attempt_input(&conn, attempt_id)?;
+    let input = repo::select_attempt_input(&conn, attempt_id)?;
+    let estimated_sec = input.estimated_sec;
 
+    let analyzers = analyzers::enabled_for_profile(app, profile_id)?;
     let mut source = "text";
-    let text = if let Some(text) = output_text {
-        text
-    } else if let Some(transcript_id) = transcript_id {
+    let feedback = if let Some(text) = input.output_text {
+        analysis::build_feedback(
+            &analyzers::AnalysisInput::from_text(
+                &text,
+                estimated_sec,
+                lexicon::profile_lexicon(&conn, Language::Unknown.code())?,
+            ),
+            &analyzers,
+        )
+    } else if let Some(transcript_id) = input.transcript_id {
         source = "transcript";
         let transcript = transcript::load_transcript(app, profile_id, &transcript_id)?;
-        transcript::transcript_text(&transcript)?
+        let mut analysis_input = analyzers::AnalysisInput::from_transcript(
+            &transcript,
+            estimated_sec,
+            lexicon::profile_lexicon(&conn, &transcript.language)?,
+        )?;
+        let signals = audio::audio_signals(
+            app,
+            profile_id,
+            input.audio_artifact_id.as_deref(),
+            &transcript.segments,
+            ("quest_attempt", attempt_id),
+            analyzers::needs_pitch(&analyzers),
+        );
+        analysis_input.audio_pauses = signals.pauses.as_deref();
+        analysis_input.audio_loudness = signals.loudness.as_ref();
+        analysis_input.audio_pitch = signals.pitch.as_ref();
+        analysis::build_feedback(&analysis_input, &analyzers)
     } else {
         return Err("attempt_missing_text".to_string());
     };
 
-    let feedback = analysis::build_feedback_from_text(&text, estimated_sec);
     let feedback_json = serde_json::to_vec(&feedback).map_err(|e| format!("feedback_json: {e}"))?;
     let metadata = serde_json::json!({
         "source": source,
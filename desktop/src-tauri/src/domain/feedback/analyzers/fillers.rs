@@ -0,0 +1,72 @@
+use super::{AnalysisInput, Analyzer, FeedbackDraft};
+use crate::domain::feedback::language;
+use crate::domain::feedback::lexicon::Lexicon;
+use crate::kernel::models;
+
+const ACTION_PRIORITY: u8 = 50;
+const ACTION_MIN_PER_MIN: f64 = 2.0;
+const PENALTY_PER_FILLER_PER_MIN: f64 = 2.5;
+const CLUSTER_MIN: usize = 3;
+const CLUSTER_HIGH: usize = 5;
+
+pub(super) struct Fillers;
+
+impl Analyzer for Fillers {
+    fn id(&self) -> &'static str {
+        "fillers"
+    }
+
+    fn analyze(&self, input: &AnalysisInput, draft: &mut FeedbackDraft) {
+        let filler_count = input.lexicon.count_fillers(&input.tokens) as f64;
+        let filler_per_min = filler_count / input.duration_min();
+        draft.metrics.filler_per_min = filler_per_min;
+        draft.penalize(filler_per_min * PENALTY_PER_FILLER_PER_MIN);
+        if filler_per_min > ACTION_MIN_PER_MIN {
+            draft.propose(
+                ACTION_PRIORITY,
+                models::FeedbackAction {
+                    action_id: "reduce_fillers".to_string(),
+                    title: "Reduce filler words".to_string(),
+                    why_it_matters: "Fillers dilute clarity and confidence.".to_string(),
+                    how_to_fix: "Pause silently instead of using fillers.".to_string(),
+                    target_quest_codes: vec!["D01".to_string()],
+                },
+            );
+        }
+        draft
+            .comments
+            .extend(cluster_comments(input.segments, &input.lexicon));
+    }
+}
+
+fn cluster_comments(
+    segments: &[models::TranscriptSegment],
+    lexicon: &Lexicon,
+) -> Vec<models::FeedbackComment> {
+    let mut comments = Vec::new();
+    for segment in segments {
+        let tokens = lexicon.tokenize(&segment.text);
+        let fillers = lexicon.count_fillers(&tokens);
+        if fillers < CLUSTER_MIN {
+            continue;
+        }
+        let severity = if fillers >= CLUSTER_HIGH {
+            "high"
+        } else {
+            "medium"
+        };
+        comments.push(models::FeedbackComment {
+            t_start_ms: segment.t_start_ms.max(0),
+            t_end_ms: segment.t_end_ms.max(segment.t_start_ms).max(0),
+            severity: severity.to_string(),
+            label: "Filler cluster".to_string(),
+            evidence: Some(serde_json::json!({
+                "kind": "filler_cluster",
+                "filler_count": fillers,
+                "word_count": language::word_count(&tokens),
+            })),
+            suggestion: "Replace the fillers here with a short silent pause.".to_string(),
+        });
+    }
+    comments
+}
@@ -0,0 +1,31 @@
+use super::{AnalysisInput, Analyzer, FeedbackDraft};
+use crate::domain::feedback::pitch;
+
+const ACTION_PRIORITY: u8 = 72;
+const MONOTONE_PENALTY: f64 = 4.0;
+
+pub(super) const ID: &str = "intonation";
+
+pub(super) struct Intonation;
+
+impl Analyzer for Intonation {
+    fn id(&self) -> &'static str {
+        ID
+    }
+
+    fn analyze(&self, input: &AnalysisInput, draft: &mut FeedbackDraft) {
+        let Some(signal) = input.audio_pitch else {
+            return;
+        };
+        draft.comments.extend(
+            pitch::flat_stretches(&signal.analysis)
+                .into_iter()
+                .map(pitch::flat_stretch_comment),
+        );
+        if pitch::is_monotone(&signal.analysis) {
+            draft.penalize(MONOTONE_PENALTY);
+            draft.propose(ACTION_PRIORITY, pitch::monotone_action());
+        }
+        draft.metrics.pitch = Some(pitch::pitch_metrics(signal));
+    }
+}
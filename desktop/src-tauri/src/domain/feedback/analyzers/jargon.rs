@@ -0,0 +1,14 @@
+use super::{AnalysisInput, Analyzer, FeedbackDraft};
+use crate::domain::feedback::analysis;
+
+pub(super) struct Jargon;
+
+impl Analyzer for Jargon {
+    fn id(&self) -> &'static str {
+        "jargon"
+    }
+
+    fn analyze(&self, input: &AnalysisInput, draft: &mut FeedbackDraft) {
+        draft.metrics.jargon_terms = analysis::extract_jargon_terms(&input.text, &input.lexicon);
+    }
+}
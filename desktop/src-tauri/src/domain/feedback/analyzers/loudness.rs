@@ -0,0 +1,34 @@
+use super::{AnalysisInput, Analyzer, FeedbackDraft};
+use crate::domain::feedback::loudness;
+
+const ACTION_PRIORITY: u8 = 70;
+const MONOTONE_PENALTY: f64 = 4.0;
+
+pub(super) struct Loudness;
+
+impl Analyzer for Loudness {
+    fn id(&self) -> &'static str {
+        "loudness"
+    }
+
+    fn analyze(&self, input: &AnalysisInput, draft: &mut FeedbackDraft) {
+        let Some(signal) = input.audio_loudness else {
+            return;
+        };
+        let analysis = &signal.analysis;
+        draft.comments.extend(
+            analysis
+                .trailing_offs
+                .iter()
+                .map(loudness::trailing_off_comment),
+        );
+        draft
+            .comments
+            .extend(analysis.clipping.iter().map(loudness::clipping_comment));
+        if loudness::is_monotone(analysis) {
+            draft.penalize(MONOTONE_PENALTY);
+            draft.propose(ACTION_PRIORITY, loudness::monotone_action());
+        }
+        draft.metrics.loudness = Some(loudness::loudness_metrics(signal));
+    }
+}
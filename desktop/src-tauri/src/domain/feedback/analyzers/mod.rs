@@ -0,0 +1,226 @@
+mod fillers;
+mod intonation;
+mod jargon;
+mod loudness;
+mod pacing;
+mod pauses;
+mod repetition;
+mod sentences;
+mod structure;
+
+use super::analysis::TalkTarget;
+use super::lexicon::Lexicon;
+use super::loudness::LoudnessSignal;
+use super::pitch::PitchSignal;
+use super::types::FeedbackAnalyzerStatus;
+use crate::domain::asr::transcript;
+use crate::domain::recorder::pauses::PauseSpan;
+use crate::kernel::models;
+use crate::platform::preferences;
+use std::collections::HashMap;
+
+pub const ANALYZERS_SETTING_KEY: &str = "lepupitre.feedback.analyzers";
+
+pub trait Analyzer: Send + Sync {
+    fn id(&self) -> &'static str;
+    fn analyze(&self, input: &AnalysisInput, draft: &mut FeedbackDraft);
+}
+
+pub struct AnalysisInput<'a> {
+    pub text: String,
+    pub lexicon: Lexicon,
+    pub tokens: Vec<String>,
+    pub segments: &'a [models::TranscriptSegment],
+    pub markers: &'a [models::RecordingMarker],
+    pub duration_ms: Option<i64>,
+    pub estimated_sec: i64,
+    pub audio_pauses: Option<&'a [PauseSpan]>,
+    pub audio_loudness: Option<&'a LoudnessSignal>,
+    pub audio_pitch: Option<&'a PitchSignal>,
+    pub talk: Option<&'a TalkTarget>,
+}
+
+impl<'a> AnalysisInput<'a> {
+    pub fn from_text(text: &str, estimated_sec: i64, lexicon: Lexicon) -> Self {
+        Self {
+            text: text.to_string(),
+            tokens: lexicon.tokenize(text),
+            lexicon,
+            segments: &[],
+            markers: &[],
+            duration_ms: None,
+            estimated_sec,
+            audio_pauses: None,
+            audio_loudness: None,
+            audio_pitch: None,
+            talk: None,
+        }
+    }
+
+    pub fn from_transcript(
+        transcript: &'a models::TranscriptV1,
+        estimated_sec: i64,
+        lexicon: Lexicon,
+    ) -> Result<Self, String> {
+        let text = transcript::transcript_text(transcript)?;
+        Ok(Self {
+            tokens: lexicon.tokenize(&text),
+            text,
+            lexicon,
+            segments: &transcript.segments,
+            markers: &transcript.markers,
+            duration_ms: transcript
+                .duration_ms
+                .or_else(|| transcript::transcript_duration_ms(transcript)),
+            estimated_sec,
+            audio_pauses: None,
+            audio_loudness: None,
+            audio_pitch: None,
+            talk: None,
+        })
+    }
+
+    pub fn duration_min(&self) -> f64 {
+        (self.estimated_sec.max(30) as f64) / 60.0
+    }
+
+    pub fn actual_ms(&self) -> i64 {
+        self.duration_ms.unwrap_or(self.estimated_sec * 1000)
+    }
+}
+
+pub struct CandidateAction {
+    pub priority: u8,
+    pub action: models::FeedbackAction,
+}
+
+#[derive(Default)]
+pub struct FeedbackDraft {
+    pub metrics: models::FeedbackMetrics,
+    pub comments: Vec<models::FeedbackComment>,
+    pub actions: Vec<CandidateAction>,
+    pub score_penalty: f64,
+}
+
+impl FeedbackDraft {
+    pub fn penalize(&mut self, points: f64) {
+        if points > 0.0 {
+            self.score_penalty += points;
+        }
+    }
+
+    pub fn propose(&mut self, priority: u8, action: models::FeedbackAction) {
+        self.actions.push(CandidateAction { priority, action });
+    }
+}
+
+pub fn registry() -> Vec<Box<dyn Analyzer>> {
+    vec![
+        Box::new(fillers::Fillers),
+        Box::new(sentences::Sentences),
+        Box::new(repetition::Repetition),
+        Box::new(jargon::Jargon),
+        Box::new(pacing::Pacing),
+        Box::new(pauses::Pauses),
+        Box::new(loudness::Loudness),
+        Box::new(intonation::Intonation),
+        Box::new(structure::Structure),
+    ]
+}
+
+/// Pitch tracking is the costliest signal; only decode it for intonation.
+pub fn needs_pitch(analyzers: &[Box<dyn Analyzer>]) -> bool {
+    analyzers
+        .iter()
+        .any(|analyzer| analyzer.id() == intonation::ID)
+}
+
+#[derive(Debug, Default)]
+pub struct AnalyzerSelection {
+    overrides: HashMap<String, bool>,
+}
+
+impl AnalyzerSelection {
+    pub fn parse(raw: Option<&str>) -> Self {
+        let overrides = raw
+            .and_then(|value| serde_json::from_str::<HashMap<String, bool>>(value).ok())
+            .unwrap_or_default();
+        Self { overrides }
+    }
+
+    pub fn is_enabled(&self, id: &str) -> bool {
+        self.overrides.get(id).copied().unwrap_or(true)
+    }
+
+    pub fn apply(&self, analyzers: Vec<Box<dyn Analyzer>>) -> Vec<Box<dyn Analyzer>> {
+        analyzers
+            .into_iter()
+            .filter(|analyzer| self.is_enabled(analyzer.id()))
+            .collect()
+    }
+}
+
+pub fn load_selection(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+) -> Result<AnalyzerSelection, String> {
+    let raw = preferences::preference_profile_get(app, profile_id, ANALYZERS_SETTING_KEY)?;
+    Ok(AnalyzerSelection::parse(raw.as_deref()))
+}
+
+pub fn enabled_for_profile(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+) -> Result<Vec<Box<dyn Analyzer>>, String> {
+    Ok(load_selection(app, profile_id)?.apply(registry()))
+}
+
+pub fn feedback_analyzers_list(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+) -> Result<Vec<FeedbackAnalyzerStatus>, String> {
+    let selection = load_selection(app, profile_id)?;
+    Ok(registry()
+        .iter()
+        .map(|analyzer| FeedbackAnalyzerStatus {
+            id: analyzer.id().to_string(),
+            enabled: selection.is_enabled(analyzer.id()),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_ids_are_unique() {
+        let analyzers = registry();
+        let mut ids: Vec<&str> = analyzers.iter().map(|analyzer| analyzer.id()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), analyzers.len());
+    }
+
+    #[test]
+    fn selection_defaults_to_enabled() {
+        let selection = AnalyzerSelection::parse(None);
+        assert!(selection.is_enabled("fillers"));
+        assert_eq!(selection.apply(registry()).len(), registry().len());
+    }
+
+    #[test]
+    fn selection_disables_listed_analyzers() {
+        let selection = AnalyzerSelection::parse(Some(r#"{"fillers":false,"jargon":true}"#));
+        let enabled = selection.apply(registry());
+        let ids: Vec<&str> = enabled.iter().map(|analyzer| analyzer.id()).collect();
+        assert!(!ids.contains(&"fillers"));
+        assert!(ids.contains(&"jargon"));
+    }
+
+    #[test]
+    fn malformed_selection_keeps_all_enabled() {
+        let selection = AnalyzerSelection::parse(Some("not json"));
+        assert!(selection.is_enabled("pacing"));
+    }
+}
@@ -0,0 +1,77 @@
+use super::{AnalysisInput, Analyzer, FeedbackDraft};
+use crate::domain::feedback::language;
+use crate::domain::feedback::lexicon::Lexicon;
+use crate::domain::feedback::pace;
+use crate::kernel::models;
+
+const ACTION_PRIORITY: u8 = 80;
+const BURST_MIN_SPAN_MS: i64 = 2_000;
+const BURST_WPM: f64 = 190.0;
+const BURST_HIGH_WPM: f64 = 220.0;
+
+pub(super) struct Pacing;
+
+impl Analyzer for Pacing {
+    fn id(&self) -> &'static str {
+        "pacing"
+    }
+
+    fn analyze(&self, input: &AnalysisInput, draft: &mut FeedbackDraft) {
+        draft.metrics.wpm = language::word_count(&input.tokens) as f64 / input.duration_min();
+        draft
+            .comments
+            .extend(speed_burst_comments(input.segments, &input.lexicon));
+
+        let Some((pace_metrics, sections)) =
+            pace::pace_metrics(input.segments, input.lexicon.language)
+        else {
+            return;
+        };
+        let mut kinds = Vec::new();
+        for section in &sections {
+            draft.comments.push(pace::section_comment(section));
+            if !kinds.contains(&section.kind) {
+                kinds.push(section.kind);
+            }
+        }
+        for kind in kinds {
+            draft.propose(ACTION_PRIORITY, pace::section_action(kind));
+        }
+        draft.metrics.pace = Some(pace_metrics);
+    }
+}
+
+fn speed_burst_comments(
+    segments: &[models::TranscriptSegment],
+    lexicon: &Lexicon,
+) -> Vec<models::FeedbackComment> {
+    let mut comments = Vec::new();
+    for segment in segments {
+        let span_ms = segment.t_end_ms - segment.t_start_ms;
+        if span_ms < BURST_MIN_SPAN_MS {
+            continue;
+        }
+        let words = language::word_count(&lexicon.tokenize(&segment.text)) as f64;
+        let wpm = words / (span_ms as f64 / 60_000.0);
+        if wpm < BURST_WPM {
+            continue;
+        }
+        let severity = if wpm >= BURST_HIGH_WPM {
+            "high"
+        } else {
+            "medium"
+        };
+        comments.push(models::FeedbackComment {
+            t_start_ms: segment.t_start_ms.max(0),
+            t_end_ms: segment.t_end_ms.max(0),
+            severity: severity.to_string(),
+            label: "Speed burst".to_string(),
+            evidence: Some(serde_json::json!({
+                "kind": "speed_burst",
+                "wpm": wpm.round(),
+            })),
+            suggestion: "Slow down and let this idea land before moving on.".to_string(),
+        });
+    }
+    comments
+}
@@ -0,0 +1,26 @@
+use super::{AnalysisInput, Analyzer, FeedbackDraft};
+use crate::domain::feedback::{analysis, pauses};
+
+pub(super) struct Pauses;
+
+impl Analyzer for Pauses {
+    fn id(&self) -> &'static str {
+        "pauses"
+    }
+
+    fn analyze(&self, input: &AnalysisInput, draft: &mut FeedbackDraft) {
+        let pause_metrics = match input.audio_pauses {
+            Some(spans) => pauses::summarize_pauses(spans, "audio"),
+            None if !input.segments.is_empty() => {
+                pauses::summarize_pauses(&pauses::segment_gap_pauses(input.segments), "segments")
+            }
+            None => {
+                draft.metrics.pause_count =
+                    analysis::sentence_count(&input.text).saturating_sub(1) as i64;
+                return;
+            }
+        };
+        draft.metrics.pause_count = pause_metrics.count;
+        draft.metrics.pauses = Some(pause_metrics);
+    }
+}
@@ -0,0 +1,36 @@
+use super::{AnalysisInput, Analyzer, FeedbackDraft};
+use crate::domain::feedback::analysis;
+use crate::kernel::models;
+
+const ACTION_PRIORITY: u8 = 10;
+const MAX_TERMS: usize = 5;
+const TOLERATED_TERMS: usize = 3;
+const PENALTY_PER_EXTRA_TERM: f64 = 1.5;
+
+pub(super) struct Repetition;
+
+impl Analyzer for Repetition {
+    fn id(&self) -> &'static str {
+        "repetition"
+    }
+
+    fn analyze(&self, input: &AnalysisInput, draft: &mut FeedbackDraft) {
+        let repeat_terms = analysis::top_repeats(&input.tokens, MAX_TERMS, &input.lexicon);
+        if repeat_terms.len() > TOLERATED_TERMS {
+            draft.penalize((repeat_terms.len() - TOLERATED_TERMS) as f64 * PENALTY_PER_EXTRA_TERM);
+        }
+        if !repeat_terms.is_empty() {
+            draft.propose(
+                ACTION_PRIORITY,
+                models::FeedbackAction {
+                    action_id: "vary_terms".to_string(),
+                    title: "Vary repeated terms".to_string(),
+                    why_it_matters: "Repetition weakens perceived structure.".to_string(),
+                    how_to_fix: "Swap repeated words with simpler alternatives.".to_string(),
+                    target_quest_codes: vec!["A01".to_string()],
+                },
+            );
+        }
+        draft.metrics.repeat_terms = repeat_terms;
+    }
+}
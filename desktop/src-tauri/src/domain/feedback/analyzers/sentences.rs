@@ -0,0 +1,108 @@
+use super::{AnalysisInput, Analyzer, FeedbackDraft};
+use crate::domain::feedback::analysis;
+use crate::domain::feedback::language;
+use crate::domain::feedback::lexicon::Lexicon;
+use crate::kernel::models;
+
+const ACTION_PRIORITY: u8 = 40;
+const TARGET_AVG_WORDS: f64 = 18.0;
+const PENALTY_PER_EXTRA_WORD: f64 = 1.5;
+const OVERLONG_WORDS: usize = 30;
+const OVERLONG_HIGH_WORDS: usize = 45;
+
+pub(super) struct Sentences;
+
+impl Analyzer for Sentences {
+    fn id(&self) -> &'static str {
+        "sentences"
+    }
+
+    fn analyze(&self, input: &AnalysisInput, draft: &mut FeedbackDraft) {
+        let word_count = language::word_count(&input.tokens) as f64;
+        let sentence_count = analysis::sentence_count(&input.text).max(1) as f64;
+        let avg_sentence_words = word_count / sentence_count;
+        draft.metrics.avg_sentence_words = avg_sentence_words;
+        draft.metrics.density_score = (avg_sentence_words * 4.0).min(100.0);
+
+        if avg_sentence_words > TARGET_AVG_WORDS {
+            draft.penalize((avg_sentence_words - TARGET_AVG_WORDS) * PENALTY_PER_EXTRA_WORD);
+            draft.propose(
+                ACTION_PRIORITY,
+                models::FeedbackAction {
+                    action_id: "shorten_sentences".to_string(),
+                    title: "Shorten long sentences".to_string(),
+                    why_it_matters: "Shorter sentences keep ideas crisp.".to_string(),
+                    how_to_fix: "Break each idea into a single line.".to_string(),
+                    target_quest_codes: vec!["A01".to_string()],
+                },
+            );
+        }
+        draft
+            .comments
+            .extend(overlong_comments(input.segments, &input.lexicon));
+    }
+}
+
+fn overlong_comments(
+    segments: &[models::TranscriptSegment],
+    lexicon: &Lexicon,
+) -> Vec<models::FeedbackComment> {
+    let mut comments = Vec::new();
+    for span in sentence_spans(segments, lexicon) {
+        if span.word_count < OVERLONG_WORDS {
+            continue;
+        }
+        let severity = if span.word_count >= OVERLONG_HIGH_WORDS {
+            "high"
+        } else {
+            "medium"
+        };
+        comments.push(models::FeedbackComment {
+            t_start_ms: span.t_start_ms.max(0),
+            t_end_ms: span.t_end_ms.max(span.t_start_ms).max(0),
+            severity: severity.to_string(),
+            label: "Overlong sentence".to_string(),
+            evidence: Some(serde_json::json!({
+                "kind": "overlong_sentence",
+                "word_count": span.word_count,
+            })),
+            suggestion: "Split this sentence into two or three shorter ones.".to_string(),
+        });
+    }
+    comments
+}
+
+struct SentenceSpan {
+    t_start_ms: i64,
+    t_end_ms: i64,
+    word_count: usize,
+}
+
+fn sentence_spans(segments: &[models::TranscriptSegment], lexicon: &Lexicon) -> Vec<SentenceSpan> {
+    let mut spans = Vec::new();
+    let mut current: Option<SentenceSpan> = None;
+    for segment in segments {
+        for (idx, chunk) in segment.text.split(['.', '!', '?']).enumerate() {
+            if idx > 0 {
+                if let Some(span) = current.take() {
+                    spans.push(span);
+                }
+            }
+            let words = language::word_count(&lexicon.tokenize(chunk));
+            if words == 0 {
+                continue;
+            }
+            let span = current.get_or_insert(SentenceSpan {
+                t_start_ms: segment.t_start_ms,
+                t_end_ms: segment.t_end_ms,
+                word_count: 0,
+            });
+            span.t_end_ms = segment.t_end_ms;
+            span.word_count += words;
+        }
+    }
+    if let Some(span) = current {
+        spans.push(span);
+    }
+    spans
+}
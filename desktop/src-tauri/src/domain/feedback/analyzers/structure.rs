@@ -0,0 +1,55 @@
+use super::{AnalysisInput, Analyzer, FeedbackDraft};
+use crate::domain::feedback::{duration, outline};
+
+const CUT_PRIORITY: u8 = 100;
+const SKIPPED_PRIORITY: u8 = 90;
+
+pub(super) struct Structure;
+
+impl Analyzer for Structure {
+    fn id(&self) -> &'static str {
+        "structure"
+    }
+
+    fn analyze(&self, input: &AnalysisInput, draft: &mut FeedbackDraft) {
+        let Some(talk) = input.talk else {
+            return;
+        };
+
+        let timings = talk
+            .outline_md
+            .as_deref()
+            .filter(|_| !input.segments.is_empty())
+            .map(|markdown| {
+                outline::section_timings(
+                    &outline::parse_outline(markdown),
+                    input.segments,
+                    &input.lexicon,
+                )
+            })
+            .unwrap_or_default();
+        if !timings.is_empty() {
+            let coverage = outline::coverage(&timings);
+            draft.comments.extend(
+                coverage
+                    .sections
+                    .iter()
+                    .filter(|section| coverage.reordered.contains(&section.title))
+                    .filter_map(outline::reordered_comment),
+            );
+            if let Some(action) = outline::skipped_action(&coverage) {
+                draft.propose(SKIPPED_PRIORITY, action);
+            }
+            draft.metrics.outline = Some(coverage);
+        }
+
+        if let Some(target_sec) = talk.duration_target_sec.filter(|target| *target > 0) {
+            let duration_metrics =
+                duration::duration_metrics(input.actual_ms(), target_sec, &timings);
+            if let Some(action) = duration::cut_action(&duration_metrics, &timings) {
+                draft.propose(CUT_PRIORITY, action);
+            }
+            draft.metrics.duration = Some(duration_metrics);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use super::loudness::{self, LoudnessSignal};
+use super::pitch::{self, PitchSignal};
+use crate::domain::asr;
+use crate::domain::recorder::pauses::{self as recorder_pauses, PauseSpan};
+use crate::domain::recorder::storage::RAW_AUDIO_ARTIFACT_TYPE;
+use crate::kernel::models;
+use crate::platform::artifacts;
+
+/// Signal-level measurements of a recording, decoded once per analysis.
+#[derive(Debug, Default)]
+pub struct AudioSignals {
+    pub pauses: Option<Vec<PauseSpan>>,
+    pub loudness: Option<LoudnessSignal>,
+    pub pitch: Option<PitchSignal>,
+}
+
+pub fn audio_signals(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: Option<&str>,
+    segments: &[models::TranscriptSegment],
+    subject: (&str, &str),
+    with_pitch: bool,
+) -> AudioSignals {
+    let Some(audio_artifact_id) = audio_artifact_id else {
+        return AudioSignals::default();
+    };
+    let Ok((samples, _)) = load_analysis_samples(app, profile_id, audio_artifact_id) else {
+        return AudioSignals::default();
+    };
+    AudioSignals {
+        pauses: Some(recorder_pauses::detect_pauses_from_samples(
+            &samples, 16_000,
+        )),
+        loudness: loudness::loudness_signal(app, profile_id, audio_artifact_id, &samples, segments),
+        pitch: with_pitch.then(|| {
+            pitch::pitch_signal(
+                app,
+                profile_id,
+                audio_artifact_id,
+                &samples,
+                segments,
+                subject,
+            )
+        }),
+    }
+}
+
+pub(super) fn load_audio_samples(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: &str,
+) -> Result<(Vec<f32>, i64), String> {
+    let artifact = artifacts::get_artifact(app, profile_id, audio_artifact_id)?;
+    if artifact.artifact_type != "audio" {
+        return Err("artifact_not_audio".to_string());
+    }
+    read_samples(app, profile_id, &artifact.relpath)
+}
+
+/// Prefers the unprocessed companion of a recording so gating and limiting
+/// do not skew pause and loudness measurements.
+pub(super) fn load_analysis_samples(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: &str,
+) -> Result<(Vec<f32>, i64), String> {
+    let metadata = artifacts::artifact_metadata(app, profile_id, audio_artifact_id)?;
+    if let Some(raw_id) = metadata.get("raw_artifact_id").and_then(|v| v.as_str()) {
+        match artifacts::get_artifact(app, profile_id, raw_id) {
+            Ok(raw) if raw.artifact_type == RAW_AUDIO_ARTIFACT_TYPE => {
+                return read_samples(app, profile_id, &raw.relpath);
+            }
+            _ => eprintln!("raw audio companion missing for {audio_artifact_id}"),
+        }
+    }
+    load_audio_samples(app, profile_id, audio_artifact_id)
+}
+
+fn read_samples(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    relpath: &str,
+) -> Result<(Vec<f32>, i64), String> {
+    let audio_path = artifacts::resolve_profile_relpath_for_read(app, profile_id, relpath)?;
+    let bytes = std::fs::read(&audio_path).map_err(|e| format!("audio_read: {e}"))?;
+    asr::decode_wav_mono_16k(&bytes)
+}
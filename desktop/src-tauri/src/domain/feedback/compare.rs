@@ -0,0 +1,242 @@
+use super::context::feedback_get;
+use super::metrics;
+use super::repo::{self, CompareSubjectRow};
+use super::types::{
+    ComparedSubject, FeedbackComparison, MetricComparison, OutlineSectionComparison,
+};
+use crate::domain::asr::transcript;
+use crate::kernel::models;
+use crate::platform::db;
+
+const MAX_COMPARED_SUBJECTS: usize = 10;
+
+pub fn feedback_compare(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    subject_type: &str,
+    subject_ids: &[String],
+) -> Result<FeedbackComparison, String> {
+    validate_subject_ids(subject_ids)?;
+    db::ensure_profile_exists(app, profile_id)?;
+    let conn = db::open_profile(app, profile_id)?;
+
+    let mut rows = subject_ids
+        .iter()
+        .map(|subject_id| repo::select_compare_subject(&conn, subject_type, subject_id))
+        .collect::<Result<Vec<CompareSubjectRow>, String>>()?;
+    let project_id = rows[0].project_id.clone();
+    if rows.iter().any(|row| row.project_id != project_id) {
+        return Err("compare_project_mismatch".to_string());
+    }
+    rows.sort_by(|a, b| {
+        a.created_at
+            .cmp(&b.created_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    let mut subjects = Vec::with_capacity(rows.len());
+    let mut feedbacks = Vec::with_capacity(rows.len());
+    for row in rows {
+        let feedback_id = row
+            .feedback_id
+            .ok_or_else(|| "compare_feedback_missing".to_string())?;
+        let feedback = feedback_get(app, profile_id, &feedback_id)?;
+        let duration_sec = row
+            .transcript_id
+            .and_then(|transcript_id| {
+                transcript::load_transcript(app, profile_id, &transcript_id).ok()
+            })
+            .and_then(|doc| transcript::transcript_duration_ms(&doc))
+            .map(|ms| (ms as f64 / 1000.0).round() as i64);
+        subjects.push(ComparedSubject {
+            subject_id: row.id,
+            created_at: row.created_at,
+            feedback_id,
+            overall_score: feedback.overall_score,
+            duration_sec,
+        });
+        feedbacks.push(feedback);
+    }
+
+    let durations: Vec<Option<f64>> = subjects
+        .iter()
+        .map(|subject| subject.duration_sec.map(|sec| sec as f64))
+        .collect();
+    Ok(FeedbackComparison {
+        subject_type: subject_type.to_string(),
+        project_id,
+        metrics: compare_metrics(&feedbacks, durations),
+        outline_sections: compare_outline(&feedbacks),
+        subjects,
+    })
+}
+
+fn validate_subject_ids(subject_ids: &[String]) -> Result<(), String> {
+    if subject_ids.len() < 2 {
+        return Err("compare_needs_two_subjects".to_string());
+    }
+    if subject_ids.len() > MAX_COMPARED_SUBJECTS {
+        return Err("compare_too_many_subjects".to_string());
+    }
+    for (idx, subject_id) in subject_ids.iter().enumerate() {
+        if subject_ids[..idx].contains(subject_id) {
+            return Err("compare_duplicate_subject".to_string());
+        }
+    }
+    Ok(())
+}
+
+fn compare_metrics(
+    feedbacks: &[models::FeedbackV1],
+    durations: Vec<Option<f64>>,
+) -> Vec<MetricComparison> {
+    let per_subject: Vec<Vec<(&'static str, Option<f64>)>> =
+        feedbacks.iter().map(metrics::metric_values).collect();
+    let mut series: Vec<(String, Vec<Option<f64>>)> = vec![("duration_sec".to_string(), durations)];
+    for (idx, (metric, _)) in per_subject[0].iter().enumerate() {
+        let values = per_subject.iter().map(|values| values[idx].1).collect();
+        series.push((metric.to_string(), values));
+    }
+
+    series
+        .into_iter()
+        .map(|(metric, values)| {
+            let deltas = values
+                .windows(2)
+                .map(|pair| pair[0].zip(pair[1]).map(|(prev, next)| next - prev))
+                .collect();
+            let first = values.first().copied().flatten();
+            let last = values.last().copied().flatten();
+            MetricComparison {
+                verdict: metrics::verdict(&metric, first, last).to_string(),
+                delta: first.zip(last).map(|(first, last)| last - first),
+                metric,
+                values,
+                deltas,
+            }
+        })
+        .collect()
+}
+
+fn compare_outline(feedbacks: &[models::FeedbackV1]) -> Vec<OutlineSectionComparison> {
+    let mut titles: Vec<String> = Vec::new();
+    for feedback in feedbacks {
+        for section in feedback
+            .metrics
+            .outline
+            .iter()
+            .flat_map(|outline| outline.sections.iter())
+        {
+            if !titles.contains(&section.title) {
+                titles.push(section.title.clone());
+            }
+        }
+    }
+    titles
+        .into_iter()
+        .map(|title| OutlineSectionComparison {
+            covered: feedbacks
+                .iter()
+                .map(|feedback| {
+                    feedback.metrics.outline.as_ref().and_then(|outline| {
+                        outline
+                            .sections
+                            .iter()
+                            .find(|section| section.title == title)
+                            .map(|section| section.covered)
+                    })
+                })
+                .collect(),
+            title,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feedback(score: i64, filler_per_min: f64, covered: &[(&str, bool)]) -> models::FeedbackV1 {
+        let outline = (!covered.is_empty()).then(|| models::OutlineCoverage {
+            sections: covered
+                .iter()
+                .map(|(title, covered)| models::OutlineSectionCoverage {
+                    title: title.to_string(),
+                    covered: *covered,
+                    t_start_ms: None,
+                    duration_ms: 0,
+                    bullets_total: 0,
+                    bullets_covered: 0,
+                })
+                .collect(),
+            covered_count: 0,
+            coverage_ratio: 0.0,
+            skipped: Vec::new(),
+            reordered: Vec::new(),
+        });
+        models::FeedbackV1 {
+            schema_version: "1.1.0".to_string(),
+            overall_score: score,
+            top_actions: Vec::new(),
+            comments: Vec::new(),
+            metrics: models::FeedbackMetrics {
+                filler_per_min,
+                outline,
+                ..Default::default()
+            },
+            analyzers: Vec::new(),
+            markers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn subject_ids_need_two_distinct_entries() {
+        let one = vec!["run_a".to_string()];
+        assert_eq!(
+            validate_subject_ids(&one).expect_err("one"),
+            "compare_needs_two_subjects"
+        );
+        let dup = vec!["run_a".to_string(), "run_a".to_string()];
+        assert_eq!(
+            validate_subject_ids(&dup).expect_err("dup"),
+            "compare_duplicate_subject"
+        );
+    }
+
+    #[test]
+    fn metrics_compare_first_and_last_subject() {
+        let feedbacks = vec![
+            feedback(64, 6.0, &[]),
+            feedback(70, 5.0, &[]),
+            feedback(75, 2.0, &[]),
+        ];
+        let report = compare_metrics(&feedbacks, vec![Some(320.0), None, Some(290.0)]);
+        let fillers = report
+            .iter()
+            .find(|m| m.metric == "filler_per_min")
+            .expect("fillers");
+        assert_eq!(fillers.deltas, vec![Some(-1.0), Some(-3.0)]);
+        assert_eq!(fillers.delta, Some(-4.0));
+        assert_eq!(fillers.verdict, "improved");
+
+        let duration = report
+            .iter()
+            .find(|m| m.metric == "duration_sec")
+            .expect("duration");
+        assert_eq!(duration.deltas, vec![None, None]);
+        assert_eq!(duration.delta, Some(-30.0));
+    }
+
+    #[test]
+    fn outline_sections_are_merged_across_subjects() {
+        let feedbacks = vec![
+            feedback(60, 0.0, &[("Intro", true), ("Demo", false)]),
+            feedback(60, 0.0, &[]),
+            feedback(60, 0.0, &[("Intro", true), ("Demo", true), ("Q&A", false)]),
+        ];
+        let sections = compare_outline(&feedbacks);
+        let titles: Vec<&str> = sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["Intro", "Demo", "Q&A"]);
+        assert_eq!(sections[1].covered, vec![Some(false), None, Some(true)]);
+    }
+}
@@ -0,0 +1,164 @@
+use super::outline;
+use crate::kernel::models;
+
+const TOLERANCE_RATIO: f64 = 0.1;
+const MIN_TOLERANCE_SEC: i64 = 15;
+const MAX_NAMED_SECTIONS: usize = 2;
+
+pub(super) fn duration_metrics(
+    actual_ms: i64,
+    target_sec: i64,
+    timings: &[outline::SectionTiming],
+) -> models::DurationMetrics {
+    let actual_sec = (actual_ms.max(0) as f64 / 1000.0).round() as i64;
+    let projected_sec = projected_length_sec(actual_sec, timings);
+    let delta_sec = projected_sec - target_sec;
+    let tolerance = ((target_sec as f64 * TOLERANCE_RATIO).round() as i64).max(MIN_TOLERANCE_SEC);
+    let status = if delta_sec > tolerance {
+        "overrun"
+    } else if delta_sec < -tolerance {
+        "underrun"
+    } else {
+        "on_target"
+    };
+
+    models::DurationMetrics {
+        target_sec,
+        actual_sec,
+        projected_sec,
+        delta_sec,
+        status: status.to_string(),
+    }
+}
+
+pub(super) fn cut_action(
+    metrics: &models::DurationMetrics,
+    timings: &[outline::SectionTiming],
+) -> Option<models::FeedbackAction> {
+    if metrics.status != "overrun" {
+        return None;
+    }
+
+    let mut heaviest: Vec<&outline::SectionTiming> = timings
+        .iter()
+        .filter(|timing| timing.duration_ms > 0)
+        .collect();
+    heaviest.sort_by_key(|timing| std::cmp::Reverse(timing.duration_ms));
+    heaviest.truncate(MAX_NAMED_SECTIONS);
+
+    let how_to_fix = if heaviest.is_empty() {
+        "Pick the least essential point and drop it, then rehearse against a timer.".to_string()
+    } else {
+        let named = heaviest
+            .iter()
+            .map(|timing| {
+                format!(
+                    "\"{}\" ({})",
+                    timing.title,
+                    format_duration(timing.duration_ms)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join(" and ");
+        format!("Start trimming {named}, the sections that take the most time.")
+    };
+
+    Some(models::FeedbackAction {
+        action_id: "cut_to_target".to_string(),
+        title: format!("Cut {} seconds", metrics.delta_sec),
+        why_it_matters: "Running over the slot eats into questions and loses the room.".to_string(),
+        how_to_fix,
+        target_quest_codes: vec!["A01".to_string()],
+    })
+}
+
+fn projected_length_sec(actual_sec: i64, timings: &[outline::SectionTiming]) -> i64 {
+    let covered: Vec<&outline::SectionTiming> = timings
+        .iter()
+        .filter(|timing| timing.t_start_ms.is_some())
+        .collect();
+    if covered.is_empty() || covered.len() == timings.len() {
+        return actual_sec;
+    }
+    let last_covered = timings
+        .iter()
+        .rposition(|timing| timing.t_start_ms.is_some())
+        .unwrap_or(0);
+    let remaining = timings.len() - last_covered - 1;
+    let covered_ms: i64 = covered.iter().map(|timing| timing.duration_ms).sum();
+    let average_sec = covered_ms as f64 / covered.len() as f64 / 1000.0;
+    actual_sec + (average_sec * remaining as f64).round() as i64
+}
+
+fn format_duration(ms: i64) -> String {
+    let total_sec = (ms.max(0) as f64 / 1000.0).round() as i64;
+    let minutes = total_sec / 60;
+    let seconds = total_sec % 60;
+    if minutes > 0 {
+        format!("{minutes}m{seconds:02}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(title: &str, t_start_ms: Option<i64>, duration_ms: i64) -> outline::SectionTiming {
+        outline::SectionTiming {
+            title: title.to_string(),
+            matched: t_start_ms.is_some(),
+            t_start_ms,
+            duration_ms,
+            bullets_total: 0,
+            bullets_covered: 0,
+        }
+    }
+
+    #[test]
+    fn within_tolerance_is_on_target() {
+        let metrics = duration_metrics(610_000, 600, &[]);
+        assert_eq!(metrics.status, "on_target");
+        assert_eq!(metrics.delta_sec, 10);
+        assert!(cut_action(&metrics, &[]).is_none());
+    }
+
+    #[test]
+    fn underrun_is_reported_without_cut_action() {
+        let metrics = duration_metrics(300_000, 600, &[]);
+        assert_eq!(metrics.status, "underrun");
+        assert_eq!(metrics.delta_sec, -300);
+        assert!(cut_action(&metrics, &[]).is_none());
+    }
+
+    #[test]
+    fn overrun_names_heaviest_sections() {
+        let timings = [
+            timing("Opening", Some(0), 60_000),
+            timing("Deep dive", Some(60_000), 300_000),
+            timing("Demo", Some(360_000), 180_000),
+            timing("Closing", Some(540_000), 30_000),
+        ];
+        let metrics = duration_metrics(570_000, 420, &timings);
+        assert_eq!(metrics.status, "overrun");
+        let action = cut_action(&metrics, &timings).expect("cut action");
+        assert_eq!(action.title, "Cut 150 seconds");
+        assert!(action
+            .how_to_fix
+            .contains("\"Deep dive\" (5m00s) and \"Demo\" (3m00s)"));
+    }
+
+    #[test]
+    fn projection_adds_sections_not_reached_yet() {
+        let timings = [
+            timing("Opening", Some(0), 60_000),
+            timing("Body", Some(60_000), 120_000),
+            timing("Closing", None, 0),
+        ];
+        let metrics = duration_metrics(180_000, 300, &timings);
+        assert_eq!(metrics.actual_sec, 180);
+        assert_eq!(metrics.projected_sec, 270);
+        assert_eq!(metrics.status, "on_target");
+    }
+}
@@ -0,0 +1,137 @@
+const APOSTROPHES: [char; 3] = ['\'', '\u{2019}', '\u{02BC}'];
+const FR_ELISIONS: &[&str] = &[
+    "c", "d", "j", "l", "m", "n", "s", "t", "qu", "jusqu", "lorsqu", "puisqu", "quoiqu",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    En,
+    Fr,
+    Unknown,
+}
+
+impl Language {
+    pub fn from_code(code: &str) -> Self {
+        let primary = code
+            .trim()
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match primary.as_str() {
+            "en" => Language::En,
+            "fr" => Language::Fr,
+            _ => Language::Unknown,
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::Fr => "fr",
+            Language::Unknown => "auto",
+        }
+    }
+
+    fn splits_elisions(self) -> bool {
+        self != Language::En
+    }
+}
+
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ('\u{0300}'..='\u{036F}').contains(&ch)
+}
+
+pub fn tokenize(text: &str, language: Language) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(ch) = chars.next() {
+        if is_word_char(ch) {
+            current.extend(ch.to_lowercase());
+            continue;
+        }
+        let inside_word =
+            !current.is_empty() && chars.peek().is_some_and(|next| is_word_char(*next));
+        if APOSTROPHES.contains(&ch) && inside_word {
+            current.push('\'');
+            if language.splits_elisions() && FR_ELISIONS.contains(&current.trim_end_matches('\'')) {
+                out.push(std::mem::take(&mut current));
+            }
+        } else if !current.is_empty() {
+            out.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        out.push(current);
+    }
+    out
+}
+
+/// Elided articles and pronouns (`l'`, `qu'`) are kept as tokens but are not words.
+pub fn word_count(tokens: &[String]) -> usize {
+    tokens.iter().filter(|token| !token.ends_with('\'')).count()
+}
+
+pub fn normalize_word(word: &str) -> String {
+    word.trim()
+        .chars()
+        .map(|ch| if APOSTROPHES.contains(&ch) { '\'' } else { ch })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+pub fn raw_words(text: &str) -> Vec<&str> {
+    text.split(|ch: char| !is_word_char(ch))
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn language_codes_map_to_primary_subtag() {
+        assert_eq!(Language::from_code("fr-FR"), Language::Fr);
+        assert_eq!(Language::from_code("EN"), Language::En);
+        assert_eq!(Language::from_code("auto"), Language::Unknown);
+    }
+
+    #[test]
+    fn accented_words_stay_whole() {
+        assert_eq!(
+            tokenize("Évidemment, c'est déjà là !", Language::Fr),
+            vec!["évidemment", "c'", "est", "déjà", "là"]
+        );
+    }
+
+    #[test]
+    fn french_elisions_split_from_their_word() {
+        assert_eq!(
+            tokenize("l'API qu’on jusqu'ici aujourd'hui", Language::Fr),
+            vec!["l'", "api", "qu'", "on", "jusqu'", "ici", "aujourd'hui"]
+        );
+    }
+
+    #[test]
+    fn elisions_do_not_count_as_words() {
+        let tokens = tokenize("L'équipe sait qu'on l'aime", Language::Fr);
+        assert_eq!(tokens.len(), 7);
+        assert_eq!(word_count(&tokens), 4);
+        assert_eq!(word_count(&tokenize("We don't ship", Language::En)), 3);
+    }
+
+    #[test]
+    fn english_contractions_stay_whole() {
+        assert_eq!(
+            tokenize("We don't ship the students' code", Language::En),
+            vec!["we", "don't", "ship", "the", "students", "code"]
+        );
+    }
+
+    #[test]
+    fn combining_accents_do_not_split_words() {
+        assert_eq!(tokenize("de\u{301}ja\u{300}", Language::Fr).len(), 1);
+    }
+}
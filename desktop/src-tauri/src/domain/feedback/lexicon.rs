@@ -0,0 +1,155 @@
+use super::language::{self, Language};
+use super::repo;
+use crate::platform::seed;
+use rusqlite::Connection;
+use std::collections::HashSet;
+
+pub(super) const KIND_FILLER: &str = "filler";
+pub(super) const KIND_STOPWORD: &str = "stopword";
+pub(super) const KIND_JARGON_IGNORE: &str = "jargon_ignore";
+
+pub struct Lexicon {
+    pub language: Language,
+    fillers: Vec<Vec<String>>,
+    stopwords: HashSet<String>,
+    ignored: HashSet<String>,
+}
+
+impl Lexicon {
+    pub fn new<'a>(
+        language: Language,
+        fillers: impl IntoIterator<Item = &'a str>,
+        stopwords: impl IntoIterator<Item = &'a str>,
+        ignored: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        let mut fillers: Vec<Vec<String>> = fillers
+            .into_iter()
+            .map(|phrase| language::tokenize(phrase, language))
+            .filter(|tokens| !tokens.is_empty())
+            .collect();
+        fillers.sort_by_key(|tokens| std::cmp::Reverse(tokens.len()));
+        fillers.dedup();
+        let stopwords = stopwords
+            .into_iter()
+            .map(language::normalize_word)
+            .filter(|word| !word.is_empty())
+            .collect();
+        let ignored = ignored.into_iter().map(language::normalize_word).collect();
+        Self {
+            language,
+            fillers,
+            stopwords,
+            ignored,
+        }
+    }
+
+    pub fn defaults(language: Language) -> Self {
+        let lexicons = seed::lexicon_seed()
+            .map(|seed| seed.lexicons)
+            .unwrap_or_default();
+        let matching: Vec<_> = lexicons
+            .iter()
+            .filter(|lexicon| language == Language::Unknown || lexicon.language == language.code())
+            .collect();
+        Self::new(
+            language,
+            matching
+                .iter()
+                .flat_map(|lexicon| lexicon.fillers.iter().map(String::as_str)),
+            matching
+                .iter()
+                .flat_map(|lexicon| lexicon.stopwords.iter().map(String::as_str)),
+            [],
+        )
+    }
+
+    pub fn tokenize(&self, text: &str) -> Vec<String> {
+        language::tokenize(text, self.language)
+    }
+
+    pub fn is_stopword(&self, token: &str) -> bool {
+        self.stopwords.contains(token)
+    }
+
+    pub fn is_ignored(&self, term: &str) -> bool {
+        !self.ignored.is_empty() && self.ignored.contains(&language::normalize_word(term))
+    }
+
+    pub fn count_fillers(&self, tokens: &[String]) -> usize {
+        let mut count = 0;
+        let mut idx = 0;
+        while idx < tokens.len() {
+            match self
+                .fillers
+                .iter()
+                .find(|phrase| tokens[idx..].starts_with(phrase))
+            {
+                Some(phrase) => {
+                    count += 1;
+                    idx += phrase.len();
+                }
+                None => idx += 1,
+            }
+        }
+        count
+    }
+}
+
+pub fn profile_lexicon(conn: &Connection, language_code: &str) -> Result<Lexicon, String> {
+    let language = Language::from_code(language_code);
+    let scope = (language != Language::Unknown).then(|| language.code());
+    let terms = repo::select_lexicon_terms(conn, scope, None)?;
+    let of_kind = |kind: &'static str| {
+        terms
+            .iter()
+            .filter(move |term| term.kind == kind)
+            .map(|term| term.term.as_str())
+    };
+    Ok(Lexicon::new(
+        language,
+        of_kind(KIND_FILLER),
+        of_kind(KIND_STOPWORD),
+        of_kind(KIND_JARGON_IGNORE),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn filler_phrases_match_whole_tokens_only() {
+        let lexicon = Lexicon::defaults(Language::En);
+        let tokens = lexicon.tokenize("You know, you knowledge is kind of like, um, great");
+        assert_eq!(lexicon.count_fillers(&tokens), 4);
+    }
+
+    #[test]
+    fn french_fillers_are_found_next_to_accented_words() {
+        let lexicon = Lexicon::defaults(Language::Fr);
+        let tokens = lexicon.tokenize("Euh, évidemment, du coup l'équipe était déjà là, euh.");
+        assert_eq!(lexicon.count_fillers(&tokens), 3);
+    }
+
+    #[test]
+    fn ignored_terms_match_case_insensitively() {
+        let lexicon = Lexicon::new(Language::En, [], [], ["Kubernetes"]);
+        assert!(lexicon.is_ignored("KUBERNETES"));
+        assert!(!lexicon.is_ignored("docker"));
+    }
+
+    #[test]
+    fn unknown_language_merges_all_defaults() {
+        let lexicon = Lexicon::defaults(Language::Unknown);
+        let tokens = lexicon.tokenize("um euh du coup you know");
+        assert_eq!(lexicon.count_fillers(&tokens), 4);
+    }
+
+    #[test]
+    fn elided_articles_are_stopwords() {
+        let lexicon = Lexicon::defaults(Language::Fr);
+        assert!(lexicon.is_stopword("l'"));
+        assert!(lexicon.is_stopword("être"));
+        assert!(!lexicon.is_stopword("équipe"));
+    }
+}
@@ -0,0 +1,167 @@
+use super::language::{self, Language};
+use super::lexicon::{KIND_FILLER, KIND_JARGON_IGNORE, KIND_STOPWORD};
+use super::repo;
+use super::types::FeedbackLexiconTerm;
+use crate::kernel::time;
+use crate::platform::{db, seed};
+
+const MAX_TERM_CHARS: usize = 64;
+
+pub fn feedback_lexicon_list(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    language: Option<&str>,
+    kind: Option<&str>,
+) -> Result<Vec<FeedbackLexiconTerm>, String> {
+    let language = language.map(normalize_language).transpose()?;
+    let kind = kind.map(validate_kind).transpose()?;
+    db::ensure_profile_exists(app, profile_id)?;
+    let conn = db::open_profile(app, profile_id)?;
+    repo::select_lexicon_terms(&conn, language.as_deref(), kind)
+}
+
+pub fn feedback_lexicon_add(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    language: &str,
+    kind: &str,
+    term: &str,
+) -> Result<FeedbackLexiconTerm, String> {
+    let language = normalize_language(language)?;
+    let kind = validate_kind(kind)?;
+    let term = normalize_term(term)?;
+    db::ensure_profile_exists(app, profile_id)?;
+    let conn = db::open_profile(app, profile_id)?;
+    let now = time::now_rfc3339();
+    repo::insert_lexicon_term(&conn, &language, kind, &term, &now)?;
+    repo::select_lexicon_terms(&conn, Some(&language), Some(kind))?
+        .into_iter()
+        .find(|entry| entry.term == term)
+        .ok_or_else(|| "lexicon_term_missing".to_string())
+}
+
+pub fn feedback_lexicon_remove(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    language: &str,
+    kind: &str,
+    term: &str,
+) -> Result<(), String> {
+    let language = normalize_language(language)?;
+    let kind = validate_kind(kind)?;
+    let term = normalize_term(term)?;
+    db::ensure_profile_exists(app, profile_id)?;
+    let conn = db::open_profile(app, profile_id)?;
+    if repo::delete_lexicon_term(&conn, &language, kind, &term)? == 0 {
+        return Err("lexicon_term_not_found".to_string());
+    }
+    Ok(())
+}
+
+pub fn feedback_lexicon_reset(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    language: &str,
+) -> Result<Vec<FeedbackLexiconTerm>, String> {
+    let language = normalize_language(language)?;
+    let seed = seed::lexicon_seed()?;
+    let defaults: Vec<(&str, &str)> = seed
+        .lexicons
+        .iter()
+        .filter(|lexicon| lexicon.language == language)
+        .flat_map(|lexicon| {
+            lexicon
+                .fillers
+                .iter()
+                .map(|term| (KIND_FILLER, term.as_str()))
+                .chain(
+                    lexicon
+                        .stopwords
+                        .iter()
+                        .map(|term| (KIND_STOPWORD, term.as_str())),
+                )
+        })
+        .collect();
+
+    db::ensure_profile_exists(app, profile_id)?;
+    let mut conn = db::open_profile(app, profile_id)?;
+    let now = time::now_rfc3339();
+    repo::replace_lexicon_terms(
+        &mut conn,
+        &language,
+        &[KIND_FILLER, KIND_STOPWORD],
+        &defaults,
+        &now,
+    )?;
+    repo::select_lexicon_terms(&conn, Some(&language), None)
+}
+
+fn normalize_language(language: &str) -> Result<String, String> {
+    let code = language.trim().to_ascii_lowercase();
+    let valid = (2..=3).contains(&code.len()) && code.chars().all(|ch| ch.is_ascii_lowercase());
+    if !valid {
+        return Err("lexicon_language_invalid".to_string());
+    }
+    Ok(code)
+}
+
+fn validate_kind(kind: &str) -> Result<&'static str, String> {
+    [KIND_FILLER, KIND_STOPWORD, KIND_JARGON_IGNORE]
+        .into_iter()
+        .find(|known| *known == kind)
+        .ok_or_else(|| "lexicon_kind_invalid".to_string())
+}
+
+/// Stores the tokenized form, which is what `Lexicon` matches against.
+/// Elided tokens stay attached so "qu'on" tokenizes back the same way.
+fn normalize_term(term: &str) -> Result<String, String> {
+    let mut normalized = String::new();
+    for token in language::tokenize(term, Language::Unknown) {
+        if !normalized.is_empty() && !normalized.ends_with('\'') {
+            normalized.push(' ');
+        }
+        normalized.push_str(&token);
+    }
+    if normalized.is_empty() || normalized.chars().count() > MAX_TERM_CHARS {
+        return Err("lexicon_term_invalid".to_string());
+    }
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn language_codes_are_normalized() {
+        assert_eq!(normalize_language(" FR ").expect("fr"), "fr");
+        assert_eq!(
+            normalize_language("fr-FR").expect_err("region"),
+            "lexicon_language_invalid"
+        );
+    }
+
+    #[test]
+    fn kinds_are_restricted() {
+        assert_eq!(
+            validate_kind("jargon_ignore").expect("kind"),
+            "jargon_ignore"
+        );
+        assert_eq!(
+            validate_kind("slang").expect_err("kind"),
+            "lexicon_kind_invalid"
+        );
+    }
+
+    #[test]
+    fn terms_are_lowercased_and_collapsed() {
+        assert_eq!(normalize_term("  En   Fait ").expect("term"), "en fait");
+        assert_eq!(normalize_term("Right?").expect("term"), "right");
+        assert_eq!(normalize_term("Qu’on").expect("term"), "qu'on");
+        assert_eq!(normalize_term("you know,").expect("term"), "you know");
+        assert_eq!(
+            normalize_term("?!").expect_err("term"),
+            "lexicon_term_invalid"
+        );
+    }
+}
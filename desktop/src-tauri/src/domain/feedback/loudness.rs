@@ -0,0 +1,241 @@
+use crate::domain::recorder::loudness::{
+    self as recorder_loudness, ClippingEvent, LoudnessAnalysis, TrailingOff,
+};
+use crate::kernel::models;
+use crate::platform::artifacts;
+
+pub const LOUDNESS_ENVELOPE_ARTIFACT_TYPE: &str = "loudness_envelope";
+const ENVELOPE_SCHEMA_VERSION: &str = "1.0.0";
+const MONOTONE_VARIATION_DB: f32 = 2.5;
+
+#[derive(Debug)]
+pub struct LoudnessSignal {
+    pub envelope_artifact_id: Option<String>,
+    pub analysis: LoudnessAnalysis,
+}
+
+/// Measures the loudness of a recording. Its RMS envelope only depends on
+/// the audio, so it is stored once per audio artifact and reused afterwards.
+pub(super) fn loudness_signal(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: &str,
+    samples: &[f32],
+    segments: &[models::TranscriptSegment],
+) -> Option<LoudnessSignal> {
+    let analysis = recorder_loudness::analyze_loudness(samples, 16_000, &sentence_spans(segments))?;
+    let envelope_artifact_id = match find_envelope_artifact(app, profile_id, audio_artifact_id) {
+        Some(id) => Some(id),
+        None => match store_envelope(app, profile_id, audio_artifact_id, &analysis) {
+            Ok(id) => Some(id),
+            Err(err) => {
+                eprintln!("loudness envelope not stored for {audio_artifact_id}: {err}");
+                None
+            }
+        },
+    };
+    Some(LoudnessSignal {
+        envelope_artifact_id,
+        analysis,
+    })
+}
+
+fn find_envelope_artifact(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: &str,
+) -> Option<String> {
+    artifacts::find_audio_derived::<models::LoudnessEnvelopeV1>(
+        app,
+        profile_id,
+        LOUDNESS_ENVELOPE_ARTIFACT_TYPE,
+        audio_artifact_id,
+    )
+    .ok()
+    .flatten()
+    .filter(|(_, envelope)| envelope.schema_version == ENVELOPE_SCHEMA_VERSION)
+    .map(|(id, _)| id)
+}
+
+fn store_envelope(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: &str,
+    analysis: &LoudnessAnalysis,
+) -> Result<String, String> {
+    let envelope = envelope_document(audio_artifact_id, analysis);
+    let bytes = serde_json::to_vec(&envelope).map_err(|e| format!("loudness_json: {e}"))?;
+    let metadata = serde_json::json!({
+        "source_audio_artifact_id": audio_artifact_id,
+        "step_ms": envelope.step_ms,
+    });
+    let record = artifacts::store_bytes(
+        app,
+        profile_id,
+        LOUDNESS_ENVELOPE_ARTIFACT_TYPE,
+        "json",
+        &bytes,
+        &metadata,
+    )?;
+    Ok(record.id)
+}
+
+fn envelope_document(
+    audio_artifact_id: &str,
+    analysis: &LoudnessAnalysis,
+) -> models::LoudnessEnvelopeV1 {
+    models::LoudnessEnvelopeV1 {
+        schema_version: ENVELOPE_SCHEMA_VERSION.to_string(),
+        source_audio_artifact_id: audio_artifact_id.to_string(),
+        step_ms: analysis.step_ms,
+        envelope_db: analysis
+            .envelope_db
+            .iter()
+            .map(|db| (db * 10.0).round() / 10.0)
+            .collect(),
+    }
+}
+
+/// Spans of transcript segments that close a sentence. Transcripts without
+/// punctuation treat every segment as one.
+pub(super) fn sentence_spans(segments: &[models::TranscriptSegment]) -> Vec<(i64, i64)> {
+    let closes_sentence = |segment: &&models::TranscriptSegment| {
+        segment.text.trim_end().ends_with(['.', '!', '?', '…'])
+    };
+    let punctuated = segments.iter().any(|segment| closes_sentence(&segment));
+    let mut spans = Vec::new();
+    let mut sentence_start: Option<i64> = None;
+    for segment in segments {
+        if segment.text.trim().is_empty() {
+            continue;
+        }
+        let start = *sentence_start.get_or_insert(segment.t_start_ms);
+        if !punctuated || closes_sentence(&segment) {
+            spans.push((start, segment.t_end_ms));
+            sentence_start = None;
+        }
+    }
+    spans
+}
+
+pub(super) fn loudness_metrics(signal: &LoudnessSignal) -> models::LoudnessMetrics {
+    let analysis = &signal.analysis;
+    models::LoudnessMetrics {
+        speech_mean_db: round_db(analysis.speech_mean_db),
+        variation_db: analysis.variation_db.map(round_db),
+        dynamic_range_db: round_db(analysis.dynamic_range_db),
+        monotone: is_monotone(analysis),
+        trailing_off_count: analysis.trailing_offs.len() as i64,
+        clipping_count: analysis.clipping.len() as i64,
+        envelope_artifact_id: signal.envelope_artifact_id.clone(),
+    }
+}
+
+/// A take too short to hold two phrases is never judged monotone.
+pub(super) fn is_monotone(analysis: &LoudnessAnalysis) -> bool {
+    analysis
+        .variation_db
+        .is_some_and(|variation| variation < MONOTONE_VARIATION_DB)
+}
+
+pub(super) fn trailing_off_comment(event: &TrailingOff) -> models::FeedbackComment {
+    models::FeedbackComment {
+        t_start_ms: event.t_start_ms.max(0),
+        t_end_ms: event.t_end_ms.max(0),
+        severity: "medium".to_string(),
+        label: "Trailing off".to_string(),
+        evidence: Some(serde_json::json!({
+            "kind": "trailing_off",
+            "drop_db": round_db(event.drop_db),
+        })),
+        suggestion: "Keep your voice up through the last words of the sentence.".to_string(),
+    }
+}
+
+pub(super) fn clipping_comment(event: &ClippingEvent) -> models::FeedbackComment {
+    models::FeedbackComment {
+        t_start_ms: event.t_start_ms.max(0),
+        t_end_ms: event.t_end_ms.max(0),
+        severity: "high".to_string(),
+        label: "Clipping".to_string(),
+        evidence: Some(serde_json::json!({
+            "kind": "clipping",
+            "clipped_samples": event.clipped_samples,
+        })),
+        suggestion: "Step back from the microphone or lower the input gain.".to_string(),
+    }
+}
+
+pub(super) fn monotone_action() -> models::FeedbackAction {
+    models::FeedbackAction {
+        action_id: "vary_loudness".to_string(),
+        title: "Vary your vocal energy".to_string(),
+        why_it_matters: "A flat loudness level makes key points blend into the rest.".to_string(),
+        how_to_fix: "Mark the one idea per section you want to land and say it with more energy."
+            .to_string(),
+        target_quest_codes: vec!["D01".to_string()],
+    }
+}
+
+fn round_db(db: f32) -> f64 {
+    (db as f64 * 10.0).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(t_start_ms: i64, t_end_ms: i64, text: &str) -> models::TranscriptSegment {
+        models::TranscriptSegment {
+            t_start_ms,
+            t_end_ms,
+            text: text.to_string(),
+            confidence: None,
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn sentences_span_segments_until_punctuation() {
+        let spans = sentence_spans(&[
+            segment(0, 1_000, "We shipped"),
+            segment(1_000, 2_500, "the new build."),
+            segment(3_000, 4_000, " "),
+            segment(4_000, 5_000, "Questions?"),
+        ]);
+        assert_eq!(spans, vec![(0, 2_500), (4_000, 5_000)]);
+
+        let unpunctuated = sentence_spans(&[segment(0, 1_000, "a"), segment(1_000, 2_000, "b")]);
+        assert_eq!(unpunctuated, vec![(0, 1_000), (1_000, 2_000)]);
+    }
+
+    #[test]
+    fn single_phrase_take_is_not_monotone() {
+        let samples: Vec<f32> = (0..16_000).map(|i| 0.3 * (i as f32 * 0.07).sin()).collect();
+        let analysis = crate::domain::recorder::loudness::analyze_loudness(&samples, 16_000, &[])
+            .expect("analysis");
+        assert!(!is_monotone(&analysis));
+        let metrics = loudness_metrics(&LoudnessSignal {
+            envelope_artifact_id: None,
+            analysis,
+        });
+        assert_eq!(metrics.variation_db, None);
+        assert!(!metrics.monotone);
+    }
+
+    #[test]
+    fn envelope_document_keeps_one_level_per_frame() {
+        let samples: Vec<f32> = (0..16_000).map(|i| 0.3 * (i as f32 * 0.07).sin()).collect();
+        let analysis = crate::domain::recorder::loudness::analyze_loudness(&samples, 16_000, &[])
+            .expect("analysis");
+        let envelope = envelope_document("art_audio", &analysis);
+        assert_eq!(envelope.source_audio_artifact_id, "art_audio");
+        assert_eq!(envelope.step_ms, analysis.step_ms);
+        assert_eq!(envelope.envelope_db.len(), analysis.envelope_db.len());
+        assert!(envelope
+            .envelope_db
+            .iter()
+            .zip(&analysis.envelope_db)
+            .all(|(stored, level)| (stored - level).abs() <= 0.05));
+    }
+}
@@ -0,0 +1,226 @@
+use super::types::{MetricDelta, TermsDelta};
+use crate::kernel::models;
+
+const TARGET_WPM: f64 = 145.0;
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    HigherIsBetter,
+    LowerIsBetter,
+    CloserTo(f64),
+    Neutral,
+}
+
+fn metric_rule(metric: &str) -> (Direction, f64) {
+    match metric {
+        "overall_score" => (Direction::HigherIsBetter, 1.0),
+        "wpm" | "pace_baseline_wpm" => (Direction::CloserTo(TARGET_WPM), 5.0),
+        "filler_per_min" => (Direction::LowerIsBetter, 0.25),
+        "avg_sentence_words" => (Direction::LowerIsBetter, 1.0),
+        "duration_delta_sec" => (Direction::CloserTo(0.0), 5.0),
+        "outline_coverage_ratio" => (Direction::HigherIsBetter, 0.01),
+        "pause_count" => (Direction::Neutral, 0.5),
+        "pause_mean_ms" => (Direction::Neutral, 50.0),
+        "loudness_variation_db" => (Direction::HigherIsBetter, 0.5),
+        "trailing_off_count" | "clipping_count" => (Direction::LowerIsBetter, 1.0),
+        "pitch_range_st" => (Direction::HigherIsBetter, 0.5),
+        "flat_stretch_count" => (Direction::LowerIsBetter, 1.0),
+        "duration_sec" | "duration_actual_sec" => (Direction::Neutral, 1.0),
+        _ => (Direction::Neutral, 1e-6),
+    }
+}
+
+pub(super) fn verdict(metric: &str, base: Option<f64>, target: Option<f64>) -> &'static str {
+    let (Some(base), Some(target)) = (base, target) else {
+        return "unavailable";
+    };
+    let (direction, tolerance) = metric_rule(metric);
+    if (target - base).abs() < tolerance {
+        return "unchanged";
+    }
+    let gain = match direction {
+        Direction::HigherIsBetter => target - base,
+        Direction::LowerIsBetter => base - target,
+        Direction::CloserTo(goal) => (base - goal).abs() - (target - goal).abs(),
+        Direction::Neutral => return "changed",
+    };
+    if gain.abs() < tolerance {
+        "unchanged"
+    } else if gain > 0.0 {
+        "improved"
+    } else {
+        "regressed"
+    }
+}
+
+pub(super) fn metric_values(feedback: &models::FeedbackV1) -> Vec<(&'static str, Option<f64>)> {
+    let metrics = &feedback.metrics;
+    vec![
+        ("overall_score", Some(feedback.overall_score as f64)),
+        ("wpm", Some(metrics.wpm)),
+        ("filler_per_min", Some(metrics.filler_per_min)),
+        ("pause_count", Some(metrics.pause_count as f64)),
+        ("avg_sentence_words", Some(metrics.avg_sentence_words)),
+        ("density_score", Some(metrics.density_score)),
+        (
+            "pause_mean_ms",
+            metrics.pauses.as_ref().map(|pauses| pauses.mean_ms),
+        ),
+        (
+            "pace_baseline_wpm",
+            metrics.pace.as_ref().map(|pace| pace.baseline_wpm),
+        ),
+        (
+            "duration_actual_sec",
+            metrics
+                .duration
+                .as_ref()
+                .map(|duration| duration.actual_sec as f64),
+        ),
+        (
+            "duration_delta_sec",
+            metrics
+                .duration
+                .as_ref()
+                .map(|duration| duration.delta_sec as f64),
+        ),
+        (
+            "outline_coverage_ratio",
+            metrics
+                .outline
+                .as_ref()
+                .map(|outline| outline.coverage_ratio),
+        ),
+        (
+            "loudness_variation_db",
+            metrics
+                .loudness
+                .as_ref()
+                .and_then(|loudness| loudness.variation_db),
+        ),
+        (
+            "trailing_off_count",
+            metrics
+                .loudness
+                .as_ref()
+                .map(|loudness| loudness.trailing_off_count as f64),
+        ),
+        (
+            "clipping_count",
+            metrics
+                .loudness
+                .as_ref()
+                .map(|loudness| loudness.clipping_count as f64),
+        ),
+        (
+            "pitch_range_st",
+            metrics.pitch.as_ref().map(|pitch| pitch.range_st),
+        ),
+        (
+            "flat_stretch_count",
+            metrics
+                .pitch
+                .as_ref()
+                .map(|pitch| pitch.flat_stretch_count as f64),
+        ),
+    ]
+}
+
+pub(super) fn metric_deltas(
+    base: &models::FeedbackV1,
+    target: &models::FeedbackV1,
+) -> Vec<MetricDelta> {
+    metric_values(base)
+        .into_iter()
+        .zip(metric_values(target))
+        .map(|((metric, base), (_, target))| MetricDelta {
+            metric: metric.to_string(),
+            base,
+            target,
+            delta: base.zip(target).map(|(base, target)| target - base),
+            verdict: verdict(metric, base, target).to_string(),
+        })
+        .collect()
+}
+
+pub(super) fn terms_delta(base: &[String], target: &[String]) -> TermsDelta {
+    TermsDelta {
+        added: target
+            .iter()
+            .filter(|term| !base.contains(term))
+            .cloned()
+            .collect(),
+        removed: base
+            .iter()
+            .filter(|term| !target.contains(term))
+            .cloned()
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feedback(score: i64, wpm: f64, repeat_terms: &[&str]) -> models::FeedbackV1 {
+        models::FeedbackV1 {
+            schema_version: "1.1.0".to_string(),
+            overall_score: score,
+            top_actions: Vec::new(),
+            comments: Vec::new(),
+            metrics: models::FeedbackMetrics {
+                wpm,
+                repeat_terms: repeat_terms.iter().map(|term| term.to_string()).collect(),
+                ..Default::default()
+            },
+            analyzers: Vec::new(),
+            markers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn deltas_are_target_minus_base() {
+        let deltas = metric_deltas(&feedback(70, 180.0, &[]), &feedback(78, 150.0, &[]));
+        let score = deltas
+            .iter()
+            .find(|d| d.metric == "overall_score")
+            .expect("score");
+        assert_eq!(score.delta, Some(8.0));
+        assert_eq!(score.verdict, "improved");
+        let wpm = deltas.iter().find(|d| d.metric == "wpm").expect("wpm");
+        assert_eq!(wpm.delta, Some(-30.0));
+        assert_eq!(wpm.verdict, "improved");
+    }
+
+    #[test]
+    fn optional_metrics_have_no_delta_when_missing() {
+        let deltas = metric_deltas(&feedback(70, 150.0, &[]), &feedback(78, 130.0, &[]));
+        let coverage = deltas
+            .iter()
+            .find(|d| d.metric == "outline_coverage_ratio")
+            .expect("coverage");
+        assert_eq!((coverage.base, coverage.delta), (None, None));
+        assert_eq!(coverage.verdict, "unavailable");
+    }
+
+    #[test]
+    fn verdict_follows_metric_direction() {
+        assert_eq!(verdict("filler_per_min", Some(4.0), Some(6.0)), "regressed");
+        assert_eq!(verdict("filler_per_min", Some(4.0), Some(4.1)), "unchanged");
+        assert_eq!(
+            verdict("duration_delta_sec", Some(-40.0), Some(20.0)),
+            "improved"
+        );
+        assert_eq!(verdict("wpm", Some(140.0), Some(170.0)), "regressed");
+        assert_eq!(verdict("pause_count", Some(3.0), Some(8.0)), "changed");
+    }
+
+    #[test]
+    fn terms_delta_lists_added_and_removed() {
+        let base = vec!["api".to_string(), "cache".to_string()];
+        let target = vec!["cache".to_string(), "queue".to_string()];
+        let delta = terms_delta(&base, &target);
+        assert_eq!(delta.added, vec!["queue"]);
+        assert_eq!(delta.removed, vec!["api"]);
+    }
+}
@@ -1,16 +1,42 @@
 pub mod analysis;
 mod analyze;
+pub mod analyzers;
+mod audio;
+mod compare;
 mod context;
+mod duration;
+pub mod language;
+pub mod lexicon;
+mod lexicon_terms;
+mod loudness;
+mod metrics;
 mod notes;
+mod outline;
+mod pace;
+mod pauses;
+mod pitch;
 mod repo;
+mod revisions;
 mod timeline;
 mod types;
 
 pub use analyze::analyze_attempt;
+pub use analyzers::feedback_analyzers_list;
+pub use audio::{audio_signals, AudioSignals};
+pub use compare::feedback_compare;
 pub use context::{feedback_context_get, feedback_get};
+pub use lexicon::profile_lexicon;
+pub use lexicon_terms::{
+    feedback_lexicon_add, feedback_lexicon_list, feedback_lexicon_remove, feedback_lexicon_reset,
+};
 pub use notes::{feedback_note_get, feedback_note_set};
+pub use revisions::{feedback_revisions_diff, feedback_revisions_list};
 pub use timeline::feedback_timeline_list;
-pub use types::{AnalyzeResponse, FeedbackContext, FeedbackTimelineItem};
+pub use types::{
+    AnalyzeResponse, ComparedSubject, FeedbackAnalyzerStatus, FeedbackComparison, FeedbackContext,
+    FeedbackLexiconTerm, FeedbackRevision, FeedbackRevisionDiff, FeedbackTimelineItem,
+    MetricComparison, MetricDelta, OutlineSectionComparison, TermsDelta,
+};
 
 #[cfg(test)]
 mod tests {
@@ -70,4 +96,82 @@ mod tests {
             .expect("count");
         assert_eq!(feedback_count, 0);
     }
+
+    #[test]
+    fn feedback_revisions_are_numbered_and_flag_current() {
+        let conn = Connection::open_in_memory().expect("open");
+        conn.execute_batch(
+            "CREATE TABLE quest_attempts (id TEXT PRIMARY KEY, feedback_id TEXT);
+             CREATE TABLE runs (id TEXT PRIMARY KEY, feedback_id TEXT);
+             CREATE TABLE auto_feedback (
+               id TEXT PRIMARY KEY,
+               subject_type TEXT NOT NULL,
+               subject_id TEXT NOT NULL,
+               created_at TEXT NOT NULL,
+               feedback_json_artifact_id TEXT NOT NULL,
+               overall_score INTEGER NOT NULL
+             );
+             INSERT INTO runs (id, feedback_id) VALUES ('run_1', 'fb_b');
+             INSERT INTO auto_feedback VALUES
+               ('fb_b', 'run', 'run_1', '2026-03-02T00:00:00Z', 'art_b', 81),
+               ('fb_a', 'run', 'run_1', '2026-03-01T00:00:00Z', 'art_a', 72),
+               ('fb_other', 'run', 'run_2', '2026-03-01T00:00:00Z', 'art_c', 60);",
+        )
+        .expect("schema");
+
+        let revisions = repo::select_feedback_revisions(&conn, "run", "run_1").expect("revisions");
+        let summary: Vec<(&str, i64, bool)> = revisions
+            .iter()
+            .map(|r| (r.feedback_id.as_str(), r.revision, r.is_current))
+            .collect();
+        assert_eq!(summary, vec![("fb_a", 1, false), ("fb_b", 2, true)]);
+    }
+
+    #[test]
+    fn timeline_lists_only_the_current_revision() {
+        let conn = Connection::open_in_memory().expect("open");
+        conn.execute_batch(
+            "CREATE TABLE quests (code TEXT PRIMARY KEY, title TEXT NOT NULL);
+             CREATE TABLE quest_attempts (
+               id TEXT PRIMARY KEY,
+               project_id TEXT NOT NULL,
+               quest_code TEXT NOT NULL,
+               feedback_id TEXT
+             );
+             CREATE TABLE runs (id TEXT PRIMARY KEY, project_id TEXT NOT NULL, feedback_id TEXT);
+             CREATE TABLE feedback_notes (feedback_id TEXT PRIMARY KEY, updated_at TEXT NOT NULL);
+             CREATE TABLE auto_feedback (
+               id TEXT PRIMARY KEY,
+               subject_type TEXT NOT NULL,
+               subject_id TEXT NOT NULL,
+               created_at TEXT NOT NULL,
+               feedback_json_artifact_id TEXT NOT NULL,
+               overall_score INTEGER NOT NULL
+             );
+             INSERT INTO quests VALUES ('Q01', 'Warm-up');
+             INSERT INTO quest_attempts VALUES ('att_1', 'proj_1', 'Q01', 'fb_att');
+             INSERT INTO runs VALUES ('run_1', 'proj_1', 'fb_a');
+             INSERT INTO auto_feedback VALUES
+               ('fb_att', 'quest_attempt', 'att_1', '2026-03-01T00:00:00Z', 'art_att', 64),
+               ('fb_a', 'run', 'run_1', '2026-03-02T00:00:00Z', 'art_a', 72);",
+        )
+        .expect("schema");
+
+        // Re-analysis adds a revision and moves the run to it.
+        conn.execute_batch(
+            "INSERT INTO auto_feedback VALUES
+               ('fb_b', 'run', 'run_1', '2026-03-03T00:00:00Z', 'art_b', 81);
+             UPDATE runs SET feedback_id = 'fb_b' WHERE id = 'run_1';",
+        )
+        .expect("reanalyze");
+
+        let items = repo::select_feedback_timeline(&conn, Some("proj_1"), 10).expect("timeline");
+        let ids: Vec<&str> = items.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, vec!["fb_b", "fb_att"]);
+        let run_entries = items
+            .iter()
+            .filter(|item| item.run_id.as_deref() == Some("run_1"))
+            .count();
+        assert_eq!(run_entries, 1);
+    }
 }
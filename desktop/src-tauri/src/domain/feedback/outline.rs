@@ -0,0 +1,383 @@
+use super::lexicon::Lexicon;
+use crate::kernel::models;
+
+#[derive(Debug)]
+pub(super) struct OutlineSection {
+    pub(super) title: String,
+    pub(super) bullets: Vec<String>,
+}
+
+#[derive(Debug)]
+pub(super) struct SectionTiming {
+    pub(super) title: String,
+    pub(super) matched: bool,
+    pub(super) t_start_ms: Option<i64>,
+    pub(super) duration_ms: i64,
+    pub(super) bullets_total: i64,
+    pub(super) bullets_covered: i64,
+}
+
+pub(super) fn parse_outline(markdown: &str) -> Vec<OutlineSection> {
+    let mut headings: Vec<(usize, OutlineSection)> = Vec::new();
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if let Some(level) = heading_level(trimmed) {
+            let title = trimmed[level..].trim().to_string();
+            if !title.is_empty() {
+                headings.push((
+                    level,
+                    OutlineSection {
+                        title,
+                        bullets: Vec::new(),
+                    },
+                ));
+            }
+            continue;
+        }
+        if let Some(bullet) = bullet_text(trimmed) {
+            if let Some((_, section)) = headings.last_mut() {
+                section.bullets.push(bullet.to_string());
+            }
+        }
+    }
+
+    let top_level_count = headings.iter().filter(|(level, _)| *level == 1).count();
+    if top_level_count == 1 && headings.len() > 1 && headings[0].0 == 1 {
+        headings.remove(0);
+    }
+    headings.into_iter().map(|(_, section)| section).collect()
+}
+
+pub(super) fn section_timings(
+    sections: &[OutlineSection],
+    segments: &[models::TranscriptSegment],
+    lexicon: &Lexicon,
+) -> Vec<SectionTiming> {
+    let keywords: Vec<Vec<String>> = sections
+        .iter()
+        .map(|section| section_keywords(section, lexicon))
+        .collect();
+    let bullet_keywords: Vec<Vec<Vec<String>>> = sections
+        .iter()
+        .map(|section| {
+            section
+                .bullets
+                .iter()
+                .map(|bullet| keywords_of([bullet.as_str()], lexicon))
+                .filter(|words| !words.is_empty())
+                .collect()
+        })
+        .collect();
+    let mut bullet_hits: Vec<Vec<bool>> = bullet_keywords
+        .iter()
+        .map(|bullets| vec![false; bullets.len()])
+        .collect();
+    let mut timings: Vec<SectionTiming> = sections
+        .iter()
+        .zip(bullet_keywords.iter())
+        .map(|(section, bullets)| SectionTiming {
+            title: section.title.clone(),
+            matched: false,
+            t_start_ms: None,
+            duration_ms: 0,
+            bullets_total: bullets.len() as i64,
+            bullets_covered: 0,
+        })
+        .collect();
+
+    let mut current: Option<usize> = None;
+    for segment in segments {
+        let tokens = lexicon.tokenize(&segment.text);
+        if tokens.is_empty() {
+            continue;
+        }
+        let best = keywords
+            .iter()
+            .enumerate()
+            .map(|(idx, words)| (idx, keyword_hits(words, &tokens)))
+            .filter(|(_, hits)| *hits > 0)
+            .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)));
+        if let Some((idx, _)) = best {
+            current = Some(idx);
+            timings[idx].matched = true;
+        }
+        for (section_idx, bullets) in bullet_keywords.iter().enumerate() {
+            for (bullet_idx, words) in bullets.iter().enumerate() {
+                if keyword_hits(words, &tokens) * 2 >= words.len() {
+                    bullet_hits[section_idx][bullet_idx] = true;
+                }
+            }
+        }
+        let Some(idx) = current else {
+            continue;
+        };
+        let timing = &mut timings[idx];
+        timing.t_start_ms.get_or_insert(segment.t_start_ms);
+        timing.duration_ms += (segment.t_end_ms - segment.t_start_ms).max(0);
+    }
+
+    for (timing, hits) in timings.iter_mut().zip(bullet_hits.iter()) {
+        timing.bullets_covered = hits.iter().filter(|hit| **hit).count() as i64;
+    }
+    timings
+}
+
+pub(super) fn coverage(timings: &[SectionTiming]) -> models::OutlineCoverage {
+    let mut sections = Vec::with_capacity(timings.len());
+    let mut skipped = Vec::new();
+    let mut reordered = Vec::new();
+    let mut latest_start: Option<i64> = None;
+
+    for timing in timings {
+        let covered = timing.matched || timing.bullets_covered > 0;
+        if !covered {
+            skipped.push(timing.title.clone());
+        } else if let Some(start) = timing.t_start_ms {
+            if latest_start.is_some_and(|latest| start < latest) {
+                reordered.push(timing.title.clone());
+            }
+            latest_start = Some(latest_start.map_or(start, |latest| latest.max(start)));
+        }
+        sections.push(models::OutlineSectionCoverage {
+            title: timing.title.clone(),
+            covered,
+            t_start_ms: if covered { timing.t_start_ms } else { None },
+            duration_ms: if covered { timing.duration_ms } else { 0 },
+            bullets_total: timing.bullets_total,
+            bullets_covered: timing.bullets_covered,
+        });
+    }
+
+    let covered_count = sections.iter().filter(|section| section.covered).count();
+    let coverage_ratio = if sections.is_empty() {
+        0.0
+    } else {
+        covered_count as f64 / sections.len() as f64
+    };
+    models::OutlineCoverage {
+        sections,
+        covered_count: covered_count as i64,
+        coverage_ratio,
+        skipped,
+        reordered,
+    }
+}
+
+pub(super) fn reordered_comment(
+    section: &models::OutlineSectionCoverage,
+) -> Option<models::FeedbackComment> {
+    let t_start_ms = section.t_start_ms?;
+    Some(models::FeedbackComment {
+        t_start_ms: t_start_ms.max(0),
+        t_end_ms: (t_start_ms + section.duration_ms).max(0),
+        severity: "low".to_string(),
+        label: "Section out of order".to_string(),
+        evidence: Some(serde_json::json!({
+            "kind": "outline_reordered",
+            "section": section.title,
+        })),
+        suggestion: format!(
+            "\"{}\" comes earlier than planned; move it in the outline or in the talk.",
+            section.title
+        ),
+    })
+}
+
+pub(super) fn skipped_action(coverage: &models::OutlineCoverage) -> Option<models::FeedbackAction> {
+    if coverage.skipped.is_empty() {
+        return None;
+    }
+    let named = coverage
+        .skipped
+        .iter()
+        .map(|title| format!("\"{title}\""))
+        .collect::<Vec<String>>()
+        .join(", ");
+    Some(models::FeedbackAction {
+        action_id: "cover_skipped_sections".to_string(),
+        title: "Cover the sections you skipped".to_string(),
+        why_it_matters: "Skipped sections leave gaps in the story you planned.".to_string(),
+        how_to_fix: format!("Rehearse {named}, or remove them from the outline."),
+        target_quest_codes: vec!["A01".to_string()],
+    })
+}
+
+fn section_keywords(section: &OutlineSection, lexicon: &Lexicon) -> Vec<String> {
+    keywords_of(
+        std::iter::once(section.title.as_str()).chain(section.bullets.iter().map(String::as_str)),
+        lexicon,
+    )
+}
+
+fn keywords_of<'a>(texts: impl IntoIterator<Item = &'a str>, lexicon: &Lexicon) -> Vec<String> {
+    let mut words = Vec::new();
+    for text in texts {
+        for token in lexicon.tokenize(text) {
+            if token.chars().count() >= 3 && !lexicon.is_stopword(&token) && !words.contains(&token)
+            {
+                words.push(token);
+            }
+        }
+    }
+    words
+}
+
+fn keyword_hits(keywords: &[String], tokens: &[String]) -> usize {
+    keywords
+        .iter()
+        .filter(|keyword| tokens.iter().any(|token| tokens_match(keyword, token)))
+        .count()
+}
+
+fn tokens_match(a: &str, b: &str) -> bool {
+    if a == b {
+        return true;
+    }
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let shortest = a.len().min(b.len());
+    if shortest < 4 {
+        return false;
+    }
+    let prefix = a.iter().zip(b.iter()).take_while(|(x, y)| x == y).count();
+    if prefix >= shortest.min(5) {
+        return true;
+    }
+    shortest >= 5 && edit_distance(&a, &b) <= 1
+}
+
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[b.len()]
+}
+
+fn heading_level(line: &str) -> Option<usize> {
+    let level = line.chars().take_while(|ch| *ch == '#').count();
+    if (1..=6).contains(&level) && line[level..].starts_with(' ') {
+        Some(level)
+    } else {
+        None
+    }
+}
+
+fn bullet_text(line: &str) -> Option<&str> {
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(rest) = line.strip_prefix(marker) {
+            return Some(rest.trim()).filter(|text| !text.is_empty());
+        }
+    }
+    let digits = line.chars().take_while(|ch| ch.is_ascii_digit()).count();
+    if digits > 0 {
+        let rest = &line[digits..];
+        if let Some(rest) = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")) {
+            return Some(rest.trim()).filter(|text| !text.is_empty());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::feedback::language::Language;
+
+    fn segment(t_start_ms: i64, t_end_ms: i64, text: &str) -> models::TranscriptSegment {
+        models::TranscriptSegment {
+            t_start_ms,
+            t_end_ms,
+            text: text.to_string(),
+            confidence: None,
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn parse_skips_single_title_heading() {
+        let sections = parse_outline(
+            "# My talk\n\n## Opening\n- Hook\n- Promise\n\n## Results\n1. Latency numbers\n",
+        );
+        let titles: Vec<&str> = sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["Opening", "Results"]);
+        assert_eq!(sections[0].bullets, vec!["Hook", "Promise"]);
+        assert_eq!(sections[1].bullets, vec!["Latency numbers"]);
+    }
+
+    #[test]
+    fn fuzzy_tokens_tolerate_plurals_and_typos() {
+        assert!(tokens_match("benchmark", "benchmarks"));
+        assert!(tokens_match("latency", "latencies"));
+        assert!(tokens_match("kubernetes", "kubernetis"));
+        assert!(tokens_match("caching", "cachng"));
+        assert!(!tokens_match("cat", "cats"));
+        assert!(!tokens_match("deploy", "display"));
+    }
+
+    #[test]
+    fn coverage_reports_skipped_and_reordered_sections() {
+        let sections = parse_outline(
+            "## Opening\n- hook story\n## Architecture\n- services diagram\n## Benchmarks\n- latency results\n## Closing\n- call to action\n",
+        );
+        let timings = section_timings(
+            &sections,
+            &[
+                segment(0, 10_000, "A short story to open."),
+                segment(10_000, 20_000, "First the latency benchmark results."),
+                segment(
+                    20_000,
+                    35_000,
+                    "Then the services and the diagram of our architecture.",
+                ),
+            ],
+            &Lexicon::defaults(Language::En),
+        );
+        let report = coverage(&timings);
+        assert_eq!(report.covered_count, 3);
+        assert_eq!(report.skipped, vec!["Closing"]);
+        assert_eq!(report.reordered, vec!["Benchmarks"]);
+        assert_eq!(report.sections[2].t_start_ms, Some(10_000));
+        assert_eq!(report.sections[2].bullets_covered, 1);
+        assert_eq!(report.sections[3].t_start_ms, None);
+        assert!((report.coverage_ratio - 0.75).abs() < 1e-9);
+
+        let comment = reordered_comment(&report.sections[2]).expect("comment");
+        assert_eq!((comment.t_start_ms, comment.t_end_ms), (10_000, 20_000));
+        let action = skipped_action(&report).expect("action");
+        assert!(action.how_to_fix.contains("\"Closing\""));
+    }
+
+    #[test]
+    fn parse_ignores_hashtags_without_space() {
+        let sections = parse_outline("#hashtag\n## Real section\n");
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].title, "Real section");
+    }
+
+    #[test]
+    fn timings_carry_forward_until_next_match() {
+        let sections =
+            parse_outline("## Opening\n- hook story\n## Benchmarks\n- latency results\n");
+        let timings = section_timings(
+            &sections,
+            &[
+                segment(0, 5_000, "Let me start with a story."),
+                segment(5_000, 20_000, "It was a rainy day."),
+                segment(20_000, 30_000, "Now the latency benchmarks."),
+                segment(30_000, 40_000, "They improved a lot."),
+            ],
+            &Lexicon::defaults(Language::En),
+        );
+        assert_eq!(timings[0].t_start_ms, Some(0));
+        assert_eq!(timings[0].duration_ms, 20_000);
+        assert_eq!(timings[1].t_start_ms, Some(20_000));
+        assert_eq!(timings[1].duration_ms, 20_000);
+    }
+}
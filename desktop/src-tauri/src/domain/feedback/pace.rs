@@ -0,0 +1,248 @@
+use super::language::{self, Language};
+use crate::kernel::models;
+
+const WINDOW_MS: i64 = 30_000;
+const STEP_MS: i64 = 15_000;
+const MIN_WINDOW_MS: i64 = 10_000;
+const RUSHED_MIN_WPM: f64 = 170.0;
+const RUSHED_RATIO: f64 = 1.2;
+const RUSHED_HIGH_WPM: f64 = 200.0;
+const DRAGGING_MAX_WPM: f64 = 100.0;
+const DRAGGING_RATIO: f64 = 0.8;
+const DRAGGING_HIGH_WPM: f64 = 70.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum PaceKind {
+    Rushed,
+    Dragging,
+}
+
+#[derive(Debug)]
+pub(super) struct PaceSection {
+    pub(super) kind: PaceKind,
+    pub(super) t_start_ms: i64,
+    pub(super) t_end_ms: i64,
+    pub(super) wpm: f64,
+}
+
+pub(super) fn pace_metrics(
+    segments: &[models::TranscriptSegment],
+    language: Language,
+) -> Option<(models::PaceMetrics, Vec<PaceSection>)> {
+    let timed: Vec<(i64, i64, usize)> = segments
+        .iter()
+        .map(|segment| {
+            (
+                segment.t_start_ms,
+                segment.t_end_ms.max(segment.t_start_ms),
+                language::word_count(&language::tokenize(&segment.text, language)),
+            )
+        })
+        .filter(|(_, _, words)| *words > 0)
+        .collect();
+    let start_ms = timed.iter().map(|(start, _, _)| *start).min()?;
+    let end_ms = timed.iter().map(|(_, end, _)| *end).max()?;
+    let span_ms = end_ms - start_ms;
+    if span_ms < MIN_WINDOW_MS {
+        return None;
+    }
+
+    let total_words: usize = timed.iter().map(|(_, _, words)| *words).sum();
+    let baseline_wpm = total_words as f64 / (span_ms as f64 / 60_000.0);
+
+    let mut points = Vec::new();
+    let mut window_start = start_ms;
+    loop {
+        let window_end = (window_start + WINDOW_MS).min(end_ms);
+        if window_end - window_start < MIN_WINDOW_MS && !points.is_empty() {
+            break;
+        }
+        let words = words_in_window(&timed, window_start, window_end);
+        points.push(models::PacePoint {
+            t_start_ms: window_start,
+            t_end_ms: window_end,
+            wpm: words / ((window_end - window_start) as f64 / 60_000.0),
+        });
+        if window_end >= end_ms {
+            break;
+        }
+        window_start += STEP_MS;
+    }
+
+    let sections = if points.len() >= 3 {
+        detect_sections(&points, baseline_wpm)
+    } else {
+        Vec::new()
+    };
+
+    Some((
+        models::PaceMetrics {
+            window_ms: WINDOW_MS,
+            step_ms: STEP_MS,
+            baseline_wpm,
+            points,
+        },
+        sections,
+    ))
+}
+
+pub(super) fn section_comment(section: &PaceSection) -> models::FeedbackComment {
+    let (label, kind, suggestion, high) = match section.kind {
+        PaceKind::Rushed => (
+            "Rushed section",
+            "rushed_section",
+            "Slow down here: breathe between ideas and let key points land.",
+            section.wpm >= RUSHED_HIGH_WPM,
+        ),
+        PaceKind::Dragging => (
+            "Dragging section",
+            "dragging_section",
+            "Tighten this part: cut hesitations and move to the next point.",
+            section.wpm <= DRAGGING_HIGH_WPM,
+        ),
+    };
+    models::FeedbackComment {
+        t_start_ms: section.t_start_ms.max(0),
+        t_end_ms: section.t_end_ms.max(0),
+        severity: if high { "high" } else { "medium" }.to_string(),
+        label: label.to_string(),
+        evidence: Some(serde_json::json!({
+            "kind": kind,
+            "wpm": section.wpm.round(),
+        })),
+        suggestion: suggestion.to_string(),
+    }
+}
+
+pub(super) fn section_action(kind: PaceKind) -> models::FeedbackAction {
+    match kind {
+        PaceKind::Rushed => models::FeedbackAction {
+            action_id: "steady_rushed_pace".to_string(),
+            title: "Steady your pace where you rush".to_string(),
+            why_it_matters: "Rushed passages are where listeners lose the thread.".to_string(),
+            how_to_fix: "Rehearse the flagged passage with a deliberate pause after each idea."
+                .to_string(),
+            target_quest_codes: vec!["D01".to_string()],
+        },
+        PaceKind::Dragging => models::FeedbackAction {
+            action_id: "tighten_dragging_pace".to_string(),
+            title: "Tighten sections that drag".to_string(),
+            why_it_matters: "Slow stretches drain energy from the talk.".to_string(),
+            how_to_fix: "Trim hesitations and rehearse the flagged passage from notes.".to_string(),
+            target_quest_codes: vec!["D01".to_string()],
+        },
+    }
+}
+
+fn words_in_window(timed: &[(i64, i64, usize)], window_start: i64, window_end: i64) -> f64 {
+    let mut words = 0.0;
+    for (start, end, count) in timed {
+        if *end == *start {
+            if *start >= window_start && *start < window_end {
+                words += *count as f64;
+            }
+            continue;
+        }
+        let overlap = (end.min(&window_end) - start.max(&window_start)).max(0);
+        words += *count as f64 * overlap as f64 / (end - start) as f64;
+    }
+    words
+}
+
+fn classify(wpm: f64, baseline_wpm: f64) -> Option<PaceKind> {
+    if wpm >= RUSHED_MIN_WPM && wpm >= baseline_wpm * RUSHED_RATIO {
+        Some(PaceKind::Rushed)
+    } else if wpm <= DRAGGING_MAX_WPM && wpm <= baseline_wpm * DRAGGING_RATIO {
+        Some(PaceKind::Dragging)
+    } else {
+        None
+    }
+}
+
+fn detect_sections(points: &[models::PacePoint], baseline_wpm: f64) -> Vec<PaceSection> {
+    let mut sections: Vec<PaceSection> = Vec::new();
+    let mut window_count = 0usize;
+    for point in points {
+        let Some(kind) = classify(point.wpm, baseline_wpm) else {
+            continue;
+        };
+        if let Some(last) = sections.last_mut() {
+            if last.kind == kind && point.t_start_ms <= last.t_end_ms {
+                last.t_end_ms = last.t_end_ms.max(point.t_end_ms);
+                window_count += 1;
+                last.wpm += (point.wpm - last.wpm) / window_count as f64;
+                continue;
+            }
+        }
+        window_count = 1;
+        sections.push(PaceSection {
+            kind,
+            t_start_ms: point.t_start_ms,
+            t_end_ms: point.t_end_ms,
+            wpm: point.wpm,
+        });
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paced_segments(wpm_per_10s: &[usize]) -> Vec<models::TranscriptSegment> {
+        wpm_per_10s
+            .iter()
+            .enumerate()
+            .map(|(idx, wpm)| models::TranscriptSegment {
+                t_start_ms: idx as i64 * 10_000,
+                t_end_ms: (idx as i64 + 1) * 10_000,
+                text: "word ".repeat(wpm / 6),
+                confidence: None,
+                words: Vec::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn short_transcripts_have_no_curve() {
+        let mut segments = paced_segments(&[120]);
+        segments[0].t_end_ms = 5_000;
+        assert!(pace_metrics(&segments, Language::En).is_none());
+    }
+
+    #[test]
+    fn windows_follow_segment_timing() {
+        let (metrics, sections) =
+            pace_metrics(&paced_segments(&[120; 9]), Language::En).expect("pace");
+        assert_eq!(metrics.points.len(), 5);
+        assert_eq!(metrics.points[0].t_end_ms, 30_000);
+        assert!(metrics
+            .points
+            .iter()
+            .all(|point| (point.wpm - 120.0).abs() < 1e-6));
+        assert!(sections.is_empty());
+    }
+
+    #[test]
+    fn rushed_ending_is_detected() {
+        let mut pattern = vec![120; 9];
+        pattern.extend([240; 3]);
+        let (_, sections) = pace_metrics(&paced_segments(&pattern), Language::En).expect("pace");
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].kind, PaceKind::Rushed);
+        assert_eq!(sections[0].t_end_ms, 120_000);
+        assert!(sections[0].t_start_ms >= 60_000);
+    }
+
+    #[test]
+    fn dragging_middle_is_detected() {
+        let mut pattern = vec![150; 6];
+        pattern.extend([48; 3]);
+        pattern.extend([150; 6]);
+        let (_, sections) = pace_metrics(&paced_segments(&pattern), Language::En).expect("pace");
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].kind, PaceKind::Dragging);
+        let comment = section_comment(&sections[0]);
+        assert_eq!(comment.label, "Dragging section");
+    }
+}
@@ -0,0 +1,127 @@
+use crate::domain::recorder::pauses::PauseSpan;
+use crate::kernel::models;
+
+const MIN_SEGMENT_GAP_MS: i64 = 250;
+const HISTOGRAM_EDGES_MS: [i64; 5] = [250, 500, 1_000, 2_000, 3_000];
+
+pub(super) fn segment_gap_pauses(segments: &[models::TranscriptSegment]) -> Vec<PauseSpan> {
+    let mut pauses = Vec::new();
+    let mut previous_end: Option<i64> = None;
+    for segment in segments {
+        if segment.text.trim().is_empty() {
+            continue;
+        }
+        if let Some(end) = previous_end {
+            if segment.t_start_ms - end >= MIN_SEGMENT_GAP_MS {
+                pauses.push(PauseSpan {
+                    t_start_ms: end,
+                    t_end_ms: segment.t_start_ms,
+                });
+            }
+        }
+        previous_end = Some(previous_end.map_or(segment.t_end_ms, |end| end.max(segment.t_end_ms)));
+    }
+    pauses
+}
+
+pub(super) fn summarize_pauses(pauses: &[PauseSpan], source: &str) -> models::PauseMetrics {
+    let durations: Vec<i64> = pauses.iter().map(PauseSpan::duration_ms).collect();
+    let total_ms: i64 = durations.iter().sum();
+    let mean_ms = if durations.is_empty() {
+        0.0
+    } else {
+        total_ms as f64 / durations.len() as f64
+    };
+
+    let mut histogram: Vec<models::PauseHistogramBin> = HISTOGRAM_EDGES_MS
+        .iter()
+        .enumerate()
+        .map(|(idx, min_ms)| models::PauseHistogramBin {
+            min_ms: *min_ms,
+            max_ms: HISTOGRAM_EDGES_MS.get(idx + 1).copied(),
+            count: 0,
+        })
+        .collect();
+    for duration in &durations {
+        if let Some(bin) = histogram
+            .iter_mut()
+            .rev()
+            .find(|bin| *duration >= bin.min_ms)
+        {
+            bin.count += 1;
+        }
+    }
+
+    models::PauseMetrics {
+        source: source.to_string(),
+        count: durations.len() as i64,
+        mean_ms,
+        max_ms: durations.iter().copied().max().unwrap_or(0),
+        total_ms,
+        histogram,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(t_start_ms: i64, t_end_ms: i64) -> models::TranscriptSegment {
+        models::TranscriptSegment {
+            t_start_ms,
+            t_end_ms,
+            text: "words".to_string(),
+            confidence: None,
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn segment_gaps_become_pauses() {
+        let pauses = segment_gap_pauses(&[
+            segment(0, 1_000),
+            segment(1_100, 2_000),
+            segment(3_500, 4_000),
+        ]);
+        assert_eq!(
+            pauses,
+            vec![PauseSpan {
+                t_start_ms: 2_000,
+                t_end_ms: 3_500
+            }]
+        );
+    }
+
+    #[test]
+    fn summary_buckets_durations() {
+        let pauses = [
+            PauseSpan {
+                t_start_ms: 0,
+                t_end_ms: 300,
+            },
+            PauseSpan {
+                t_start_ms: 1_000,
+                t_end_ms: 2_500,
+            },
+            PauseSpan {
+                t_start_ms: 5_000,
+                t_end_ms: 9_000,
+            },
+        ];
+        let summary = summarize_pauses(&pauses, "audio");
+        assert_eq!(summary.count, 3);
+        assert_eq!(summary.max_ms, 4_000);
+        assert_eq!(summary.total_ms, 5_800);
+        let counts: Vec<i64> = summary.histogram.iter().map(|bin| bin.count).collect();
+        assert_eq!(counts, vec![1, 0, 1, 0, 1]);
+        assert_eq!(summary.histogram.last().and_then(|bin| bin.max_ms), None);
+    }
+
+    #[test]
+    fn empty_summary_is_zeroed() {
+        let summary = summarize_pauses(&[], "segments");
+        assert_eq!(summary.count, 0);
+        assert_eq!(summary.mean_ms, 0.0);
+        assert!(summary.histogram.iter().all(|bin| bin.count == 0));
+    }
+}
@@ -0,0 +1,235 @@
+use crate::domain::recorder::pitch::{self as recorder_pitch, PitchTrack};
+use crate::kernel::models;
+use crate::platform::artifacts;
+
+pub const PITCH_ARTIFACT_TYPE: &str = "pitch";
+const SCHEMA_VERSION: &str = "1.0.0";
+const MONOTONE_RANGE_ST: f64 = 4.0;
+const MONOTONE_FLAT_RATIO: f64 = 0.5;
+
+#[derive(Debug)]
+pub struct PitchSignal {
+    pub artifact_id: Option<String>,
+    pub analysis: models::PitchAnalysisV1,
+}
+
+/// Loads the stored pitch analysis of a recording, or computes and stores
+/// it. A transcript with other segments gets a new analysis artifact.
+pub(super) fn pitch_signal(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: &str,
+    samples: &[f32],
+    segments: &[models::TranscriptSegment],
+    subject: (&str, &str),
+) -> PitchSignal {
+    let spans = segment_spans(segments);
+    let stored = find_pitch_artifact(app, profile_id, audio_artifact_id);
+    let (track, summary) = match stored.as_ref().map(|(_, analysis)| analysis) {
+        Some(analysis) => (
+            PitchTrack {
+                hop_ms: analysis.hop_ms,
+                f0_hz: analysis.f0_hz.clone(),
+            },
+            analysis.summary.clone(),
+        ),
+        None => {
+            let track = recorder_pitch::track_pitch(samples, 16_000);
+            let summary = recorder_pitch::summarize_pitch(&track);
+            (track, summary)
+        }
+    };
+    let analysis = models::PitchAnalysisV1 {
+        schema_version: SCHEMA_VERSION.to_string(),
+        source_audio_artifact_id: audio_artifact_id.to_string(),
+        segments: recorder_pitch::segment_pitch_stats(&track, &spans),
+        hop_ms: track.hop_ms,
+        f0_hz: track.f0_hz,
+        summary,
+    };
+
+    let stored_spans = stored.as_ref().map(|(_, stored)| {
+        stored
+            .segments
+            .iter()
+            .map(|segment| (segment.t_start_ms, segment.t_end_ms))
+            .collect::<Vec<_>>()
+    });
+    let artifact_id = match stored {
+        Some((id, _)) if stored_spans.as_ref() == Some(&spans) => Some(id),
+        _ => match store_pitch(app, profile_id, &analysis, subject) {
+            Ok(id) => Some(id),
+            Err(err) => {
+                eprintln!("pitch analysis not stored for {audio_artifact_id}: {err}");
+                None
+            }
+        },
+    };
+    PitchSignal {
+        artifact_id,
+        analysis,
+    }
+}
+
+fn segment_spans(segments: &[models::TranscriptSegment]) -> Vec<(i64, i64)> {
+    segments
+        .iter()
+        .filter(|segment| !segment.text.trim().is_empty())
+        .map(|segment| (segment.t_start_ms, segment.t_end_ms))
+        .collect()
+}
+
+fn find_pitch_artifact(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: &str,
+) -> Option<(String, models::PitchAnalysisV1)> {
+    artifacts::find_audio_derived::<models::PitchAnalysisV1>(
+        app,
+        profile_id,
+        PITCH_ARTIFACT_TYPE,
+        audio_artifact_id,
+    )
+    .ok()
+    .flatten()
+    .filter(|(_, analysis)| analysis.schema_version == SCHEMA_VERSION)
+}
+
+fn store_pitch(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    analysis: &models::PitchAnalysisV1,
+    (subject_type, subject_id): (&str, &str),
+) -> Result<String, String> {
+    let bytes = serde_json::to_vec(analysis).map_err(|e| format!("pitch_json: {e}"))?;
+    let metadata = serde_json::json!({
+        "source_audio_artifact_id": analysis.source_audio_artifact_id,
+        "subject_type": subject_type,
+        "subject_id": subject_id,
+        "hop_ms": analysis.hop_ms,
+    });
+    // Earlier feedback revisions keep pointing at the analysis they used.
+    let record = artifacts::store_bytes(
+        app,
+        profile_id,
+        PITCH_ARTIFACT_TYPE,
+        "json",
+        &bytes,
+        &metadata,
+    )?;
+    Ok(record.id)
+}
+
+pub(super) fn flat_stretches(analysis: &models::PitchAnalysisV1) -> Vec<(i64, i64)> {
+    recorder_pitch::flat_stretches(&analysis.segments)
+}
+
+pub(super) fn pitch_metrics(signal: &PitchSignal) -> models::PitchMetrics {
+    let analysis = &signal.analysis;
+    let summary = &analysis.summary;
+    models::PitchMetrics {
+        pitch_artifact_id: signal.artifact_id.clone(),
+        median_hz: summary.median_hz,
+        range_st: summary.range_st,
+        variability_st: summary.variability_st,
+        voiced_ratio: summary.voiced_ratio,
+        flat_stretch_count: flat_stretches(analysis).len() as i64,
+        flat_ratio: flat_ratio(analysis),
+    }
+}
+
+/// Share of judged segments whose pitch barely moves.
+fn flat_ratio(analysis: &models::PitchAnalysisV1) -> f64 {
+    let judged: Vec<bool> = analysis
+        .segments
+        .iter()
+        .filter(|segment| segment.range_st.is_some())
+        .map(|segment| segment.flat)
+        .collect();
+    if judged.is_empty() {
+        return 0.0;
+    }
+    let flat = judged.iter().filter(|flat| **flat).count() as f64;
+    (flat / judged.len() as f64 * 100.0).round() / 100.0
+}
+
+pub(super) fn is_monotone(analysis: &models::PitchAnalysisV1) -> bool {
+    let summary = &analysis.summary;
+    summary.voiced_ratio > 0.0
+        && (summary.range_st < MONOTONE_RANGE_ST || flat_ratio(analysis) >= MONOTONE_FLAT_RATIO)
+}
+
+pub(super) fn flat_stretch_comment((t_start_ms, t_end_ms): (i64, i64)) -> models::FeedbackComment {
+    models::FeedbackComment {
+        t_start_ms: t_start_ms.max(0),
+        t_end_ms: t_end_ms.max(0),
+        severity: "medium".to_string(),
+        label: "Flat intonation".to_string(),
+        evidence: Some(serde_json::json!({
+            "kind": "flat_pitch",
+            "duration_ms": (t_end_ms - t_start_ms).max(0),
+        })),
+        suggestion: "Let your pitch rise and fall to mark what matters in this passage."
+            .to_string(),
+    }
+}
+
+pub(super) fn monotone_action() -> models::FeedbackAction {
+    models::FeedbackAction {
+        action_id: "vary_pitch".to_string(),
+        title: "Use more melody in your voice".to_string(),
+        why_it_matters: "A narrow pitch range is what listeners hear as monotone.".to_string(),
+        how_to_fix:
+            "Read a paragraph aloud, exaggerate the key words, then keep half of that movement."
+                .to_string(),
+        target_quest_codes: vec!["D01".to_string()],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(t_start_ms: i64, range_st: Option<f64>) -> models::PitchSegmentStats {
+        models::PitchSegmentStats {
+            t_start_ms,
+            t_end_ms: t_start_ms + 2_000,
+            median_hz: range_st.map(|_| 150.0),
+            range_st,
+            variability_st: range_st.map(|range| range / 3.0),
+            flat: range_st.is_some_and(|range| range < 3.0),
+        }
+    }
+
+    #[test]
+    fn monotone_needs_a_narrow_range_or_mostly_flat_segments() {
+        let mut analysis = models::PitchAnalysisV1 {
+            schema_version: SCHEMA_VERSION.to_string(),
+            source_audio_artifact_id: "audio_1".to_string(),
+            hop_ms: 20,
+            f0_hz: Vec::new(),
+            summary: models::PitchSummary {
+                voiced_ratio: 0.6,
+                median_hz: 150.0,
+                range_st: 7.0,
+                variability_st: 2.4,
+            },
+            segments: vec![
+                stats(0, Some(1.5)),
+                stats(2_000, None),
+                stats(4_000, Some(2.0)),
+                stats(6_000, Some(8.0)),
+            ],
+        };
+        assert_eq!(flat_ratio(&analysis), 0.67);
+        assert_eq!(flat_stretches(&analysis), vec![(0, 6_000)]);
+        assert!(is_monotone(&analysis));
+
+        analysis.segments[0] = stats(0, Some(6.0));
+        assert!(!is_monotone(&analysis));
+        analysis.summary.range_st = 3.0;
+        assert!(is_monotone(&analysis));
+        analysis.summary.voiced_ratio = 0.0;
+        assert!(!is_monotone(&analysis));
+    }
+}
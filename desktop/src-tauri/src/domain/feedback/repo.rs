@@ -1,9 +1,13 @@
 mod mutations;
 mod queries;
 
-pub use mutations::{delete_feedback_note, persist_attempt_feedback_link, upsert_feedback_note};
+pub use mutations::{
+    delete_feedback_note, delete_lexicon_term, insert_lexicon_term, persist_attempt_feedback_link,
+    replace_lexicon_terms, upsert_feedback_note,
+};
 pub use queries::{
-    ensure_project_exists, feedback_exists, select_attempt_input, select_feedback_artifact_id,
-    select_feedback_note, select_feedback_subject, select_feedback_timeline,
-    select_quest_attempt_context, select_run_project_id,
+    ensure_project_exists, feedback_exists, select_attempt_input, select_compare_subject,
+    select_feedback_artifact_id, select_feedback_note, select_feedback_revisions,
+    select_feedback_subject, select_feedback_timeline, select_lexicon_terms,
+    select_quest_attempt_context, select_run_project_id, CompareSubjectRow,
 };
@@ -59,3 +59,59 @@ pub fn persist_attempt_feedback_link(
     tx.commit().map_err(|e| format!("commit: {e}"))?;
     Ok(())
 }
+
+pub fn insert_lexicon_term(
+    conn: &Connection,
+    language: &str,
+    kind: &str,
+    term: &str,
+    now: &str,
+) -> Result<(), String> {
+    conn.execute(
+        "INSERT OR IGNORE INTO feedback_lexicon_terms (language, kind, term, created_at)
+         VALUES (?1, ?2, ?3, ?4)",
+        params![language, kind, term, now],
+    )
+    .map_err(|e| format!("lexicon_insert: {e}"))?;
+    Ok(())
+}
+
+pub fn delete_lexicon_term(
+    conn: &Connection,
+    language: &str,
+    kind: &str,
+    term: &str,
+) -> Result<usize, String> {
+    conn.execute(
+        "DELETE FROM feedback_lexicon_terms WHERE language = ?1 AND kind = ?2 AND term = ?3",
+        params![language, kind, term],
+    )
+    .map_err(|e| format!("lexicon_delete: {e}"))
+}
+
+pub fn replace_lexicon_terms(
+    conn: &mut Connection,
+    language: &str,
+    kinds: &[&str],
+    terms: &[(&str, &str)],
+    now: &str,
+) -> Result<(), String> {
+    let tx = conn.transaction().map_err(|e| format!("tx: {e}"))?;
+    for kind in kinds {
+        tx.execute(
+            "DELETE FROM feedback_lexicon_terms WHERE language = ?1 AND kind = ?2",
+            params![language, kind],
+        )
+        .map_err(|e| format!("lexicon_reset_delete: {e}"))?;
+    }
+    for (kind, term) in terms {
+        tx.execute(
+            "INSERT OR IGNORE INTO feedback_lexicon_terms (language, kind, term, created_at)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![language, kind, term, now],
+        )
+        .map_err(|e| format!("lexicon_reset_insert: {e}"))?;
+    }
+    tx.commit().map_err(|e| format!("commit: {e}"))?;
+    Ok(())
+}
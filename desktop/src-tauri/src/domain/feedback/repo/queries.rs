@@ -1,4 +1,4 @@
-use super::super::types::FeedbackTimelineItem;
+use super::super::types::{FeedbackLexiconTerm, FeedbackRevision, FeedbackTimelineItem};
 use rusqlite::{params, Connection, OptionalExtension};
 
 pub fn ensure_project_exists(conn: &Connection, project_id: &str) -> Result<(), String> {
@@ -33,6 +33,7 @@ pub fn select_feedback_timeline(
                ON af.subject_type = 'run' AND r.id = af.subject_id
              LEFT JOIN feedback_notes fn ON fn.feedback_id = af.id
              WHERE af.subject_type IN ('quest_attempt', 'run')
+               AND af.id = COALESCE(qa.feedback_id, r.feedback_id)
                AND COALESCE(qa.project_id, r.project_id) IS NOT NULL
                AND (?1 IS NULL OR COALESCE(qa.project_id, r.project_id) = ?1)
              ORDER BY af.created_at DESC
@@ -62,22 +63,27 @@ pub fn select_feedback_timeline(
     Ok(items)
 }
 
-pub fn select_attempt_input(
-    conn: &Connection,
-    attempt_id: &str,
-) -> Result<(Option<String>, Option<String>, i64), String> {
+pub struct AttemptInput {
+    pub output_text: Option<String>,
+    pub transcript_id: Option<String>,
+    pub audio_artifact_id: Option<String>,
+    pub estimated_sec: i64,
+}
+
+pub fn select_attempt_input(conn: &Connection, attempt_id: &str) -> Result<AttemptInput, String> {
     conn.query_row(
-        "SELECT qa.output_text, qa.transcript_id, q.estimated_sec
+        "SELECT qa.output_text, qa.transcript_id, qa.audio_artifact_id, q.estimated_sec
          FROM quest_attempts qa
          JOIN quests q ON qa.quest_code = q.code
          WHERE qa.id = ?1",
         [attempt_id],
         |row| {
-            Ok((
-                row.get::<_, Option<String>>(0)?,
-                row.get::<_, Option<String>>(1)?,
-                row.get::<_, i64>(2)?,
-            ))
+            Ok(AttemptInput {
+                output_text: row.get(0)?,
+                transcript_id: row.get(1)?,
+                audio_artifact_id: row.get(2)?,
+                estimated_sec: row.get(3)?,
+            })
         },
     )
     .map_err(|e| format!("attempt_lookup: {e}"))
@@ -151,3 +157,116 @@ pub fn feedback_exists(conn: &Connection, feedback_id: &str) -> Result<bool, Str
         .map_err(|e| format!("feedback_check: {e}"))?;
     Ok(exists > 0)
 }
+
+pub fn select_lexicon_terms(
+    conn: &Connection,
+    language: Option<&str>,
+    kind: Option<&str>,
+) -> Result<Vec<FeedbackLexiconTerm>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT language, kind, term, created_at
+             FROM feedback_lexicon_terms
+             WHERE (?1 IS NULL OR language = ?1)
+               AND (?2 IS NULL OR kind = ?2)
+             ORDER BY language, kind, term",
+        )
+        .map_err(|e| format!("lexicon_prepare: {e}"))?;
+    let rows = stmt
+        .query_map(params![language, kind], |row| {
+            Ok(FeedbackLexiconTerm {
+                language: row.get(0)?,
+                kind: row.get(1)?,
+                term: row.get(2)?,
+                created_at: row.get(3)?,
+            })
+        })
+        .map_err(|e| format!("lexicon_query: {e}"))?;
+
+    let mut terms = Vec::new();
+    for row in rows {
+        terms.push(row.map_err(|e| format!("lexicon_row: {e}"))?);
+    }
+    Ok(terms)
+}
+
+pub fn select_feedback_revisions(
+    conn: &Connection,
+    subject_type: &str,
+    subject_id: &str,
+) -> Result<Vec<FeedbackRevision>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT af.id, af.created_at, af.overall_score,
+                    af.id = COALESCE(qa.feedback_id, r.feedback_id) AS is_current
+             FROM auto_feedback af
+             LEFT JOIN quest_attempts qa
+               ON af.subject_type = 'quest_attempt' AND qa.id = af.subject_id
+             LEFT JOIN runs r
+               ON af.subject_type = 'run' AND r.id = af.subject_id
+             WHERE af.subject_type = ?1 AND af.subject_id = ?2
+             ORDER BY af.created_at ASC, af.rowid ASC",
+        )
+        .map_err(|e| format!("feedback_revisions_prepare: {e}"))?;
+    let rows = stmt
+        .query_map(params![subject_type, subject_id], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get::<_, Option<bool>>(3)?.unwrap_or(false),
+            ))
+        })
+        .map_err(|e| format!("feedback_revisions_query: {e}"))?;
+
+    let mut revisions = Vec::new();
+    for (idx, row) in rows.enumerate() {
+        let (feedback_id, created_at, overall_score, is_current) =
+            row.map_err(|e| format!("feedback_revisions_row: {e}"))?;
+        revisions.push(FeedbackRevision {
+            feedback_id,
+            revision: idx as i64 + 1,
+            created_at,
+            overall_score,
+            is_current,
+        });
+    }
+    Ok(revisions)
+}
+
+pub struct CompareSubjectRow {
+    pub id: String,
+    pub project_id: String,
+    pub created_at: String,
+    pub transcript_id: Option<String>,
+    pub feedback_id: Option<String>,
+}
+
+pub fn select_compare_subject(
+    conn: &Connection,
+    subject_type: &str,
+    subject_id: &str,
+) -> Result<CompareSubjectRow, String> {
+    let sql = match subject_type {
+        "run" => {
+            "SELECT id, project_id, created_at, transcript_id, feedback_id FROM runs WHERE id = ?1"
+        }
+        "quest_attempt" => {
+            "SELECT id, project_id, created_at, transcript_id, feedback_id
+             FROM quest_attempts WHERE id = ?1"
+        }
+        _ => return Err("feedback_subject_not_supported".to_string()),
+    };
+    conn.query_row(sql, [subject_id], |row| {
+        Ok(CompareSubjectRow {
+            id: row.get(0)?,
+            project_id: row.get(1)?,
+            created_at: row.get(2)?,
+            transcript_id: row.get(3)?,
+            feedback_id: row.get(4)?,
+        })
+    })
+    .optional()
+    .map_err(|e| format!("compare_subject_lookup: {e}"))?
+    .ok_or_else(|| "compare_subject_not_found".to_string())
+}
@@ -0,0 +1,59 @@
+use super::context::feedback_get;
+use super::metrics;
+use super::repo;
+use super::types::{FeedbackRevision, FeedbackRevisionDiff};
+use crate::kernel::models;
+use crate::platform::db;
+
+pub fn feedback_revisions_list(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    subject_type: &str,
+    subject_id: &str,
+) -> Result<Vec<FeedbackRevision>, String> {
+    if !matches!(subject_type, "run" | "quest_attempt") {
+        return Err("feedback_subject_not_supported".to_string());
+    }
+    db::ensure_profile_exists(app, profile_id)?;
+    let conn = db::open_profile(app, profile_id)?;
+    repo::select_feedback_revisions(&conn, subject_type, subject_id)
+}
+
+pub fn feedback_revisions_diff(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    base_feedback_id: &str,
+    target_feedback_id: &str,
+) -> Result<FeedbackRevisionDiff, String> {
+    db::ensure_profile_exists(app, profile_id)?;
+    let conn = db::open_profile(app, profile_id)?;
+    let base_subject = repo::select_feedback_subject(&conn, base_feedback_id)?;
+    let target_subject = repo::select_feedback_subject(&conn, target_feedback_id)?;
+    if base_subject != target_subject {
+        return Err("feedback_subject_mismatch".to_string());
+    }
+
+    let base = feedback_get(app, profile_id, base_feedback_id)?;
+    let target = feedback_get(app, profile_id, target_feedback_id)?;
+    let action_ids = |feedback: &models::FeedbackV1| -> Vec<String> {
+        feedback
+            .top_actions
+            .iter()
+            .map(|action| action.action_id.clone())
+            .collect()
+    };
+    Ok(FeedbackRevisionDiff {
+        base_feedback_id: base_feedback_id.to_string(),
+        target_feedback_id: target_feedback_id.to_string(),
+        metrics: metrics::metric_deltas(&base, &target),
+        repeat_terms: metrics::terms_delta(
+            &base.metrics.repeat_terms,
+            &target.metrics.repeat_terms,
+        ),
+        jargon_terms: metrics::terms_delta(
+            &base.metrics.jargon_terms,
+            &target.metrics.jargon_terms,
+        ),
+        top_actions: metrics::terms_delta(&action_ids(&base), &action_ids(&target)),
+    })
+}
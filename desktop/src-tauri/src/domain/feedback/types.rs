@@ -28,3 +28,84 @@ pub struct FeedbackTimelineItem {
     pub run_id: Option<String>,
     pub note_updated_at: Option<String>,
 }
+
+#[derive(Debug, Serialize)]
+pub struct FeedbackLexiconTerm {
+    pub language: String,
+    pub kind: String,
+    pub term: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeedbackAnalyzerStatus {
+    pub id: String,
+    pub enabled: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeedbackRevision {
+    pub feedback_id: String,
+    pub revision: i64,
+    pub created_at: String,
+    pub overall_score: i64,
+    pub is_current: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MetricDelta {
+    pub metric: String,
+    pub base: Option<f64>,
+    pub target: Option<f64>,
+    pub delta: Option<f64>,
+    pub verdict: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TermsDelta {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeedbackRevisionDiff {
+    pub base_feedback_id: String,
+    pub target_feedback_id: String,
+    pub metrics: Vec<MetricDelta>,
+    pub repeat_terms: TermsDelta,
+    pub jargon_terms: TermsDelta,
+    pub top_actions: TermsDelta,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComparedSubject {
+    pub subject_id: String,
+    pub created_at: String,
+    pub feedback_id: String,
+    pub overall_score: i64,
+    pub duration_sec: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MetricComparison {
+    pub metric: String,
+    pub values: Vec<Option<f64>>,
+    pub deltas: Vec<Option<f64>>,
+    pub delta: Option<f64>,
+    pub verdict: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OutlineSectionComparison {
+    pub title: String,
+    pub covered: Vec<Option<bool>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FeedbackComparison {
+    pub subject_type: String,
+    pub project_id: String,
+    pub subjects: Vec<ComparedSubject>,
+    pub metrics: Vec<MetricComparison>,
+    pub outline_sections: Vec<OutlineSectionComparison>,
+}
@@ -0,0 +1,494 @@
+use super::recording::repair_wav_header;
+use super::storage::{self, RAW_AUDIO_ARTIFACT_TYPE};
+use crate::kernel::models::RecordingMarker;
+use crate::kernel::time;
+use crate::platform::{artifacts, db};
+use serde::{Deserialize, Serialize};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+const CHECKPOINT_SCHEMA_VERSION: &str = "1.0.0";
+const CHECKPOINT_DIR: &str = "drafts/recordings";
+const DRAFT_ARTIFACT_TYPE: &str = "audio";
+const DRAFT_EXTENSION: &str = "wav";
+const DRAFT_ID_PREFIX: &str = "art_";
+const WAV_HEADER_BYTES: u64 = 44;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordingCheckpoint {
+    pub schema_version: String,
+    pub recording_id: String,
+    pub artifact_id: String,
+    pub sample_rate_hz: u32,
+    pub channels: u16,
+    pub started_at: String,
+    pub updated_at: String,
+    pub data_bytes: u64,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub markers: Vec<RecordingMarker>,
+    /// Draft of the unprocessed companion, when the take keeps one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw_artifact_id: Option<String>,
+}
+
+impl RecordingCheckpoint {
+    pub fn new(recording_id: &str, artifact_id: &str, sample_rate_hz: u32, channels: u16) -> Self {
+        let now = time::now_rfc3339();
+        Self {
+            schema_version: CHECKPOINT_SCHEMA_VERSION.to_string(),
+            recording_id: recording_id.to_string(),
+            artifact_id: artifact_id.to_string(),
+            sample_rate_hz,
+            channels,
+            started_at: now.clone(),
+            updated_at: now,
+            data_bytes: 0,
+            markers: Vec::new(),
+            raw_artifact_id: None,
+        }
+    }
+
+    pub fn advance(&mut self, data_bytes: u64, markers: &[RecordingMarker]) {
+        self.data_bytes = data_bytes;
+        self.markers = markers.to_vec();
+        self.updated_at = time::now_rfc3339();
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordingDraftInfo {
+    pub artifact_id: String,
+    pub path: PathBuf,
+    pub bytes: u64,
+    pub duration_ms: i64,
+    pub recording_id: Option<String>,
+    pub started_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+pub struct RecoveredRecording {
+    pub artifact_id: String,
+    pub path: PathBuf,
+    pub bytes: u64,
+    pub sha256: String,
+    pub duration_ms: i64,
+    pub raw_artifact_id: Option<String>,
+}
+
+pub fn checkpoint_path(profile_dir: &Path, artifact_id: &str) -> PathBuf {
+    profile_dir
+        .join(CHECKPOINT_DIR)
+        .join(format!("{artifact_id}.json"))
+}
+
+pub fn write_checkpoint(
+    profile_dir: &Path,
+    checkpoint: &RecordingCheckpoint,
+) -> Result<(), String> {
+    let path = checkpoint_path(profile_dir, &checkpoint.artifact_id);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("checkpoint_dir: {e}"))?;
+    }
+    let payload =
+        serde_json::to_vec(checkpoint).map_err(|e| format!("checkpoint_serialize: {e}"))?;
+    let tmp_path = path.with_extension("json.tmp");
+    std::fs::write(&tmp_path, payload).map_err(|e| format!("checkpoint_write: {e}"))?;
+    std::fs::rename(&tmp_path, &path).map_err(|e| format!("checkpoint_rename: {e}"))
+}
+
+pub fn read_checkpoint(profile_dir: &Path, artifact_id: &str) -> Option<RecordingCheckpoint> {
+    let bytes = std::fs::read(checkpoint_path(profile_dir, artifact_id)).ok()?;
+    serde_json::from_slice(&bytes).ok()
+}
+
+pub fn remove_checkpoint(profile_dir: &Path, artifact_id: &str) -> Result<(), String> {
+    match std::fs::remove_file(checkpoint_path(profile_dir, artifact_id)) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(format!("checkpoint_remove: {err}")),
+    }
+}
+
+/// Recording drafts are named like every artifact; anything else in the
+/// audio directory belongs to another writer.
+fn is_draft_id(artifact_id: &str) -> bool {
+    artifact_id
+        .strip_prefix(DRAFT_ID_PREFIX)
+        .is_some_and(|rest| {
+            rest.len() == 32
+                && rest
+                    .chars()
+                    .all(|ch| ch.is_ascii_digit() || ('a'..='f').contains(&ch))
+        })
+}
+
+/// The checkpoint of a recording draft nobody finalized: the take is not
+/// being recorded or finalized and never got an artifact row. Files without
+/// a checkpoint (trim and edit temporaries) are never drafts.
+fn orphan_checkpoint(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    profile_dir: &Path,
+    artifact_id: &str,
+    busy_artifact_ids: &[String],
+) -> Result<Option<RecordingCheckpoint>, String> {
+    if !is_draft_id(artifact_id) || busy_artifact_ids.iter().any(|id| id == artifact_id) {
+        return Ok(None);
+    }
+    let Some(checkpoint) = read_checkpoint(profile_dir, artifact_id) else {
+        return Ok(None);
+    };
+    if checkpoint.artifact_id != artifact_id
+        || artifacts::artifact_exists(app, profile_id, artifact_id)?
+    {
+        return Ok(None);
+    }
+    Ok(Some(checkpoint))
+}
+
+pub fn list_orphan_drafts(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    busy_artifact_ids: &[String],
+) -> Result<Vec<RecordingDraftInfo>, String> {
+    db::ensure_profile_exists(app, profile_id)?;
+    let profile_dir = db::profile_dir(app, profile_id)?;
+    prune_stale_checkpoints(app, profile_id, &profile_dir)?;
+
+    let audio_dir = profile_dir.join("artifacts").join(DRAFT_ARTIFACT_TYPE);
+    let entries = match std::fs::read_dir(&audio_dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(format!("recording_drafts_read: {err}")),
+    };
+
+    let mut drafts = Vec::new();
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some(DRAFT_EXTENSION) {
+            continue;
+        }
+        let Some(artifact_id) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let Some(checkpoint) = orphan_checkpoint(
+            app,
+            profile_id,
+            &profile_dir,
+            artifact_id,
+            busy_artifact_ids,
+        )?
+        else {
+            continue;
+        };
+        let bytes = entry.metadata().map(|meta| meta.len()).unwrap_or(0);
+        let (sample_rate_hz, channels) =
+            wav_format(&path).unwrap_or((checkpoint.sample_rate_hz, checkpoint.channels));
+        drafts.push(RecordingDraftInfo {
+            artifact_id: artifact_id.to_string(),
+            duration_ms: draft_duration_ms(
+                bytes.saturating_sub(WAV_HEADER_BYTES),
+                sample_rate_hz,
+                channels,
+            ),
+            path,
+            bytes,
+            recording_id: Some(checkpoint.recording_id),
+            started_at: Some(checkpoint.started_at),
+            updated_at: Some(checkpoint.updated_at),
+        });
+    }
+    drafts.sort_by(|a, b| {
+        b.started_at
+            .cmp(&a.started_at)
+            .then_with(|| a.artifact_id.cmp(&b.artifact_id))
+    });
+    Ok(drafts)
+}
+
+pub fn recover_orphan_draft(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    artifact_id: &str,
+    busy_artifact_ids: &[String],
+) -> Result<RecoveredRecording, String> {
+    let (draft, checkpoint) = open_orphan_draft(app, profile_id, artifact_id, busy_artifact_ids)?;
+    let profile_dir = db::profile_dir(app, profile_id)?;
+
+    let data_bytes = repair_wav_header(&draft.abspath)?;
+    if data_bytes == 0 {
+        return Err("recording_draft_empty".to_string());
+    }
+    let (sample_rate_hz, channels) = wav_format(&draft.abspath)?;
+    let duration_ms = draft_duration_ms(data_bytes, sample_rate_hz, channels);
+    let format = storage::load_storage_format(app, profile_id)?;
+    let raw_artifact_id = recover_raw_companion(app, profile_id, &checkpoint, format)?;
+    let draft = storage::compress_draft(app, profile_id, draft, format)?;
+
+    let metadata = serde_json::json!({
+        "format": format.code(),
+        "sample_rate_hz": sample_rate_hz,
+        "channels": channels,
+        "recovered": true,
+        "recovered_at": time::now_rfc3339(),
+        "recording_id": checkpoint.recording_id,
+        "recording_started_at": checkpoint.started_at,
+        "checkpoint_updated_at": checkpoint.updated_at,
+        "checkpoint_data_bytes": checkpoint.data_bytes,
+        "markers": super::markers::trim_markers(&checkpoint.markers, 0, duration_ms),
+        "raw_artifact_id": raw_artifact_id,
+    });
+    let record = artifacts::finalize_draft(app, profile_id, draft, &metadata)?;
+    remove_checkpoint(&profile_dir, artifact_id)?;
+
+    Ok(RecoveredRecording {
+        artifact_id: record.id,
+        path: record.abspath,
+        bytes: record.bytes,
+        sha256: record.sha256,
+        duration_ms,
+        raw_artifact_id,
+    })
+}
+
+/// Finalizes the unprocessed companion of a recovered take. A companion that
+/// was already registered is reused; a missing or empty one is dropped.
+fn recover_raw_companion(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    checkpoint: &RecordingCheckpoint,
+    format: storage::AudioStorageFormat,
+) -> Result<Option<String>, String> {
+    let Some(raw_id) = checkpoint.raw_artifact_id.as_deref() else {
+        return Ok(None);
+    };
+    if artifacts::artifact_exists(app, profile_id, raw_id)? {
+        return Ok(Some(raw_id.to_string()));
+    }
+    let Ok(raw_draft) = artifacts::reopen_draft(
+        app,
+        profile_id,
+        raw_id,
+        RAW_AUDIO_ARTIFACT_TYPE,
+        DRAFT_EXTENSION,
+    ) else {
+        return Ok(None);
+    };
+    if repair_wav_header(&raw_draft.abspath)? == 0 {
+        std::fs::remove_file(&raw_draft.abspath)
+            .map_err(|e| format!("recording_draft_remove: {e}"))?;
+        return Ok(None);
+    }
+    let metadata = storage::audio_metadata(
+        format,
+        &serde_json::json!({
+            "raw": true,
+            "processed_artifact_id": checkpoint.artifact_id,
+            "recovered": true,
+        }),
+    );
+    let raw_draft = storage::compress_draft(app, profile_id, raw_draft, format)?;
+    let record = artifacts::finalize_draft(app, profile_id, raw_draft, &metadata)?;
+    Ok(Some(record.id))
+}
+
+fn remove_raw_companion(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    profile_dir: &Path,
+    checkpoint: &RecordingCheckpoint,
+) -> Result<(), String> {
+    let Some(raw_id) = checkpoint.raw_artifact_id.as_deref() else {
+        return Ok(());
+    };
+    if !is_draft_id(raw_id) || artifacts::artifact_exists(app, profile_id, raw_id)? {
+        return Ok(());
+    }
+    let path = profile_dir
+        .join("artifacts")
+        .join(RAW_AUDIO_ARTIFACT_TYPE)
+        .join(format!("{raw_id}.{DRAFT_EXTENSION}"));
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(err) => Err(format!("recording_draft_remove: {err}")),
+    }
+}
+
+pub fn discard_orphan_draft(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    artifact_id: &str,
+    busy_artifact_ids: &[String],
+) -> Result<(), String> {
+    let (draft, checkpoint) = open_orphan_draft(app, profile_id, artifact_id, busy_artifact_ids)?;
+    std::fs::remove_file(&draft.abspath).map_err(|e| format!("recording_draft_remove: {e}"))?;
+    let profile_dir = db::profile_dir(app, profile_id)?;
+    remove_raw_companion(app, profile_id, &profile_dir, &checkpoint)?;
+    remove_checkpoint(&profile_dir, artifact_id)
+}
+
+fn open_orphan_draft(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    artifact_id: &str,
+    busy_artifact_ids: &[String],
+) -> Result<(artifacts::ArtifactDraft, RecordingCheckpoint), String> {
+    db::ensure_profile_exists(app, profile_id)?;
+    if busy_artifact_ids.iter().any(|id| id == artifact_id) {
+        return Err("recording_draft_active".to_string());
+    }
+    let profile_dir = db::profile_dir(app, profile_id)?;
+    let checkpoint = orphan_checkpoint(
+        app,
+        profile_id,
+        &profile_dir,
+        artifact_id,
+        busy_artifact_ids,
+    )?
+    .ok_or_else(|| "recording_draft_not_orphaned".to_string())?;
+    let draft = artifacts::reopen_draft(
+        app,
+        profile_id,
+        artifact_id,
+        DRAFT_ARTIFACT_TYPE,
+        DRAFT_EXTENSION,
+    )?;
+    Ok((draft, checkpoint))
+}
+
+fn prune_stale_checkpoints(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    profile_dir: &Path,
+) -> Result<(), String> {
+    let Ok(entries) = std::fs::read_dir(profile_dir.join(CHECKPOINT_DIR)) else {
+        return Ok(());
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+        let Some(artifact_id) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+        let draft_path = profile_dir
+            .join("artifacts")
+            .join(DRAFT_ARTIFACT_TYPE)
+            .join(format!("{artifact_id}.{DRAFT_EXTENSION}"));
+        if artifacts::artifact_exists(app, profile_id, artifact_id)? {
+            remove_checkpoint(profile_dir, artifact_id)?;
+        } else if !draft_path.exists() {
+            if let Some(checkpoint) = read_checkpoint(profile_dir, artifact_id) {
+                remove_raw_companion(app, profile_id, profile_dir, &checkpoint)?;
+            }
+            remove_checkpoint(profile_dir, artifact_id)?;
+        }
+    }
+    Ok(())
+}
+
+fn wav_format(path: &Path) -> Result<(u32, u16), String> {
+    let mut header = [0u8; WAV_HEADER_BYTES as usize];
+    std::fs::File::open(path)
+        .and_then(|mut file| file.read_exact(&mut header))
+        .map_err(|_| "wav_header".to_string())?;
+    if &header[0..4] != b"RIFF" || &header[8..12] != b"WAVE" {
+        return Err("wav_header".to_string());
+    }
+    let channels = u16::from_le_bytes([header[22], header[23]]);
+    let sample_rate = u32::from_le_bytes([header[24], header[25], header[26], header[27]]);
+    if channels == 0 || sample_rate == 0 {
+        return Err("wav_format".to_string());
+    }
+    Ok((sample_rate, channels))
+}
+
+fn draft_duration_ms(data_bytes: u64, sample_rate_hz: u32, channels: u16) -> i64 {
+    let frame_bytes = 2 * channels.max(1) as u64;
+    let frames = data_bytes / frame_bytes;
+    super::duration_ms_from_sample_count(frames as usize, sample_rate_hz.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::recorder::recording::WavWriter;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    fn temp_dir(prefix: &str) -> PathBuf {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("lepupitre-{prefix}-{nonce}"));
+        std::fs::create_dir_all(&dir).expect("mkdir");
+        dir
+    }
+
+    #[test]
+    fn checkpoint_round_trips_and_is_removed() {
+        let dir = temp_dir("recording-checkpoint");
+        let mut checkpoint = RecordingCheckpoint::new("rec_1", "art_1", 16_000, 1);
+        let markers = vec![RecordingMarker {
+            t_ms: 800,
+            label: "new slide".to_string(),
+        }];
+        checkpoint.advance(32_000, &markers);
+        write_checkpoint(&dir, &checkpoint).expect("write");
+
+        let loaded = read_checkpoint(&dir, "art_1").expect("checkpoint");
+        assert_eq!(loaded.recording_id, "rec_1");
+        assert_eq!(loaded.data_bytes, 32_000);
+        assert_eq!(loaded.markers, markers);
+        assert_eq!(loaded.raw_artifact_id, None);
+
+        checkpoint.raw_artifact_id = Some("art_2".to_string());
+        write_checkpoint(&dir, &checkpoint).expect("write raw");
+        let loaded = read_checkpoint(&dir, "art_1").expect("checkpoint");
+        assert_eq!(loaded.raw_artifact_id.as_deref(), Some("art_2"));
+
+        remove_checkpoint(&dir, "art_1").expect("remove");
+        assert!(read_checkpoint(&dir, "art_1").is_none());
+        remove_checkpoint(&dir, "art_1").expect("remove missing");
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn only_artifact_ids_are_draft_candidates() {
+        assert!(is_draft_id(&crate::kernel::ids::new_id("art")));
+        assert!(!is_draft_id("art_1"));
+        assert!(!is_draft_id("rec_0123456789abcdef0123456789abcdef"));
+        assert!(!is_draft_id("art_0123456789abcdef0123456789abcdef.trim"));
+        assert!(!is_draft_id("art_0123456789ABCDEF0123456789ABCDEF"));
+    }
+
+    #[test]
+    fn unfinalized_draft_header_is_repaired() {
+        let dir = temp_dir("recording-repair");
+        let path = dir.join("draft.wav");
+        let mut writer = WavWriter::create(&path, 16_000, 1).expect("create");
+        writer.write_samples(&vec![0.25; 16_000]).expect("write");
+        writer.checkpoint().expect("checkpoint");
+        writer.write_samples(&vec![-0.25; 8_000]).expect("write");
+        drop(writer);
+
+        let mut bytes = std::fs::read(&path).expect("read");
+        assert_eq!(
+            u32::from_le_bytes([bytes[40], bytes[41], bytes[42], bytes[43]]),
+            32_000
+        );
+        bytes.push(0x7f);
+        std::fs::write(&path, &bytes).expect("torn write");
+
+        let data_bytes = repair_wav_header(&path).expect("repair");
+        assert_eq!(data_bytes, 48_000);
+        let repaired = std::fs::read(&path).expect("read");
+        let samples =
+            crate::domain::recorder::decode_wav_pcm16_mono_16k(&repaired).expect("decode");
+        assert_eq!(samples.len(), 24_000);
+        assert_eq!(draft_duration_ms(data_bytes, 16_000, 1), 1_500);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -1,3 +1,14 @@
+use super::recording::ResamplerKind;
+use serde::Deserialize;
+use serde_json::Value;
+
+const HIGH_PASS_HZ: f32 = 80.0;
+const GATE_THRESHOLD: f32 = 0.01;
+const GATE_FLOOR_GAIN: f32 = 0.1;
+const GATE_HOLD_MS: u32 = 150;
+const LIMITER_CEILING: f32 = 0.95;
+const LIMITER_RELEASE_MS: f32 = 50.0;
+
 pub fn rms(samples: &[f32]) -> f32 {
     if samples.is_empty() {
         return 0.0;
@@ -44,6 +55,287 @@ impl Agc {
         }
         self.gain
     }
+
+    /// Applies the current gain without adapting it, so gated silence does not
+    /// drive the gain up to `max_gain`.
+    pub fn hold(&self, samples: &mut [f32]) {
+        for sample in samples {
+            *sample = (*sample * self.gain).clamp(-1.0, 1.0);
+        }
+    }
+}
+
+/// One-pole high-pass filter; at low cutoffs it doubles as a DC blocker.
+pub struct HighPass {
+    alpha: f32,
+    prev_input: f32,
+    prev_output: f32,
+}
+
+impl HighPass {
+    pub fn new(cutoff_hz: f32, sample_rate: u32) -> Self {
+        let rc = 1.0 / (2.0 * std::f32::consts::PI * cutoff_hz.max(1.0));
+        let dt = 1.0 / sample_rate.max(1) as f32;
+        Self {
+            alpha: rc / (rc + dt),
+            prev_input: 0.0,
+            prev_output: 0.0,
+        }
+    }
+
+    pub fn process(&mut self, samples: &mut [f32]) {
+        for sample in samples {
+            let output = self.alpha * (self.prev_output + *sample - self.prev_input);
+            self.prev_input = *sample;
+            self.prev_output = output;
+            *sample = output;
+        }
+    }
+}
+
+pub struct NoiseGate {
+    threshold: f32,
+    floor_gain: f32,
+    hold_samples: u32,
+    attack_coef: f32,
+    release_coef: f32,
+    envelope_coef: f32,
+    envelope: f32,
+    held: u32,
+    gain: f32,
+}
+
+impl NoiseGate {
+    pub fn new(threshold: f32, floor_gain: f32, hold_ms: u32, sample_rate: u32) -> Self {
+        let sample_rate = sample_rate.max(1);
+        Self {
+            threshold,
+            floor_gain,
+            hold_samples: hold_ms * sample_rate / 1000,
+            attack_coef: smoothing_coef(2.0, sample_rate),
+            release_coef: smoothing_coef(80.0, sample_rate),
+            envelope_coef: smoothing_coef(20.0, sample_rate),
+            envelope: 0.0,
+            held: 0,
+            gain: floor_gain,
+        }
+    }
+
+    pub fn process(&mut self, samples: &mut [f32]) {
+        for sample in samples {
+            let level = sample.abs();
+            self.envelope = if level > self.envelope {
+                level
+            } else {
+                self.envelope + (level - self.envelope) * self.envelope_coef
+            };
+            if self.envelope >= self.threshold {
+                self.held = self.hold_samples;
+            } else {
+                self.held = self.held.saturating_sub(1);
+            }
+            let (target, coef) = if self.held > 0 {
+                (1.0, self.attack_coef)
+            } else {
+                (self.floor_gain, self.release_coef)
+            };
+            self.gain += (target - self.gain) * coef;
+            *sample *= self.gain;
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.held == 0
+    }
+}
+
+/// Instant-attack peak limiter with exponential release.
+pub struct Limiter {
+    ceiling: f32,
+    release_coef: f32,
+    gain: f32,
+}
+
+impl Limiter {
+    pub fn new(ceiling: f32, release_ms: f32, sample_rate: u32) -> Self {
+        Self {
+            ceiling,
+            release_coef: smoothing_coef(release_ms, sample_rate.max(1)),
+            gain: 1.0,
+        }
+    }
+
+    pub fn process(&mut self, samples: &mut [f32]) {
+        for sample in samples {
+            let level = sample.abs();
+            if level * self.gain > self.ceiling {
+                self.gain = self.ceiling / level;
+            } else {
+                self.gain += (1.0 - self.gain) * self.release_coef;
+            }
+            *sample = (*sample * self.gain).clamp(-self.ceiling, self.ceiling);
+        }
+    }
+}
+
+fn smoothing_coef(time_ms: f32, sample_rate: u32) -> f32 {
+    let samples = time_ms.max(0.01) * sample_rate as f32 / 1000.0;
+    1.0 - (-1.0 / samples).exp()
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingDspSettingsPayload {
+    pub preset: Option<String>,
+    pub high_pass: Option<bool>,
+    pub noise_gate: Option<bool>,
+    pub agc: Option<bool>,
+    pub limiter: Option<bool>,
+    pub resampler: Option<String>,
+    pub keep_raw: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DspChainConfig {
+    pub high_pass: bool,
+    pub noise_gate: bool,
+    pub agc: bool,
+    pub limiter: bool,
+}
+
+impl DspChainConfig {
+    pub fn voice() -> Self {
+        Self {
+            high_pass: true,
+            noise_gate: false,
+            agc: false,
+            limiter: true,
+        }
+    }
+
+    pub fn bypass() -> Self {
+        Self {
+            high_pass: false,
+            noise_gate: false,
+            agc: false,
+            limiter: false,
+        }
+    }
+
+    pub fn broadcast() -> Self {
+        Self {
+            high_pass: true,
+            noise_gate: true,
+            agc: true,
+            limiter: true,
+        }
+    }
+
+    pub fn is_bypass(&self) -> bool {
+        *self == Self::bypass()
+    }
+
+    pub fn to_metadata(self) -> Value {
+        serde_json::json!({
+            "high_pass_hz": self.high_pass.then_some(HIGH_PASS_HZ),
+            "noise_gate": self.noise_gate,
+            "agc": self.agc,
+            "limiter": self.limiter,
+        })
+    }
+}
+
+/// Takes are stored unprocessed unless a preset or stage is asked for, so
+/// analysis (clipping, loudness) sees the signal the microphone delivered.
+pub fn normalize_dsp_settings(
+    payload: Option<RecordingDspSettingsPayload>,
+) -> Result<DspChainConfig, String> {
+    let Some(payload) = payload else {
+        return Ok(DspChainConfig::bypass());
+    };
+    let mut config = match payload.preset.as_deref() {
+        None | Some("raw") => DspChainConfig::bypass(),
+        Some("voice") => DspChainConfig::voice(),
+        Some("broadcast") => DspChainConfig::broadcast(),
+        Some(_) => return Err("recording_dsp_preset_invalid".to_string()),
+    };
+    if let Some(high_pass) = payload.high_pass {
+        config.high_pass = high_pass;
+    }
+    if let Some(noise_gate) = payload.noise_gate {
+        config.noise_gate = noise_gate;
+    }
+    if let Some(agc) = payload.agc {
+        config.agc = agc;
+    }
+    if let Some(limiter) = payload.limiter {
+        config.limiter = limiter;
+    }
+    Ok(config)
+}
+
+pub fn normalize_resampler_kind(payload: Option<&RecordingDspSettingsPayload>) -> ResamplerKind {
+    payload
+        .and_then(|payload| payload.resampler.as_deref())
+        .and_then(ResamplerKind::from_code)
+        .unwrap_or(ResamplerKind::Sinc)
+}
+
+/// Whether an unprocessed copy is kept next to the take. Off unless asked
+/// for, and pointless when the chain is bypassed.
+pub fn normalize_keep_raw(
+    payload: Option<&RecordingDspSettingsPayload>,
+    config: DspChainConfig,
+) -> bool {
+    !config.is_bypass()
+        && payload
+            .and_then(|payload| payload.keep_raw)
+            .unwrap_or(false)
+}
+
+/// High-pass, noise gate, AGC and limiter, applied in that order.
+pub struct DspChain {
+    config: DspChainConfig,
+    high_pass: HighPass,
+    gate: NoiseGate,
+    agc: Agc,
+    limiter: Limiter,
+}
+
+impl DspChain {
+    pub fn new(config: DspChainConfig, sample_rate: u32) -> Self {
+        Self {
+            config,
+            high_pass: HighPass::new(HIGH_PASS_HZ, sample_rate),
+            gate: NoiseGate::new(GATE_THRESHOLD, GATE_FLOOR_GAIN, GATE_HOLD_MS, sample_rate),
+            agc: Agc::new(0.1, 0.5, 8.0, 0.2),
+            limiter: Limiter::new(LIMITER_CEILING, LIMITER_RELEASE_MS, sample_rate),
+        }
+    }
+
+    pub fn config(&self) -> DspChainConfig {
+        self.config
+    }
+
+    pub fn process(&mut self, samples: &mut [f32]) {
+        if self.config.high_pass {
+            self.high_pass.process(samples);
+        }
+        if self.config.noise_gate {
+            self.gate.process(samples);
+        }
+        if self.config.agc {
+            if self.config.noise_gate && self.gate.is_closed() {
+                self.agc.hold(samples);
+            } else {
+                self.agc.process(samples);
+            }
+        }
+        if self.config.limiter {
+            self.limiter.process(samples);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -63,4 +355,115 @@ mod tests {
         assert!(gain > 1.0);
         assert!(samples.iter().all(|v| v.abs() <= 1.0));
     }
+
+    fn sine(freq: f32, amplitude: f32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| amplitude * (2.0 * std::f32::consts::PI * freq * i as f32 / 16_000.0).sin())
+            .collect()
+    }
+
+    #[test]
+    fn high_pass_removes_dc_and_keeps_speech_band() {
+        let mut filter = HighPass::new(HIGH_PASS_HZ, 16_000);
+        let mut dc = vec![0.5f32; 16_000];
+        filter.process(&mut dc);
+        assert!(dc[15_000..].iter().all(|v| v.abs() < 1e-3));
+
+        let mut filter = HighPass::new(HIGH_PASS_HZ, 16_000);
+        let mut voice = sine(440.0, 0.5, 16_000);
+        filter.process(&mut voice);
+        assert!(rms(&voice[8_000..]) > 0.34);
+    }
+
+    #[test]
+    fn noise_gate_attenuates_room_noise_but_passes_speech() {
+        let mut gate = NoiseGate::new(GATE_THRESHOLD, GATE_FLOOR_GAIN, GATE_HOLD_MS, 16_000);
+        let mut noise = sine(300.0, 0.005, 16_000);
+        gate.process(&mut noise);
+        assert!(rms(&noise[8_000..]) < 0.001);
+        assert!(gate.is_closed());
+
+        let mut speech = sine(300.0, 0.2, 16_000);
+        gate.process(&mut speech);
+        assert!(rms(&speech[8_000..]) > 0.13);
+    }
+
+    #[test]
+    fn limiter_keeps_peaks_under_ceiling() {
+        let mut limiter = Limiter::new(LIMITER_CEILING, LIMITER_RELEASE_MS, 16_000);
+        let mut loud = sine(200.0, 1.5, 4_000);
+        limiter.process(&mut loud);
+        assert!(loud.iter().all(|v| v.abs() <= LIMITER_CEILING));
+    }
+
+    #[test]
+    fn gated_silence_does_not_pump_agc() {
+        let config = DspChainConfig::broadcast();
+        let mut chain = DspChain::new(config, 16_000);
+        for _ in 0..50 {
+            let mut noise = sine(300.0, 0.004, 320);
+            chain.process(&mut noise);
+        }
+        assert!((chain.agc.gain - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn dsp_settings_apply_preset_then_overrides() {
+        assert_eq!(normalize_dsp_settings(None), Ok(DspChainConfig::bypass()));
+        let config = normalize_dsp_settings(Some(RecordingDspSettingsPayload {
+            preset: Some("raw".to_string()),
+            limiter: Some(true),
+            ..Default::default()
+        }))
+        .expect("config");
+        assert!(config.limiter && !config.high_pass && !config.agc);
+        assert_eq!(
+            normalize_dsp_settings(Some(RecordingDspSettingsPayload {
+                preset: Some("voice".to_string()),
+                ..Default::default()
+            })),
+            Ok(DspChainConfig::voice())
+        );
+        assert!(
+            normalize_dsp_settings(Some(RecordingDspSettingsPayload::default()))
+                .expect("config")
+                .is_bypass()
+        );
+        assert_eq!(normalize_resampler_kind(None), ResamplerKind::Sinc);
+        let payload = RecordingDspSettingsPayload {
+            resampler: Some("linear".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_resampler_kind(Some(&payload)),
+            ResamplerKind::Linear
+        );
+    }
+
+    #[test]
+    fn unknown_presets_are_rejected() {
+        let payload = RecordingDspSettingsPayload {
+            preset: Some("studio".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            normalize_dsp_settings(Some(payload)),
+            Err("recording_dsp_preset_invalid".to_string())
+        );
+    }
+
+    #[test]
+    fn raw_copy_is_opt_in() {
+        let voice = DspChainConfig::voice();
+        assert!(!normalize_keep_raw(None, voice));
+        let payload = RecordingDspSettingsPayload {
+            keep_raw: Some(true),
+            ..Default::default()
+        };
+        assert!(normalize_keep_raw(Some(&payload), voice));
+        assert!(!normalize_keep_raw(
+            Some(&payload),
+            DspChainConfig::bypass()
+        ));
+    }
 }
@@ -0,0 +1,522 @@
+use super::{markers, storage};
+use crate::domain::asr::transcript;
+use crate::kernel::models;
+use crate::platform::{artifacts, db};
+use serde::Deserialize;
+use std::path::PathBuf;
+
+const SAMPLE_RATE_HZ: u32 = 16_000;
+
+#[derive(Debug, Deserialize, Clone, Copy)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct EditRange {
+    pub start_ms: i64,
+    pub end_ms: i64,
+}
+
+/// One take in an edit list: an optional kept window of the source audio,
+/// minus any number of cut ranges. Takes are concatenated in order.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioEditSourcePayload {
+    pub audio_artifact_id: String,
+    pub start_ms: Option<i64>,
+    pub end_ms: Option<i64>,
+    #[serde(default)]
+    pub cuts: Vec<EditRange>,
+    pub transcript_id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditSegment {
+    pub source_index: usize,
+    pub start_idx: usize,
+    pub end_idx: usize,
+    pub source_start_ms: i64,
+    pub source_end_ms: i64,
+    pub output_start_ms: i64,
+    pub output_end_ms: i64,
+}
+
+pub struct EditedAudio {
+    pub artifact_id: String,
+    pub path: PathBuf,
+    pub bytes: u64,
+    pub sha256: String,
+    pub duration_ms: i64,
+    pub transcript_id: Option<String>,
+}
+
+pub fn apply_edit_list(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    sources: &[AudioEditSourcePayload],
+) -> Result<EditedAudio, String> {
+    db::ensure_profile_exists(app, profile_id)?;
+    if sources.is_empty() {
+        return Err("edit_sources_empty".to_string());
+    }
+
+    let profile_dir = db::profile_dir(app, profile_id)?;
+    let mut source_samples = Vec::with_capacity(sources.len());
+    let mut source_markers = Vec::with_capacity(sources.len());
+    for source in sources {
+        let artifact = artifacts::get_artifact(app, profile_id, &source.audio_artifact_id)?;
+        if artifact.artifact_type != "audio" {
+            return Err("artifact_not_audio".to_string());
+        }
+        let bytes = std::fs::read(profile_dir.join(&artifact.relpath))
+            .map_err(|e| format!("audio_read: {e}"))?;
+        source_samples.push(super::decode_audio_pcm16_mono_16k(&bytes)?);
+        source_markers.push(markers::audio_markers(
+            app,
+            profile_id,
+            &source.audio_artifact_id,
+        ));
+    }
+
+    let lengths: Vec<usize> = source_samples.iter().map(Vec::len).collect();
+    let segments = plan_edit_segments(&lengths, sources, SAMPLE_RATE_HZ)?;
+    let mut samples = Vec::new();
+    for segment in &segments {
+        samples.extend_from_slice(
+            &source_samples[segment.source_index][segment.start_idx..segment.end_idx],
+        );
+    }
+    let duration_ms = super::duration_ms_from_sample_count(samples.len(), SAMPLE_RATE_HZ);
+    let edited_markers = remap_markers(&source_markers, &segments);
+
+    let format = storage::load_storage_format(app, profile_id)?;
+    let encoded = format.encode(&samples)?;
+    let provenance: Vec<serde_json::Value> = segments
+        .iter()
+        .map(|segment| {
+            serde_json::json!({
+                "source_audio_artifact_id": sources[segment.source_index].audio_artifact_id,
+                "source_start_ms": segment.source_start_ms,
+                "source_end_ms": segment.source_end_ms,
+                "output_start_ms": segment.output_start_ms,
+                "output_end_ms": segment.output_end_ms,
+            })
+        })
+        .collect();
+    let metadata = storage::audio_metadata(
+        format,
+        &serde_json::json!({
+            "edit_kind": "edit_list",
+            "source_audio_artifact_ids": sources
+                .iter()
+                .map(|source| source.audio_artifact_id.as_str())
+                .collect::<Vec<_>>(),
+            "edit_segments": provenance,
+            "markers": edited_markers,
+        }),
+    );
+    let record =
+        artifacts::store_bytes(app, profile_id, "audio", format.code(), &encoded, &metadata)?;
+
+    let transcript_id = match remap_source_transcripts(
+        app,
+        profile_id,
+        sources,
+        &segments,
+        &record.id,
+        duration_ms,
+        &edited_markers,
+    ) {
+        Ok(transcript_id) => transcript_id,
+        Err(err) => {
+            if let Err(cleanup) = artifacts::delete_artifact(app, profile_id, &record.id) {
+                return Err(format!("{err}; {cleanup}"));
+            }
+            return Err(err);
+        }
+    };
+
+    Ok(EditedAudio {
+        artifact_id: record.id,
+        path: record.abspath,
+        bytes: record.bytes,
+        sha256: record.sha256,
+        duration_ms,
+        transcript_id,
+    })
+}
+
+/// Resolves the kept sample ranges of every take, in output order.
+pub fn plan_edit_segments(
+    source_lengths: &[usize],
+    sources: &[AudioEditSourcePayload],
+    sample_rate: u32,
+) -> Result<Vec<EditSegment>, String> {
+    let mut segments = Vec::new();
+    let mut output_samples = 0usize;
+    for (source_index, (source, &sample_count)) in sources.iter().zip(source_lengths).enumerate() {
+        let total_ms = super::duration_ms_from_sample_count(sample_count, sample_rate);
+        let keep_start = source.start_ms.unwrap_or(0).clamp(0, total_ms);
+        let keep_end = source.end_ms.unwrap_or(total_ms).clamp(0, total_ms);
+        if keep_end <= keep_start {
+            return Err("edit_range_invalid".to_string());
+        }
+
+        let mut cuts: Vec<(i64, i64)> = Vec::with_capacity(source.cuts.len());
+        for cut in &source.cuts {
+            if cut.end_ms <= cut.start_ms {
+                return Err("edit_cut_invalid".to_string());
+            }
+            cuts.push((cut.start_ms.max(keep_start), cut.end_ms.min(keep_end)));
+        }
+        cuts.retain(|(start, end)| end > start);
+        cuts.sort_unstable();
+
+        let mut pieces = Vec::new();
+        let mut cursor = keep_start;
+        for (start, end) in cuts {
+            if start > cursor {
+                pieces.push((cursor, start));
+            }
+            cursor = cursor.max(end);
+        }
+        if keep_end > cursor {
+            pieces.push((cursor, keep_end));
+        }
+
+        for (start_ms, end_ms) in pieces {
+            let start_idx =
+                super::ms_to_sample_index_floor(start_ms, sample_rate).min(sample_count);
+            let end_idx = super::ms_to_sample_index_ceil(end_ms, sample_rate).min(sample_count);
+            if end_idx <= start_idx {
+                continue;
+            }
+            let output_start_ms = super::duration_ms_from_sample_count(output_samples, sample_rate);
+            output_samples += end_idx - start_idx;
+            segments.push(EditSegment {
+                source_index,
+                start_idx,
+                end_idx,
+                source_start_ms: start_ms,
+                source_end_ms: end_ms,
+                output_start_ms,
+                output_end_ms: super::duration_ms_from_sample_count(output_samples, sample_rate),
+            });
+        }
+    }
+    if segments.is_empty() {
+        return Err("edit_empty".to_string());
+    }
+    Ok(segments)
+}
+
+/// Moves transcript segments of one take onto the edited timeline. Segments
+/// that fall entirely inside a cut are dropped; a segment spanning a cut keeps
+/// its text once, from its first to its last surviving instant.
+pub fn remap_transcript_segments(
+    source_segments: &[models::TranscriptSegment],
+    edit_segments: &[&EditSegment],
+) -> Vec<models::TranscriptSegment> {
+    let mut remapped = Vec::new();
+    for segment in source_segments {
+        let mut mapped: Option<(i64, i64)> = None;
+        for edit in edit_segments {
+            let start = segment.t_start_ms.max(edit.source_start_ms);
+            let end = segment.t_end_ms.min(edit.source_end_ms);
+            if end <= start {
+                continue;
+            }
+            let offset = edit.output_start_ms - edit.source_start_ms;
+            let (t_start, t_end) = (start + offset, end + offset);
+            mapped = Some(match mapped {
+                Some((first, _)) => (first, t_end),
+                None => (t_start, t_end),
+            });
+        }
+        if let Some((t_start_ms, t_end_ms)) = mapped {
+            remapped.push(models::TranscriptSegment {
+                t_start_ms,
+                t_end_ms,
+                text: segment.text.clone(),
+                confidence: segment.confidence,
+                words: remap_words(&segment.words, edit_segments),
+            });
+        }
+    }
+    remapped
+}
+
+/// Words keep the part that survives the first kept range they overlap;
+/// words entirely inside a cut are dropped.
+fn remap_words(
+    words: &[models::TranscriptWord],
+    edit_segments: &[&EditSegment],
+) -> Vec<models::TranscriptWord> {
+    words
+        .iter()
+        .filter_map(|word| {
+            edit_segments.iter().find_map(|edit| {
+                let start = word.t_start_ms.max(edit.source_start_ms);
+                let end = word.t_end_ms.min(edit.source_end_ms);
+                let offset = edit.output_start_ms - edit.source_start_ms;
+                (end > start).then(|| models::TranscriptWord {
+                    t_start_ms: start + offset,
+                    t_end_ms: end + offset,
+                    text: word.text.clone(),
+                    confidence: word.confidence,
+                })
+            })
+        })
+        .collect()
+}
+
+/// Carries the markers of every take onto the edited timeline; markers
+/// inside a cut are dropped.
+pub fn remap_markers(
+    source_markers: &[Vec<models::RecordingMarker>],
+    segments: &[EditSegment],
+) -> Vec<models::RecordingMarker> {
+    let mut remapped = Vec::new();
+    for segment in segments {
+        let Some(markers) = source_markers.get(segment.source_index) else {
+            continue;
+        };
+        remapped.extend(
+            markers
+                .iter()
+                .filter(|marker| {
+                    marker.t_ms >= segment.source_start_ms && marker.t_ms < segment.source_end_ms
+                })
+                .map(|marker| models::RecordingMarker {
+                    t_ms: marker.t_ms - segment.source_start_ms + segment.output_start_ms,
+                    label: marker.label.clone(),
+                }),
+        );
+    }
+    remapped
+}
+
+fn remap_source_transcripts(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    sources: &[AudioEditSourcePayload],
+    segments: &[EditSegment],
+    audio_artifact_id: &str,
+    duration_ms: i64,
+    edited_markers: &[models::RecordingMarker],
+) -> Result<Option<String>, String> {
+    let mut transcript_ids = Vec::with_capacity(sources.len());
+    for source in sources {
+        let transcript_id = match &source.transcript_id {
+            Some(transcript_id) => Some(transcript_id.clone()),
+            None => latest_transcript_for_audio(app, profile_id, &source.audio_artifact_id)?,
+        };
+        match transcript_id {
+            Some(transcript_id) => transcript_ids.push(transcript_id),
+            // A partial transcript would misplace every later take.
+            None => return Ok(None),
+        }
+    }
+
+    let mut language = None;
+    let mut model_ids = Vec::new();
+    let mut remapped = Vec::new();
+    for (source_index, transcript_id) in transcript_ids.iter().enumerate() {
+        let source = transcript::load_transcript(app, profile_id, transcript_id)?;
+        let edits: Vec<&EditSegment> = segments
+            .iter()
+            .filter(|segment| segment.source_index == source_index)
+            .collect();
+        remapped.extend(remap_transcript_segments(&source.segments, &edits));
+        language.get_or_insert(source.language);
+        model_ids.push(source.model_id);
+    }
+    model_ids.dedup();
+
+    let edited = models::TranscriptV1 {
+        schema_version: "1.0.0".to_string(),
+        language: language.unwrap_or_else(|| "auto".to_string()),
+        model_id: if model_ids.len() == 1 {
+            model_ids.pop().flatten()
+        } else {
+            None
+        },
+        duration_ms: Some(duration_ms),
+        segments: remapped,
+        markers: edited_markers.to_vec(),
+    };
+    let bytes = serde_json::to_vec(&edited).map_err(|e| format!("transcript_json: {e}"))?;
+    let metadata = serde_json::json!({
+        "source_audio_artifact_id": audio_artifact_id,
+        "edit_kind": "audio_edit",
+        "source_transcript_ids": transcript_ids,
+    });
+    let record = artifacts::store_bytes(app, profile_id, "transcript", "json", &bytes, &metadata)?;
+    Ok(Some(record.id))
+}
+
+/// Newest transcript of an audio artifact, following manual edit revisions.
+fn latest_transcript_for_audio(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: &str,
+) -> Result<Option<String>, String> {
+    let mut latest: Option<String> = None;
+    for entry in artifacts::list_artifacts(app, profile_id, "transcript")? {
+        let metadata_str = |key: &str| entry.metadata.get(key).and_then(|value| value.as_str());
+        let transcribed = metadata_str("source_audio_artifact_id") == Some(audio_artifact_id);
+        let revised = latest.is_some() && metadata_str("source_transcript_id") == latest.as_deref();
+        if transcribed || revised {
+            latest = Some(entry.id);
+        }
+    }
+    Ok(latest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(id: &str, cuts: &[(i64, i64)]) -> AudioEditSourcePayload {
+        AudioEditSourcePayload {
+            audio_artifact_id: id.to_string(),
+            start_ms: None,
+            end_ms: None,
+            cuts: cuts
+                .iter()
+                .map(|&(start_ms, end_ms)| EditRange { start_ms, end_ms })
+                .collect(),
+            transcript_id: None,
+        }
+    }
+
+    fn text_segment(t_start_ms: i64, t_end_ms: i64, text: &str) -> models::TranscriptSegment {
+        models::TranscriptSegment {
+            t_start_ms,
+            t_end_ms,
+            text: text.to_string(),
+            confidence: None,
+            words: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn cuts_are_merged_and_takes_concatenated() {
+        let sources = vec![
+            source("a", &[(1_000, 2_000), (1_500, 2_500), (4_000, 9_000)]),
+            AudioEditSourcePayload {
+                start_ms: Some(500),
+                end_ms: Some(1_500),
+                ..source("b", &[])
+            },
+        ];
+        let segments = plan_edit_segments(&[80_000, 32_000], &sources, 16_000).expect("plan");
+        let ranges: Vec<_> = segments
+            .iter()
+            .map(|s| {
+                (
+                    s.source_index,
+                    s.source_start_ms,
+                    s.source_end_ms,
+                    s.output_start_ms,
+                    s.output_end_ms,
+                )
+            })
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (0, 0, 1_000, 0, 1_000),
+                (0, 2_500, 4_000, 1_000, 2_500),
+                (1, 500, 1_500, 2_500, 3_500),
+            ]
+        );
+        assert_eq!(segments[1].start_idx, 40_000);
+        assert_eq!(segments[2].end_idx, 24_000);
+    }
+
+    #[test]
+    fn invalid_edit_lists_are_rejected() {
+        assert_eq!(
+            plan_edit_segments(&[16_000], &[source("a", &[(500, 100)])], 16_000),
+            Err("edit_cut_invalid".to_string())
+        );
+        assert_eq!(
+            plan_edit_segments(&[16_000], &[source("a", &[(0, 5_000)])], 16_000),
+            Err("edit_empty".to_string())
+        );
+        let reversed = AudioEditSourcePayload {
+            start_ms: Some(800),
+            end_ms: Some(200),
+            ..source("a", &[])
+        };
+        assert_eq!(
+            plan_edit_segments(&[16_000], &[reversed], 16_000),
+            Err("edit_range_invalid".to_string())
+        );
+    }
+
+    #[test]
+    fn transcript_segments_follow_the_edit() {
+        let segments = plan_edit_segments(
+            &[96_000],
+            &[source("a", &[(1_000, 2_000), (3_000, 3_500)])],
+            16_000,
+        )
+        .expect("plan");
+        let edits: Vec<&EditSegment> = segments.iter().collect();
+        let word = |t_start_ms: i64, t_end_ms: i64, text: &str| models::TranscriptWord {
+            t_start_ms,
+            t_end_ms,
+            text: text.to_string(),
+            confidence: Some(0.9),
+        };
+        let mut spanning = text_segment(2_500, 4_000, "spans a cut");
+        spanning.words = vec![
+            word(2_500, 2_900, "spans"),
+            word(3_100, 3_400, "a"),
+            word(3_600, 4_000, "cut"),
+        ];
+        let remapped = remap_transcript_segments(
+            &[
+                text_segment(0, 900, "intro"),
+                text_segment(1_100, 1_900, "false start"),
+                spanning,
+                text_segment(5_000, 5_800, "outro"),
+            ],
+            &edits,
+        );
+        let timings: Vec<_> = remapped
+            .iter()
+            .map(|s| (s.text.as_str(), s.t_start_ms, s.t_end_ms))
+            .collect();
+        assert_eq!(
+            timings,
+            vec![
+                ("intro", 0, 900),
+                ("spans a cut", 1_500, 2_500),
+                ("outro", 3_500, 4_300),
+            ]
+        );
+        assert_eq!(
+            remapped[1].words,
+            vec![word(1_500, 1_900, "spans"), word(2_100, 2_500, "cut")]
+        );
+
+        let marker = |t_ms: i64, label: &str| models::RecordingMarker {
+            t_ms,
+            label: label.to_string(),
+        };
+        let markers = remap_markers(
+            &[vec![
+                marker(500, "kept"),
+                marker(1_200, "in cut"),
+                marker(4_000, "after cuts"),
+            ]],
+            &segments,
+        );
+        assert_eq!(
+            markers,
+            vec![marker(500, "kept"), marker(2_500, "after cuts")]
+        );
+    }
+}
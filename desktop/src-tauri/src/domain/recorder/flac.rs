@@ -0,0 +1,502 @@
+use std::io::Cursor;
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_FLAC};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+const FLAC_MARKER: &[u8; 4] = b"fLaC";
+const STREAM_INFO_LEN: usize = 34;
+/// Marker, one metadata block header and STREAMINFO: an empty take.
+const HEADER_ONLY_LEN: usize = FLAC_MARKER.len() + 4 + STREAM_INFO_LEN;
+const BLOCK_SIZE: usize = 4096;
+const MAX_FIXED_ORDER: usize = 4;
+const MAX_PARTITION_ORDER: u32 = 8;
+const MAX_RICE_PARAM: u32 = 14;
+const FRAME_SYNC: u32 = 0x3ffe;
+
+/// Decodes a stored take through symphonia's FLAC decoder; only the
+/// encoder is implemented here.
+pub fn decode_flac_pcm16_mono_16k(bytes: &[u8]) -> Result<Vec<i16>, String> {
+    let stream = MediaSourceStream::new(Box::new(Cursor::new(bytes.to_vec())), Default::default());
+    let mut hint = Hint::new();
+    hint.with_extension("flac");
+    let probed = symphonia::default::get_probe().format(
+        &hint,
+        stream,
+        &FormatOptions::default(),
+        &MetadataOptions::default(),
+    );
+    let probed = match probed {
+        Ok(probed) => probed,
+        // symphonia syncs to the first frame, which an empty take has none of.
+        Err(SymphoniaError::IoError(_))
+            if bytes.len() == HEADER_ONLY_LEN && bytes.starts_with(FLAC_MARKER) =>
+        {
+            return Ok(Vec::new())
+        }
+        Err(_) => return Err("flac_header".to_string()),
+    };
+    let mut reader = probed.format;
+    let track = reader
+        .default_track()
+        .filter(|track| track.codec_params.codec == CODEC_TYPE_FLAC)
+        .ok_or_else(|| "flac_header".to_string())?;
+    let params = &track.codec_params;
+    if params.channels.map(|channels| channels.count()) != Some(1)
+        || params.bits_per_sample != Some(16)
+    {
+        return Err("flac_format".to_string());
+    }
+    if params.sample_rate != Some(16_000) {
+        return Err("flac_sample_rate".to_string());
+    }
+    let track_id = track.id;
+    let total_samples = params.n_frames;
+    let mut samples = Vec::with_capacity(total_samples.unwrap_or(0) as usize);
+    let mut decoder = symphonia::default::get_codecs()
+        .make(params, &DecoderOptions::default())
+        .map_err(|_| "flac_format".to_string())?;
+    loop {
+        let packet = match reader.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(err))
+                if err.kind() == std::io::ErrorKind::UnexpectedEof =>
+            {
+                break
+            }
+            Err(_) => return Err("flac_frame".to_string()),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let audio = decoder
+            .decode(&packet)
+            .map_err(|_| "flac_frame".to_string())?;
+        let mut buffer = SampleBuffer::<i16>::new(audio.capacity() as u64, *audio.spec());
+        buffer.copy_interleaved_ref(audio);
+        samples.extend_from_slice(buffer.samples());
+    }
+    if total_samples.is_some_and(|total| total != samples.len() as u64) {
+        return Err("flac_truncated".to_string());
+    }
+    Ok(samples)
+}
+
+pub fn encode_flac_pcm16_mono(sample_rate: u32, samples: &[i16]) -> Result<Vec<u8>, String> {
+    if sample_rate == 0 || sample_rate >= 1 << 20 {
+        return Err("flac_sample_rate".to_string());
+    }
+    let samples: Vec<i32> = samples.iter().map(|sample| *sample as i32).collect();
+
+    let mut frames = Vec::new();
+    let mut min_frame = u32::MAX;
+    let mut max_frame = 0u32;
+    for (frame_index, block) in samples.chunks(BLOCK_SIZE).enumerate() {
+        let frame = encode_frame(frame_index as u64, sample_rate, block)?;
+        min_frame = min_frame.min(frame.len() as u32);
+        max_frame = max_frame.max(frame.len() as u32);
+        frames.extend_from_slice(&frame);
+    }
+    if frames.is_empty() {
+        min_frame = 0;
+    }
+
+    let block_size = samples.len().clamp(16, BLOCK_SIZE) as u64;
+    let mut info = BitWriter::default();
+    info.write(block_size, 16);
+    info.write(block_size, 16);
+    info.write(min_frame as u64, 24);
+    info.write(max_frame as u64, 24);
+    info.write(sample_rate as u64, 20);
+    info.write(0, 3);
+    info.write(15, 5);
+    info.write(samples.len() as u64, 36);
+    for _ in 0..16 {
+        info.write(0, 8);
+    }
+    let info = info.finish();
+
+    let mut out = Vec::with_capacity(FLAC_MARKER.len() + 4 + info.len() + frames.len());
+    out.extend_from_slice(FLAC_MARKER);
+    out.push(0x80);
+    out.extend_from_slice(&(info.len() as u32).to_be_bytes()[1..]);
+    out.extend_from_slice(&info);
+    out.extend_from_slice(&frames);
+    Ok(out)
+}
+
+fn encode_frame(frame_index: u64, sample_rate: u32, block: &[i32]) -> Result<Vec<u8>, String> {
+    let mut writer = BitWriter::default();
+    writer.write(FRAME_SYNC as u64, 14);
+    writer.write(0, 1);
+    writer.write(0, 1);
+
+    let (block_size_code, block_size_extra) = match block.len() {
+        BLOCK_SIZE => (12, None),
+        len if len <= 256 => (6, Some((len as u64 - 1, 8))),
+        len => (7, Some((len as u64 - 1, 16))),
+    };
+    let (sample_rate_code, sample_rate_extra) = match sample_rate {
+        8_000 => (4, None),
+        16_000 => (5, None),
+        22_050 => (6, None),
+        24_000 => (7, None),
+        32_000 => (8, None),
+        44_100 => (9, None),
+        48_000 => (10, None),
+        96_000 => (11, None),
+        rate if rate <= 0xffff => (13, Some((rate as u64, 16))),
+        _ => (0, None),
+    };
+    writer.write(block_size_code, 4);
+    writer.write(sample_rate_code, 4);
+    writer.write(0, 4);
+    writer.write(4, 3);
+    writer.write(0, 1);
+    write_utf8_number(&mut writer, frame_index);
+    if let Some((value, bits)) = block_size_extra {
+        writer.write(value, bits);
+    }
+    if let Some((value, bits)) = sample_rate_extra {
+        writer.write(value, bits);
+    }
+    let crc = crc8(writer.bytes());
+    writer.write(crc as u64, 8);
+
+    encode_subframe(&mut writer, block, 16);
+    writer.align();
+    let crc = crc16(writer.bytes());
+    writer.write(crc as u64, 16);
+    Ok(writer.finish())
+}
+
+fn write_utf8_number(writer: &mut BitWriter, value: u64) {
+    if value < 0x80 {
+        writer.write(value, 8);
+        return;
+    }
+    let extra = match value {
+        0..=0x7ff => 1,
+        0x800..=0xffff => 2,
+        0x1_0000..=0x1f_ffff => 3,
+        0x20_0000..=0x3ff_ffff => 4,
+        0x400_0000..=0x7fff_ffff => 5,
+        _ => 6,
+    };
+    let lead = (0xff00u64 >> (extra + 1)) & 0xff;
+    writer.write(lead | (value >> (6 * extra)), 8);
+    for index in (0..extra).rev() {
+        writer.write(0x80 | ((value >> (6 * index)) & 0x3f), 8);
+    }
+}
+
+fn encode_subframe(writer: &mut BitWriter, block: &[i32], bps: u32) {
+    if block.iter().all(|sample| *sample == block[0]) {
+        writer.write(0, 8);
+        writer.write_signed(block[0] as i64, bps);
+        return;
+    }
+
+    let verbatim_bits = block.len() as u64 * bps as u64;
+    let best = (0..=MAX_FIXED_ORDER.min(block.len() - 1))
+        .map(|order| {
+            let residual = fixed_residual(block, order);
+            let plan = plan_residual(&residual, block.len(), order);
+            let bits = order as u64 * bps as u64 + plan.bits;
+            (order, residual, plan, bits)
+        })
+        .min_by_key(|(_, _, _, bits)| *bits);
+
+    match best {
+        Some((order, residual, plan, bits)) if bits < verbatim_bits => {
+            writer.write((0x08 | order as u64) << 1, 8);
+            for sample in &block[..order] {
+                writer.write_signed(*sample as i64, bps);
+            }
+            write_residual(writer, &residual, &plan, block.len(), order);
+        }
+        _ => {
+            writer.write(0x02, 8);
+            for sample in block {
+                writer.write_signed(*sample as i64, bps);
+            }
+        }
+    }
+}
+
+fn fixed_residual(block: &[i32], order: usize) -> Vec<u64> {
+    (order..block.len())
+        .map(|i| {
+            let s = |back: usize| block[i - back] as i64;
+            let prediction = match order {
+                0 => 0,
+                1 => s(1),
+                2 => 2 * s(1) - s(2),
+                3 => 3 * s(1) - 3 * s(2) + s(3),
+                _ => 4 * s(1) - 6 * s(2) + 4 * s(3) - s(4),
+            };
+            fold(block[i] as i64 - prediction)
+        })
+        .collect()
+}
+
+struct ResidualPlan {
+    partition_order: u32,
+    params: Vec<u32>,
+    bits: u64,
+}
+
+/// Picks the partition order and Rice parameters from per-partition sums,
+/// estimating each partition's cost as `count * (k + 1) + sum >> k`.
+fn plan_residual(folded: &[u64], block_size: usize, order: usize) -> ResidualPlan {
+    let mut max_order = 0;
+    while max_order < MAX_PARTITION_ORDER
+        && block_size.is_multiple_of(1 << (max_order + 1))
+        && block_size >> (max_order + 1) > order
+    {
+        max_order += 1;
+    }
+
+    let mut sums = Vec::with_capacity(1 << max_order);
+    let per_partition = block_size >> max_order;
+    let mut start = 0;
+    for partition in 0..1usize << max_order {
+        let count = per_partition - if partition == 0 { order } else { 0 };
+        sums.push((
+            folded[start..start + count].iter().sum::<u64>(),
+            count as u64,
+        ));
+        start += count;
+    }
+
+    let mut best: Option<ResidualPlan> = None;
+    let mut partition_order = max_order;
+    loop {
+        let mut params = Vec::with_capacity(sums.len());
+        let mut bits = 2 + 4;
+        for (sum, count) in &sums {
+            let (param, cost) = estimate_rice_param(*sum, *count);
+            params.push(param);
+            bits += 4 + cost;
+        }
+        if best.as_ref().is_none_or(|plan| bits <= plan.bits) {
+            best = Some(ResidualPlan {
+                partition_order,
+                params,
+                bits,
+            });
+        }
+        if partition_order == 0 {
+            break;
+        }
+        sums = sums
+            .chunks(2)
+            .map(|pair| (pair[0].0 + pair[1].0, pair[0].1 + pair[1].1))
+            .collect();
+        partition_order -= 1;
+    }
+    best.unwrap_or(ResidualPlan {
+        partition_order: 0,
+        params: vec![0],
+        bits: u64::MAX,
+    })
+}
+
+fn estimate_rice_param(sum: u64, count: u64) -> (u32, u64) {
+    (0..=MAX_RICE_PARAM)
+        .map(|param| (param, count * (param as u64 + 1) + (sum >> param)))
+        .min_by_key(|(_, cost)| *cost)
+        .unwrap_or((0, 0))
+}
+
+fn write_residual(
+    writer: &mut BitWriter,
+    folded: &[u64],
+    plan: &ResidualPlan,
+    block_size: usize,
+    order: usize,
+) {
+    writer.write(0, 2);
+    writer.write(plan.partition_order as u64, 4);
+    let per_partition = block_size >> plan.partition_order;
+    let mut start = 0;
+    for (partition, param) in plan.params.iter().enumerate() {
+        let count = per_partition - if partition == 0 { order } else { 0 };
+        let chunk = &folded[start..start + count];
+        start += count;
+        writer.write(*param as u64, 4);
+        for value in chunk {
+            writer.write_unary(value >> param);
+            writer.write(value & ((1 << param) - 1), *param);
+        }
+    }
+}
+
+fn fold(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+fn crc8(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |mut crc, byte| {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 {
+                (crc << 1) ^ 0x07
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+fn crc16(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(0u16, |mut crc, byte| {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x8005
+            } else {
+                crc << 1
+            };
+        }
+        crc
+    })
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u64,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u64, bits: u32) {
+        if bits == 0 {
+            return;
+        }
+        if bits > 32 {
+            self.write(value >> 32, bits - 32);
+            self.write(value & 0xffff_ffff, 32);
+            return;
+        }
+        self.acc = (self.acc << bits) | (value & ((1u64 << bits) - 1));
+        self.bits += bits;
+        while self.bits >= 8 {
+            self.bits -= 8;
+            self.bytes.push((self.acc >> self.bits) as u8);
+        }
+        self.acc &= (1u64 << self.bits) - 1;
+    }
+
+    fn write_signed(&mut self, value: i64, bits: u32) {
+        self.write(value as u64, bits);
+    }
+
+    fn write_unary(&mut self, zeros: u64) {
+        let mut zeros = zeros;
+        while zeros >= 32 {
+            self.write(0, 32);
+            zeros -= 32;
+        }
+        self.write(1, zeros as u32 + 1);
+    }
+
+    fn align(&mut self) {
+        if self.bits > 0 {
+            self.write(0, 8 - self.bits);
+        }
+    }
+
+    /// Completed bytes; callers align first when they need the whole stream.
+    fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        self.align();
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn speech_like(len: usize) -> Vec<i16> {
+        let mut seed = 0x2545_f491u32;
+        (0..len)
+            .map(|i| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                let noise = ((seed >> 16) as i32 - 32_768) / 64;
+                let t = i as f64 / 16_000.0;
+                let tone = (t * 220.0 * std::f64::consts::TAU).sin() * 9_000.0
+                    + (t * 470.0 * std::f64::consts::TAU).sin() * 3_000.0;
+                (tone as i32 + noise).clamp(i16::MIN as i32, i16::MAX as i32) as i16
+            })
+            .collect()
+    }
+
+    #[test]
+    fn encode_decode_round_trip_is_lossless() {
+        let samples = speech_like(BLOCK_SIZE * 3 + 123);
+        let bytes = encode_flac_pcm16_mono(16_000, &samples).expect("encode");
+        assert!(bytes.starts_with(FLAC_MARKER));
+        assert_eq!(decode_flac_pcm16_mono_16k(&bytes).expect("decode"), samples);
+        assert!(bytes.len() < samples.len() * 2);
+    }
+
+    #[test]
+    fn silence_and_extremes_round_trip() {
+        let mut samples = vec![0i16; BLOCK_SIZE + 7];
+        samples.extend([i16::MIN, i16::MAX, i16::MIN, i16::MAX, 0, -1, 1]);
+        let bytes = encode_flac_pcm16_mono(16_000, &samples).expect("encode");
+        assert_eq!(decode_flac_pcm16_mono_16k(&bytes).expect("decode"), samples);
+
+        let empty = encode_flac_pcm16_mono(16_000, &[]).expect("encode empty");
+        assert!(decode_flac_pcm16_mono_16k(&empty)
+            .expect("decode")
+            .is_empty());
+    }
+
+    #[test]
+    fn decode_rejects_corruption_and_other_formats() {
+        let samples = speech_like(2_000);
+        let mut bytes = encode_flac_pcm16_mono(16_000, &samples).expect("encode");
+        let truncated = &bytes[..bytes.len() - 100];
+        assert!(decode_flac_pcm16_mono_16k(truncated).is_err());
+        let middle = bytes.len() / 2;
+        bytes[middle] ^= 0x5a;
+        assert_ne!(
+            decode_flac_pcm16_mono_16k(&bytes).ok(),
+            Some(samples.clone())
+        );
+
+        assert_eq!(
+            decode_flac_pcm16_mono_16k(b"RIFF....WAVE").expect_err("wav"),
+            "flac_header"
+        );
+        let other_rate = encode_flac_pcm16_mono(44_100, &samples).expect("encode");
+        assert_eq!(
+            decode_flac_pcm16_mono_16k(&other_rate).expect_err("rate"),
+            "flac_sample_rate"
+        );
+    }
+
+    #[test]
+    fn checksums_match_reference_check_values() {
+        assert_eq!(crc8(b"123456789"), 0xf4);
+        assert_eq!(crc16(b"123456789"), 0xfee8);
+    }
+
+    #[test]
+    fn multibyte_frame_numbers_decode() {
+        let samples = speech_like(BLOCK_SIZE * 130);
+        let bytes = encode_flac_pcm16_mono(16_000, &samples).expect("encode");
+        assert_eq!(decode_flac_pcm16_mono_16k(&bytes).expect("decode"), samples);
+    }
+}
@@ -0,0 +1,438 @@
+use super::recording::{Resampler, ResamplerKind};
+use super::storage;
+use crate::domain::run;
+use crate::domain::training::quest;
+use crate::platform::{artifacts, db};
+use std::fs::File;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{DecoderOptions, CODEC_TYPE_NULL};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+const TARGET_SAMPLE_RATE: u32 = 16_000;
+const MAX_IMPORT_BYTES: u64 = 1 << 30;
+const MAGIC_LEN: usize = 16;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceFormat {
+    Wav,
+    Flac,
+    Ogg,
+    Mp3,
+}
+
+impl SourceFormat {
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Wav => "wav",
+            Self::Flac => "flac",
+            Self::Ogg => "ogg",
+            Self::Mp3 => "mp3",
+        }
+    }
+
+    pub fn detect(bytes: &[u8], path: &Path) -> Option<Self> {
+        if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WAVE" {
+            return Some(Self::Wav);
+        }
+        if bytes.starts_with(b"fLaC") {
+            return Some(Self::Flac);
+        }
+        if bytes.starts_with(b"OggS") {
+            return Some(Self::Ogg);
+        }
+        if bytes.starts_with(b"ID3")
+            || (bytes.len() >= 2 && bytes[0] == 0xff && bytes[1] & 0xe0 == 0xe0)
+        {
+            return Some(Self::Mp3);
+        }
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "ogg" | "oga" => Some(Self::Ogg),
+            "mp3" => Some(Self::Mp3),
+            _ => None,
+        }
+    }
+}
+
+/// Mono 16 kHz PCM16 plus what the source file looked like.
+struct DecodedImport {
+    pcm: Vec<i16>,
+    source_sample_rate: u32,
+    source_channels: u16,
+    source_duration_ms: i64,
+}
+
+pub enum ImportTarget {
+    Run {
+        project_id: String,
+    },
+    QuestAttempt {
+        project_id: String,
+        quest_code: String,
+    },
+}
+
+pub struct ImportedAudio {
+    pub artifact_id: String,
+    pub path: PathBuf,
+    pub bytes: u64,
+    pub sha256: String,
+    pub duration_ms: i64,
+    pub source_format: SourceFormat,
+    pub run_id: Option<String>,
+    pub attempt_id: Option<String>,
+}
+
+pub fn import_audio_file(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    source_path: &Path,
+    target: ImportTarget,
+    resampler: ResamplerKind,
+) -> Result<ImportedAudio, String> {
+    db::ensure_profile_exists(app, profile_id)?;
+    let metadata = std::fs::metadata(source_path).map_err(|e| format!("audio_import_read: {e}"))?;
+    if !metadata.is_file() {
+        return Err("audio_import_not_file".to_string());
+    }
+    if metadata.len() > MAX_IMPORT_BYTES {
+        return Err("audio_import_too_large".to_string());
+    }
+    let source_format = SourceFormat::detect(&read_magic(source_path)?, source_path)
+        .ok_or_else(|| "audio_import_format_unsupported".to_string())?;
+    let decoded = decode_to_mono_16k(source_path, source_format, resampler)?;
+    if decoded.pcm.is_empty() {
+        return Err("audio_import_empty".to_string());
+    }
+    let pcm = decoded.pcm;
+
+    let format = storage::load_storage_format(app, profile_id)?;
+    let encoded = format.encode(&pcm)?;
+    let file_name = source_path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string());
+    let artifact_metadata = storage::audio_metadata(
+        format,
+        &serde_json::json!({
+            "imported": true,
+            "source_filename": file_name,
+            "source_format": source_format.code(),
+            "source_sample_rate_hz": decoded.source_sample_rate,
+            "source_channels": decoded.source_channels,
+            "source_duration_ms": decoded.source_duration_ms,
+            "resampler": resampler.code(),
+        }),
+    );
+    let record = artifacts::store_bytes(
+        app,
+        profile_id,
+        "audio",
+        format.code(),
+        &encoded,
+        &artifact_metadata,
+    )?;
+
+    let attached = attach_audio(app, profile_id, &record.id, target);
+    let (run_id, attempt_id) = match attached {
+        Ok(ids) => ids,
+        Err(err) => {
+            if let Err(cleanup) = artifacts::delete_artifact(app, profile_id, &record.id) {
+                return Err(format!("{err}; {cleanup}"));
+            }
+            return Err(err);
+        }
+    };
+
+    Ok(ImportedAudio {
+        artifact_id: record.id,
+        path: record.abspath,
+        bytes: record.bytes,
+        sha256: record.sha256,
+        duration_ms: super::duration_ms_from_sample_count(pcm.len(), TARGET_SAMPLE_RATE),
+        source_format,
+        run_id,
+        attempt_id,
+    })
+}
+
+fn attach_audio(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: &str,
+    target: ImportTarget,
+) -> Result<(Option<String>, Option<String>), String> {
+    match target {
+        ImportTarget::Run { project_id } => {
+            let run_id =
+                run::run_create_with_audio(app, profile_id, &project_id, audio_artifact_id)?;
+            Ok((Some(run_id), None))
+        }
+        ImportTarget::QuestAttempt {
+            project_id,
+            quest_code,
+        } => {
+            let attempt_id = quest::quest_submit_audio(
+                app,
+                profile_id,
+                &project_id,
+                &quest_code,
+                audio_artifact_id,
+                None,
+            )?;
+            Ok((None, Some(attempt_id)))
+        }
+    }
+}
+
+fn read_magic(path: &Path) -> Result<Vec<u8>, String> {
+    let mut file = File::open(path).map_err(|e| format!("audio_import_read: {e}"))?;
+    let mut magic = Vec::with_capacity(MAGIC_LEN);
+    file.by_ref()
+        .take(MAGIC_LEN as u64)
+        .read_to_end(&mut magic)
+        .map_err(|e| format!("audio_import_read: {e}"))?;
+    Ok(magic)
+}
+
+/// Decodes packet by packet, downmixing and resampling as it goes, so only
+/// the 16 kHz result is held in memory.
+fn decode_to_mono_16k(
+    path: &Path,
+    source_format: SourceFormat,
+    kind: ResamplerKind,
+) -> Result<DecodedImport, String> {
+    let file = File::open(path).map_err(|e| format!("audio_import_read: {e}"))?;
+    let stream = MediaSourceStream::new(Box::new(file), Default::default());
+    let mut hint = Hint::new();
+    hint.with_extension(source_format.code());
+    let probed = symphonia::default::get_probe()
+        .format(
+            &hint,
+            stream,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|_| "audio_import_format_unsupported".to_string())?;
+    let mut reader = probed.format;
+    let track = reader
+        .tracks()
+        .iter()
+        .find(|track| track.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or_else(|| "audio_import_empty".to_string())?;
+    let track_id = track.id;
+    let source_sample_rate = track
+        .codec_params
+        .sample_rate
+        .filter(|rate| *rate > 0)
+        .ok_or_else(|| "audio_import_format_unsupported".to_string())?;
+    let mut source_channels = track
+        .codec_params
+        .channels
+        .map(|channels| channels.count() as u16)
+        .unwrap_or(0);
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|_| "audio_import_codec_unsupported".to_string())?;
+
+    let mut resampler = Resampler::new(kind, source_sample_rate, TARGET_SAMPLE_RATE);
+    let mut pcm = Vec::new();
+    let mut source_frames = 0usize;
+    loop {
+        let packet = match reader.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(err))
+                if err.kind() == std::io::ErrorKind::UnexpectedEof =>
+            {
+                break
+            }
+            Err(_) => return Err("audio_import_decode_failed".to_string()),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+        let audio = match decoder.decode(&packet) {
+            Ok(audio) => audio,
+            // A corrupt frame is skipped, as players do.
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(_) => return Err("audio_import_decode_failed".to_string()),
+        };
+        let spec = *audio.spec();
+        let channels = spec.channels.count().max(1);
+        source_channels = source_channels.max(channels as u16);
+        let mut buffer = SampleBuffer::<f32>::new(audio.capacity() as u64, spec);
+        buffer.copy_interleaved_ref(audio);
+        let mono: Vec<f32> = buffer
+            .samples()
+            .chunks_exact(channels)
+            .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+            .collect();
+        source_frames += mono.len();
+        push_pcm16(&mut pcm, &resampler.process(&mono));
+    }
+    push_pcm16(&mut pcm, &resampler.flush());
+
+    Ok(DecodedImport {
+        pcm,
+        source_sample_rate,
+        source_channels,
+        source_duration_ms: super::duration_ms_from_sample_count(source_frames, source_sample_rate),
+    })
+}
+
+fn push_pcm16(pcm: &mut Vec<i16>, samples: &[f32]) {
+    // Decoders scale PCM16 by 1/32768, so the inverse keeps 16-bit sources exact.
+    pcm.extend(samples.iter().map(|sample| {
+        (sample * 32768.0)
+            .round()
+            .clamp(i16::MIN as f32, i16::MAX as f32) as i16
+    }));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::recorder::flac;
+
+    fn wav_bytes(format_tag: u16, channels: u16, rate: u32, bits: u16, data: &[u8]) -> Vec<u8> {
+        let block_align = channels * bits / 8;
+        let mut out = Vec::new();
+        out.extend_from_slice(b"RIFF");
+        out.extend_from_slice(&(4 + 8 + 16 + 8 + 6 + 8 + data.len() as u32).to_le_bytes());
+        out.extend_from_slice(b"WAVE");
+        out.extend_from_slice(b"fmt ");
+        out.extend_from_slice(&16u32.to_le_bytes());
+        out.extend_from_slice(&format_tag.to_le_bytes());
+        out.extend_from_slice(&channels.to_le_bytes());
+        out.extend_from_slice(&rate.to_le_bytes());
+        out.extend_from_slice(&(rate * block_align as u32).to_le_bytes());
+        out.extend_from_slice(&block_align.to_le_bytes());
+        out.extend_from_slice(&bits.to_le_bytes());
+        // An odd-sized chunk before data exercises the pad byte handling.
+        out.extend_from_slice(b"junk");
+        out.extend_from_slice(&5u32.to_le_bytes());
+        out.extend_from_slice(b"abcde\0");
+        out.extend_from_slice(b"data");
+        out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        out.extend_from_slice(data);
+        out
+    }
+
+    fn decode_bytes(
+        name: &str,
+        bytes: &[u8],
+        kind: ResamplerKind,
+    ) -> Result<DecodedImport, String> {
+        let nonce = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or(0);
+        let path = std::env::temp_dir().join(format!("lepupitre-import-{nonce}-{name}"));
+        std::fs::write(&path, bytes).expect("write source");
+        let format = SourceFormat::detect(&read_magic(&path).expect("magic"), &path)
+            .ok_or_else(|| "audio_import_format_unsupported".to_string());
+        let decoded = format.and_then(|format| decode_to_mono_16k(&path, format, kind));
+        let _ = std::fs::remove_file(&path);
+        decoded
+    }
+
+    #[test]
+    fn stereo_24bit_wav_is_downmixed_and_resampled() {
+        let mut data = Vec::new();
+        for _ in 0..48_000 {
+            data.extend_from_slice(&(4_194_304i32).to_le_bytes()[..3]);
+            data.extend_from_slice(&(-2_097_152i32).to_le_bytes()[..3]);
+        }
+        let bytes = wav_bytes(1, 2, 48_000, 24, &data);
+
+        let decoded = decode_bytes("s24.wav", &bytes, ResamplerKind::Linear).expect("decode");
+        assert_eq!(decoded.source_sample_rate, 48_000);
+        assert_eq!(decoded.source_channels, 2);
+        assert_eq!(decoded.source_duration_ms, 1_000);
+        assert!((15_990..=16_000).contains(&decoded.pcm.len()));
+        assert!(decoded
+            .pcm
+            .iter()
+            .all(|sample| (*sample - 4_095).abs() <= 1));
+
+        let decoded = decode_bytes("s24.wav", &bytes, ResamplerKind::Sinc).expect("decode");
+        assert_eq!(decoded.pcm.len(), 16_000);
+        assert!(decoded.pcm[100..15_900]
+            .iter()
+            .all(|sample| (*sample - 4_095).abs() <= 40));
+    }
+
+    #[test]
+    fn float_wav_is_supported() {
+        let data: Vec<u8> = [0.5f32, -0.5, 0.25]
+            .iter()
+            .flat_map(|sample| sample.to_le_bytes())
+            .collect();
+        let decoded = decode_bytes(
+            "float.wav",
+            &wav_bytes(3, 1, 16_000, 32, &data),
+            ResamplerKind::Linear,
+        )
+        .expect("decode");
+        assert_eq!(decoded.pcm, vec![16_384, -16_384, 8_192]);
+        assert!(decode_bytes(
+            "garbage.ogg",
+            b"OggS\0garbage that is not a stream",
+            ResamplerKind::Linear
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn pcm16_wav_at_16khz_is_bit_exact() {
+        let pcm = [16_384i16, -16_384, 32_767, -32_768, 1, -1, 0];
+        let data: Vec<u8> = pcm.iter().flat_map(|sample| sample.to_le_bytes()).collect();
+        let decoded = decode_bytes(
+            "pcm16.wav",
+            &wav_bytes(1, 1, 16_000, 16, &data),
+            ResamplerKind::Sinc,
+        )
+        .expect("decode");
+        assert_eq!(decoded.pcm, pcm);
+    }
+
+    #[test]
+    fn flac_sources_are_normalized() {
+        let pcm = [16_384i16, -8_192, 0, 32_767, -32_768, 1, -1];
+        let bytes = flac::encode_flac_pcm16_mono(16_000, &pcm).expect("encode");
+        let decoded = decode_bytes("take.flac", &bytes, ResamplerKind::Linear).expect("decode");
+        assert_eq!(decoded.source_sample_rate, 16_000);
+        assert_eq!(decoded.source_channels, 1);
+        assert_eq!(decoded.pcm, pcm);
+    }
+
+    #[test]
+    fn source_format_uses_magic_bytes_before_extension() {
+        let path = Path::new("talk.mp3");
+        assert_eq!(
+            SourceFormat::detect(b"RIFF\0\0\0\0WAVEfmt ", path),
+            Some(SourceFormat::Wav)
+        );
+        assert_eq!(
+            SourceFormat::detect(b"OggS\0", path),
+            Some(SourceFormat::Ogg)
+        );
+        assert_eq!(
+            SourceFormat::detect(b"ID3\x04", Path::new("x.bin")),
+            Some(SourceFormat::Mp3)
+        );
+        assert_eq!(
+            SourceFormat::detect(b"\0\0\0\0", path),
+            Some(SourceFormat::Mp3)
+        );
+        assert_eq!(
+            SourceFormat::detect(b"\0\0\0\0", Path::new("notes.txt")),
+            None
+        );
+    }
+}
@@ -0,0 +1,263 @@
+use super::dsp;
+use super::pauses::energy_threshold;
+use super::vad::{VadConfig, VadState};
+
+const FRAME_MS: u32 = 50;
+const SILENCE_DB: f32 = -90.0;
+const PHRASE_FRAMES: usize = 20;
+const MIN_PHRASE_SPEECH_FRAMES: usize = 10;
+const TAIL_MS: i64 = 500;
+const MIN_TAIL_FRAMES: usize = 4;
+const MIN_BODY_FRAMES: usize = 10;
+const TRAILING_DROP_DB: f32 = 8.0;
+const CLIP_LEVEL: f32 = 0.99;
+const MIN_CLIP_RUN: usize = 3;
+const CLIP_MERGE_MS: i64 = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrailingOff {
+    pub t_start_ms: i64,
+    pub t_end_ms: i64,
+    pub drop_db: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClippingEvent {
+    pub t_start_ms: i64,
+    pub t_end_ms: i64,
+    pub clipped_samples: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct LoudnessAnalysis {
+    pub step_ms: i64,
+    /// RMS level per frame in dBFS, floored at -90.
+    pub envelope_db: Vec<f32>,
+    pub speech_mean_db: f32,
+    /// Standard deviation of phrase-level loudness; low values sound monotone.
+    /// `None` when the take has fewer than two phrases to compare.
+    pub variation_db: Option<f32>,
+    pub dynamic_range_db: f32,
+    pub trailing_offs: Vec<TrailingOff>,
+    pub clipping: Vec<ClippingEvent>,
+}
+
+/// Sentence ends are `(t_start_ms, t_end_ms)` spans whose last words are
+/// checked for a loudness drop.
+pub fn analyze_loudness(
+    samples: &[f32],
+    sample_rate: u32,
+    sentences: &[(i64, i64)],
+) -> Option<LoudnessAnalysis> {
+    let frame_len = (sample_rate as usize * FRAME_MS as usize) / 1000;
+    if frame_len == 0 || samples.len() < frame_len {
+        return None;
+    }
+
+    let energies: Vec<f32> = samples.chunks_exact(frame_len).map(dsp::rms).collect();
+    let envelope_db: Vec<f32> = energies.iter().map(|energy| to_db(*energy)).collect();
+    let config = VadConfig::pause_analysis(energy_threshold(&energies));
+    let mut vad = VadState::default();
+    let speech: Vec<bool> = energies
+        .iter()
+        .map(|energy| vad.update(*energy, FRAME_MS, &config).in_speech)
+        .collect();
+
+    let mut speech_db: Vec<f32> = envelope_db
+        .iter()
+        .zip(&speech)
+        .filter(|(_, in_speech)| **in_speech)
+        .map(|(db, _)| *db)
+        .collect();
+    if speech_db.is_empty() {
+        return None;
+    }
+    let speech_mean_db = mean(&speech_db);
+    speech_db.sort_by(|a, b| a.total_cmp(b));
+    let percentile = |p: usize| speech_db[(speech_db.len() * p / 100).min(speech_db.len() - 1)];
+    let dynamic_range_db = percentile(95) - percentile(10);
+
+    let phrase_levels: Vec<f32> = envelope_db
+        .chunks(PHRASE_FRAMES)
+        .zip(speech.chunks(PHRASE_FRAMES))
+        .filter_map(|(levels, flags)| {
+            let voiced: Vec<f32> = levels
+                .iter()
+                .zip(flags)
+                .filter(|(_, in_speech)| **in_speech)
+                .map(|(db, _)| *db)
+                .collect();
+            (voiced.len() >= MIN_PHRASE_SPEECH_FRAMES).then(|| mean(&voiced))
+        })
+        .collect();
+    let variation_db = (phrase_levels.len() >= 2).then(|| std_dev(&phrase_levels));
+
+    let trailing_offs = sentences
+        .iter()
+        .filter_map(|&(start, end)| trailing_off(&envelope_db, &speech, start, end))
+        .collect();
+
+    Some(LoudnessAnalysis {
+        step_ms: FRAME_MS as i64,
+        envelope_db,
+        speech_mean_db,
+        variation_db,
+        dynamic_range_db,
+        trailing_offs,
+        clipping: clipping_events(samples, sample_rate),
+    })
+}
+
+fn trailing_off(
+    envelope_db: &[f32],
+    speech: &[bool],
+    t_start_ms: i64,
+    t_end_ms: i64,
+) -> Option<TrailingOff> {
+    let frame = |ms: i64| (ms.max(0) / FRAME_MS as i64) as usize;
+    let tail_start = t_end_ms - TAIL_MS;
+    let voiced = |from: usize, to: usize| -> Vec<f32> {
+        (from..to.min(envelope_db.len()))
+            .filter(|idx| speech[*idx])
+            .map(|idx| envelope_db[idx])
+            .collect()
+    };
+    let body = voiced(frame(t_start_ms), frame(tail_start));
+    let tail = voiced(frame(tail_start), frame(t_end_ms));
+    if body.len() < MIN_BODY_FRAMES || tail.len() < MIN_TAIL_FRAMES {
+        return None;
+    }
+    let drop_db = mean(&body) - mean(&tail);
+    (drop_db >= TRAILING_DROP_DB).then_some(TrailingOff {
+        t_start_ms: tail_start.max(t_start_ms),
+        t_end_ms,
+        drop_db,
+    })
+}
+
+pub fn clipping_events(samples: &[f32], sample_rate: u32) -> Vec<ClippingEvent> {
+    let to_ms = |idx: usize| (idx as u64 * 1000 / sample_rate.max(1) as u64) as i64;
+    let mut events: Vec<ClippingEvent> = Vec::new();
+    let mut run_start = None;
+    for idx in 0..=samples.len() {
+        let clipped = samples
+            .get(idx)
+            .is_some_and(|sample| sample.abs() >= CLIP_LEVEL);
+        match (clipped, run_start) {
+            (true, None) => run_start = Some(idx),
+            (false, Some(start)) => {
+                run_start = None;
+                if idx - start < MIN_CLIP_RUN {
+                    continue;
+                }
+                let (t_start_ms, t_end_ms) = (to_ms(start), to_ms(idx).max(to_ms(start) + 1));
+                match events.last_mut() {
+                    Some(last) if t_start_ms - last.t_end_ms <= CLIP_MERGE_MS => {
+                        last.t_end_ms = t_end_ms;
+                        last.clipped_samples += idx - start;
+                    }
+                    _ => events.push(ClippingEvent {
+                        t_start_ms,
+                        t_end_ms,
+                        clipped_samples: idx - start,
+                    }),
+                }
+            }
+            _ => {}
+        }
+    }
+    events
+}
+
+fn to_db(energy: f32) -> f32 {
+    if energy <= 0.0 {
+        SILENCE_DB
+    } else {
+        (20.0 * energy.log10()).max(SILENCE_DB)
+    }
+}
+
+fn mean(values: &[f32]) -> f32 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().sum::<f32>() / values.len() as f32
+}
+
+fn std_dev(values: &[f32]) -> f32 {
+    let avg = mean(values);
+    (values.iter().map(|v| (v - avg) * (v - avg)).sum::<f32>() / values.len() as f32).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(ms: usize, amplitude: f32) -> Vec<f32> {
+        (0..ms * 16)
+            .map(|i| amplitude * (i as f32 * 0.07).sin())
+            .collect()
+    }
+
+    #[test]
+    fn steady_level_reads_as_monotone() {
+        let mut samples = Vec::new();
+        for _ in 0..6 {
+            samples.extend(tone(1_000, 0.3));
+        }
+        let flat = analyze_loudness(&samples, 16_000, &[]).expect("analysis");
+        assert!(flat.variation_db.is_some_and(|db| db < 0.5));
+        assert_eq!(flat.envelope_db.len(), 120);
+
+        let mut samples = Vec::new();
+        for amplitude in [0.05, 0.4, 0.1, 0.5, 0.08, 0.3] {
+            samples.extend(tone(1_000, amplitude));
+        }
+        let varied = analyze_loudness(&samples, 16_000, &[]).expect("analysis");
+        assert!(varied.variation_db.is_some_and(|db| db > 4.0));
+        assert!(varied.dynamic_range_db > 10.0);
+    }
+
+    #[test]
+    fn single_phrase_has_no_variation() {
+        let analysis = analyze_loudness(&tone(1_000, 0.3), 16_000, &[]).expect("analysis");
+        assert_eq!(analysis.variation_db, None);
+    }
+
+    #[test]
+    fn quiet_sentence_ending_is_trailing_off() {
+        let mut samples = tone(2_000, 0.3);
+        samples.extend(tone(600, 0.03));
+        samples.extend(tone(2_600, 0.3));
+        let analysis =
+            analyze_loudness(&samples, 16_000, &[(0, 2_600), (2_600, 5_200)]).expect("analysis");
+        assert_eq!(analysis.trailing_offs.len(), 1);
+        assert_eq!(analysis.trailing_offs[0].t_end_ms, 2_600);
+        assert!(analysis.trailing_offs[0].drop_db > 15.0);
+    }
+
+    #[test]
+    fn clipped_runs_are_grouped_into_events() {
+        let mut samples = vec![0.1f32; 16_000];
+        samples[1_000..1_010].fill(1.0);
+        samples[2_000..2_005].fill(-1.0);
+        samples[8_000..8_002].fill(1.0);
+        samples[12_000..12_020].fill(0.995);
+        let events = clipping_events(&samples, 16_000);
+        assert_eq!(
+            events,
+            vec![
+                ClippingEvent {
+                    t_start_ms: 62,
+                    t_end_ms: 126,
+                    clipped_samples: 15
+                },
+                ClippingEvent {
+                    t_start_ms: 750,
+                    t_end_ms: 751,
+                    clipped_samples: 20
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,92 @@
+use crate::kernel::models::RecordingMarker;
+use crate::platform::artifacts;
+use serde_json::Value;
+
+pub const MAX_MARKERS: usize = 200;
+const DEFAULT_LABEL: &str = "Marker";
+const MAX_LABEL_CHARS: usize = 80;
+
+pub fn normalize_marker_label(label: Option<&str>) -> Result<String, String> {
+    let label = label.map(str::trim).unwrap_or_default();
+    if label.is_empty() {
+        return Ok(DEFAULT_LABEL.to_string());
+    }
+    if label.chars().count() > MAX_LABEL_CHARS {
+        return Err("recording_marker_label_too_long".to_string());
+    }
+    Ok(label.to_string())
+}
+
+pub fn markers_from_metadata(metadata: &Value) -> Vec<RecordingMarker> {
+    metadata
+        .get("markers")
+        .cloned()
+        .and_then(|markers| serde_json::from_value(markers).ok())
+        .unwrap_or_default()
+}
+
+/// Markers stored with an audio artifact; unreadable metadata means none.
+pub fn audio_markers(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: &str,
+) -> Vec<RecordingMarker> {
+    artifacts::artifact_metadata(app, profile_id, audio_artifact_id)
+        .map(|metadata| markers_from_metadata(&metadata))
+        .unwrap_or_default()
+}
+
+/// Keeps the markers inside `[start_ms, end_ms]`, rebased on `start_ms`.
+pub fn trim_markers(
+    markers: &[RecordingMarker],
+    start_ms: i64,
+    end_ms: i64,
+) -> Vec<RecordingMarker> {
+    markers
+        .iter()
+        .filter(|marker| marker.t_ms >= start_ms && marker.t_ms <= end_ms)
+        .map(|marker| RecordingMarker {
+            t_ms: marker.t_ms - start_ms,
+            label: marker.label.clone(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marker(t_ms: i64, label: &str) -> RecordingMarker {
+        RecordingMarker {
+            t_ms,
+            label: label.to_string(),
+        }
+    }
+
+    #[test]
+    fn labels_default_and_are_bounded() {
+        assert_eq!(normalize_marker_label(None).expect("none"), "Marker");
+        assert_eq!(normalize_marker_label(Some("  ")).expect("blank"), "Marker");
+        assert_eq!(
+            normalize_marker_label(Some(" new slide ")).expect("label"),
+            "new slide"
+        );
+        assert_eq!(
+            normalize_marker_label(Some(&"x".repeat(81))).expect_err("long"),
+            "recording_marker_label_too_long"
+        );
+    }
+
+    #[test]
+    fn markers_round_trip_through_metadata_and_trim() {
+        let markers = vec![marker(500, "stumbled"), marker(4_000, "new slide")];
+        let metadata = serde_json::json!({ "markers": markers });
+        assert_eq!(markers_from_metadata(&metadata), markers);
+        assert!(markers_from_metadata(&serde_json::json!({})).is_empty());
+
+        assert_eq!(
+            trim_markers(&markers, 1_000, 5_000),
+            vec![marker(3_000, "new slide")]
+        );
+    }
+}
@@ -1,6 +1,16 @@
+pub mod drafts;
 pub mod dsp;
+pub mod edit;
+pub mod flac;
+pub mod import;
+pub mod loudness;
+pub mod markers;
+pub mod pauses;
+pub mod pitch;
 pub mod recording;
+pub mod storage;
 pub mod vad;
+pub mod waveform;
 
 use cpal::traits::{DeviceTrait, HostTrait};
 
@@ -58,6 +68,14 @@ pub fn build_recording_input_device_id(index: usize, name: &str) -> String {
     format!("mic-{}-{}", index, name.replace(' ', "_"))
 }
 
+pub fn decode_audio_pcm16_mono_16k(bytes: &[u8]) -> Result<Vec<i16>, String> {
+    if storage::AudioStorageFormat::detect(bytes) == storage::AudioStorageFormat::Flac {
+        flac::decode_flac_pcm16_mono_16k(bytes)
+    } else {
+        decode_wav_pcm16_mono_16k(bytes)
+    }
+}
+
 pub fn decode_wav_pcm16_mono_16k(bytes: &[u8]) -> Result<Vec<i16>, String> {
     if bytes.len() < 44 {
         return Err("wav_header".to_string());
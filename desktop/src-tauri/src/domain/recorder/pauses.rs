@@ -0,0 +1,105 @@
+use super::dsp;
+use super::vad::{VadConfig, VadState};
+
+const FRAME_MS: u32 = 20;
+const MIN_PAUSE_MS: i64 = 250;
+const MIN_ENERGY_THRESHOLD: f32 = 0.003;
+const MAX_ENERGY_THRESHOLD: f32 = 0.02;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PauseSpan {
+    pub t_start_ms: i64,
+    pub t_end_ms: i64,
+}
+
+impl PauseSpan {
+    pub fn duration_ms(&self) -> i64 {
+        self.t_end_ms - self.t_start_ms
+    }
+}
+
+pub fn detect_pauses_from_samples(samples: &[f32], sample_rate: u32) -> Vec<PauseSpan> {
+    let frame_len = (sample_rate as usize * FRAME_MS as usize) / 1000;
+    if frame_len == 0 || samples.len() < frame_len {
+        return Vec::new();
+    }
+
+    let energies: Vec<f32> = samples.chunks_exact(frame_len).map(dsp::rms).collect();
+    let config = VadConfig::pause_analysis(energy_threshold(&energies));
+    let mut vad = VadState::default();
+    let mut pauses = Vec::new();
+    let mut pause_start_ms: Option<i64> = None;
+    let mut heard_speech = false;
+
+    for (idx, energy) in energies.iter().enumerate() {
+        let frame_end_ms = (idx as i64 + 1) * FRAME_MS as i64;
+        let decision = vad.update(*energy, FRAME_MS, &config);
+        if decision.speech_ended {
+            pause_start_ms = Some(frame_end_ms - config.speech_end_ms as i64);
+        }
+        if decision.speech_started {
+            let speech_start_ms = frame_end_ms - config.speech_start_ms as i64;
+            if let Some(start) = pause_start_ms.take() {
+                if heard_speech && speech_start_ms - start >= MIN_PAUSE_MS {
+                    pauses.push(PauseSpan {
+                        t_start_ms: start,
+                        t_end_ms: speech_start_ms,
+                    });
+                }
+            }
+            heard_speech = true;
+        }
+    }
+
+    pauses
+}
+
+pub(crate) fn energy_threshold(energies: &[f32]) -> f32 {
+    let mut sorted = energies.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let p90 = sorted[(sorted.len() * 9 / 10).min(sorted.len() - 1)];
+    (p90 * 0.25).clamp(MIN_ENERGY_THRESHOLD, MAX_ENERGY_THRESHOLD)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(ms: usize, amplitude: f32) -> Vec<f32> {
+        let len = ms * 16;
+        (0..len)
+            .map(|i| amplitude * (i as f32 * 0.07).sin())
+            .collect()
+    }
+
+    #[test]
+    fn detects_silence_between_speech() {
+        let mut samples = tone(1_000, 0.3);
+        samples.extend(tone(800, 0.0));
+        samples.extend(tone(1_000, 0.3));
+
+        let pauses = detect_pauses_from_samples(&samples, 16_000);
+        assert_eq!(pauses.len(), 1);
+        let pause = pauses[0];
+        assert!((pause.t_start_ms - 1_000).abs() <= 40, "{pause:?}");
+        assert!((pause.t_end_ms - 1_800).abs() <= 40, "{pause:?}");
+    }
+
+    #[test]
+    fn ignores_leading_and_trailing_silence() {
+        let mut samples = tone(1_500, 0.0);
+        samples.extend(tone(1_000, 0.3));
+        samples.extend(tone(1_500, 0.0));
+
+        assert!(detect_pauses_from_samples(&samples, 16_000).is_empty());
+    }
+
+    #[test]
+    fn short_gaps_are_not_pauses() {
+        let mut samples = tone(1_000, 0.3);
+        samples.extend(tone(120, 0.0));
+        samples.extend(tone(1_000, 0.3));
+
+        assert!(detect_pauses_from_samples(&samples, 16_000).is_empty());
+    }
+}
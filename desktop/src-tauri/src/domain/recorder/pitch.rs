@@ -0,0 +1,243 @@
+use super::dsp;
+use crate::kernel::models;
+
+const HOP_MS: i64 = 20;
+const WINDOW_MS: usize = 25;
+const MIN_F0_HZ: f32 = 70.0;
+const MAX_F0_HZ: f32 = 400.0;
+const YIN_THRESHOLD: f32 = 0.15;
+const MIN_VOICED_RMS: f32 = 0.01;
+const MIN_SEGMENT_VOICED_FRAMES: usize = 25;
+const FLAT_RANGE_ST: f64 = 3.0;
+
+/// Fundamental frequency per hop; `None` marks unvoiced or silent frames.
+#[derive(Debug, Clone)]
+pub struct PitchTrack {
+    pub hop_ms: i64,
+    pub f0_hz: Vec<Option<f32>>,
+}
+
+/// YIN estimator (de Cheveigné & Kawahara, 2002) over mono audio.
+pub fn track_pitch(samples: &[f32], sample_rate: u32) -> PitchTrack {
+    let rate = sample_rate.max(1) as usize;
+    let hop = (rate * HOP_MS as usize / 1000).max(1);
+    let window = rate * WINDOW_MS / 1000;
+    let tau_min = (rate as f32 / MAX_F0_HZ).floor() as usize;
+    let tau_max = (rate as f32 / MIN_F0_HZ).ceil() as usize;
+    let frame_len = window + tau_max;
+
+    let mut f0_hz = Vec::new();
+    let mut diff = vec![0.0f32; tau_max + 1];
+    let mut start = 0;
+    while start + frame_len <= samples.len() {
+        let frame = &samples[start..start + frame_len];
+        f0_hz.push(if dsp::rms(&frame[..window]) < MIN_VOICED_RMS {
+            None
+        } else {
+            yin_period(frame, window, tau_min, tau_max, &mut diff)
+                .map(|period| rate as f32 / period)
+        });
+        start += hop;
+    }
+    PitchTrack {
+        hop_ms: HOP_MS,
+        f0_hz,
+    }
+}
+
+fn yin_period(
+    frame: &[f32],
+    window: usize,
+    tau_min: usize,
+    tau_max: usize,
+    diff: &mut [f32],
+) -> Option<f32> {
+    diff[0] = 1.0;
+    let mut running = 0.0f32;
+    for tau in 1..=tau_max {
+        let mut sum = 0.0f32;
+        for (a, b) in frame[..window].iter().zip(&frame[tau..tau + window]) {
+            let delta = a - b;
+            sum += delta * delta;
+        }
+        running += sum;
+        // Cumulative mean normalized difference.
+        diff[tau] = if running > 0.0 {
+            sum * tau as f32 / running
+        } else {
+            1.0
+        };
+    }
+
+    let mut tau = tau_min.max(2);
+    while tau < tau_max {
+        if diff[tau] < YIN_THRESHOLD {
+            while tau + 1 < tau_max && diff[tau + 1] < diff[tau] {
+                tau += 1;
+            }
+            let (prev, next) = (diff[tau - 1], diff[tau + 1]);
+            let denominator = prev + next - 2.0 * diff[tau];
+            let shift = if denominator.abs() > f32::EPSILON {
+                (prev - next) / (2.0 * denominator)
+            } else {
+                0.0
+            };
+            return Some(tau as f32 + shift.clamp(-1.0, 1.0));
+        }
+        tau += 1;
+    }
+    None
+}
+
+pub fn summarize_pitch(track: &PitchTrack) -> models::PitchSummary {
+    let voiced: Vec<f32> = track.f0_hz.iter().flatten().copied().collect();
+    let voiced_ratio = if track.f0_hz.is_empty() {
+        0.0
+    } else {
+        voiced.len() as f64 / track.f0_hz.len() as f64
+    };
+    let (median_hz, range_st, variability_st) = voiced_stats(&voiced).unwrap_or((0.0, 0.0, 0.0));
+    models::PitchSummary {
+        voiced_ratio: round2(voiced_ratio),
+        median_hz,
+        range_st,
+        variability_st,
+    }
+}
+
+/// Pitch spread of each transcript span. Spans with too little voicing to
+/// judge carry no statistics and are never flagged.
+pub fn segment_pitch_stats(
+    track: &PitchTrack,
+    spans: &[(i64, i64)],
+) -> Vec<models::PitchSegmentStats> {
+    spans
+        .iter()
+        .map(|&(t_start_ms, t_end_ms)| {
+            let from = (t_start_ms.max(0) / track.hop_ms) as usize;
+            let to = ((t_end_ms.max(0) / track.hop_ms) as usize).min(track.f0_hz.len());
+            let voiced: Vec<f32> = track
+                .f0_hz
+                .get(from..to.max(from))
+                .unwrap_or(&[])
+                .iter()
+                .flatten()
+                .copied()
+                .collect();
+            let stats = (voiced.len() >= MIN_SEGMENT_VOICED_FRAMES)
+                .then(|| voiced_stats(&voiced))
+                .flatten();
+            models::PitchSegmentStats {
+                t_start_ms,
+                t_end_ms,
+                median_hz: stats.map(|(median, _, _)| median),
+                range_st: stats.map(|(_, range, _)| range),
+                variability_st: stats.map(|(_, _, variability)| variability),
+                flat: stats.is_some_and(|(_, range, _)| range < FLAT_RANGE_ST),
+            }
+        })
+        .collect()
+}
+
+/// Merges consecutive flat segments into stretches.
+pub fn flat_stretches(segments: &[models::PitchSegmentStats]) -> Vec<(i64, i64)> {
+    let mut stretches: Vec<(i64, i64)> = Vec::new();
+    let mut extend = false;
+    for segment in segments {
+        if segment.range_st.is_none() {
+            continue;
+        }
+        if !segment.flat {
+            extend = false;
+            continue;
+        }
+        match stretches.last_mut() {
+            Some(last) if extend => last.1 = segment.t_end_ms,
+            _ => stretches.push((segment.t_start_ms, segment.t_end_ms)),
+        }
+        extend = true;
+    }
+    stretches
+}
+
+/// Median in Hz, p10–p90 range and standard deviation, both in semitones
+/// around the median.
+fn voiced_stats(voiced: &[f32]) -> Option<(f64, f64, f64)> {
+    if voiced.is_empty() {
+        return None;
+    }
+    let mut sorted = voiced.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let at = |p: usize| sorted[(sorted.len() * p / 100).min(sorted.len() - 1)] as f64;
+    let median = at(50);
+    let semitones: Vec<f64> = voiced
+        .iter()
+        .map(|f0| 12.0 * (*f0 as f64 / median).log2())
+        .collect();
+    let mean = semitones.iter().sum::<f64>() / semitones.len() as f64;
+    let variance = semitones
+        .iter()
+        .map(|st| (st - mean) * (st - mean))
+        .sum::<f64>()
+        / semitones.len() as f64;
+    Some((
+        round2(median),
+        round2(12.0 * (at(90) / at(10)).log2()),
+        round2(variance.sqrt()),
+    ))
+}
+
+fn round2(value: f64) -> f64 {
+    (value * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glide(ms: usize, from_hz: f32, to_hz: f32) -> Vec<f32> {
+        let len = ms * 16;
+        let mut phase = 0.0f32;
+        (0..len)
+            .map(|i| {
+                let f0 = from_hz + (to_hz - from_hz) * i as f32 / len as f32;
+                phase += 2.0 * std::f32::consts::PI * f0 / 16_000.0;
+                0.4 * phase.sin() + 0.2 * (2.0 * phase).sin()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn yin_recovers_steady_pitch() {
+        for hz in [110.0, 180.0, 260.0] {
+            let track = track_pitch(&glide(1_000, hz, hz), 16_000);
+            let voiced: Vec<f32> = track.f0_hz.iter().flatten().copied().collect();
+            assert!(voiced.len() as f32 > track.f0_hz.len() as f32 * 0.9);
+            assert!(voiced.iter().all(|f0| (f0 - hz).abs() < hz * 0.02), "{hz}");
+        }
+    }
+
+    #[test]
+    fn silence_is_unvoiced() {
+        let track = track_pitch(&vec![0.0; 16_000], 16_000);
+        assert!(!track.f0_hz.is_empty());
+        assert!(track.f0_hz.iter().all(Option::is_none));
+        assert_eq!(summarize_pitch(&track).voiced_ratio, 0.0);
+    }
+
+    #[test]
+    fn flat_segments_are_flagged_and_merged() {
+        let mut samples = glide(1_500, 150.0, 150.0);
+        samples.extend(glide(1_500, 152.0, 150.0));
+        samples.extend(glide(1_500, 120.0, 220.0));
+        let track = track_pitch(&samples, 16_000);
+        let stats = segment_pitch_stats(&track, &[(0, 1_500), (1_500, 3_000), (3_000, 4_500)]);
+        assert!(stats[0].flat && stats[1].flat);
+        assert!(!stats[2].flat);
+        assert!(stats[2].range_st.expect("range") > 6.0);
+        assert_eq!(flat_stretches(&stats), vec![(0, 3_000)]);
+
+        let summary = summarize_pitch(&track);
+        assert!((summary.median_hz - 150.0).abs() < 5.0);
+    }
+}
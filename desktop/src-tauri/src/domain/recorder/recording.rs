@@ -2,6 +2,6 @@ mod resampler;
 mod ring_buffer;
 mod wav_writer;
 
-pub use resampler::LinearResampler;
+pub use resampler::{LinearResampler, Resampler, ResamplerKind, SincResampler};
 pub use ring_buffer::RingBuffer;
-pub use wav_writer::WavWriter;
+pub use wav_writer::{repair_wav_header, WavWriter};
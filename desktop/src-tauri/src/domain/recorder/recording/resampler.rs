@@ -1,3 +1,66 @@
+const SINC_ZERO_CROSSINGS: f64 = 16.0;
+const SINC_ROLLOFF: f64 = 0.9;
+const SINC_PHASES: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResamplerKind {
+    Linear,
+    Sinc,
+}
+
+impl ResamplerKind {
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim().to_ascii_lowercase().as_str() {
+            "linear" => Some(Self::Linear),
+            "sinc" => Some(Self::Sinc),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Linear => "linear",
+            Self::Sinc => "sinc",
+        }
+    }
+}
+
+pub enum Resampler {
+    Linear(LinearResampler),
+    Sinc(SincResampler),
+}
+
+impl Resampler {
+    pub fn new(kind: ResamplerKind, input_rate: u32, target_rate: u32) -> Self {
+        match kind {
+            ResamplerKind::Linear => Self::Linear(LinearResampler::new(input_rate, target_rate)),
+            ResamplerKind::Sinc => Self::Sinc(SincResampler::new(input_rate, target_rate)),
+        }
+    }
+
+    pub fn kind(&self) -> ResamplerKind {
+        match self {
+            Self::Linear(_) => ResamplerKind::Linear,
+            Self::Sinc(_) => ResamplerKind::Sinc,
+        }
+    }
+
+    pub fn process(&mut self, input: &[f32]) -> Vec<f32> {
+        match self {
+            Self::Linear(resampler) => resampler.process(input),
+            Self::Sinc(resampler) => resampler.process(input),
+        }
+    }
+
+    /// Emits whatever the resampler still holds back; used at end of file.
+    pub fn flush(&mut self) -> Vec<f32> {
+        match self {
+            Self::Linear(_) => Vec::new(),
+            Self::Sinc(resampler) => resampler.flush(),
+        }
+    }
+}
+
 pub struct LinearResampler {
     input_rate: u32,
     target_rate: u32,
@@ -62,6 +125,139 @@ impl LinearResampler {
     }
 }
 
+/// Streaming windowed-sinc (Blackman) resampler. The low-pass cutoff sits just
+/// below the lower of the two Nyquist frequencies, and kernels are read from a
+/// polyphase table interpolated between neighbouring phases.
+pub struct SincResampler {
+    input_rate: u32,
+    target_rate: u32,
+    half_width: usize,
+    table: Vec<f32>,
+    buffer: Vec<f32>,
+    // Next output position in the buffer: `index + remainder / target_rate`,
+    // kept exact so chunk boundaries never drift.
+    index: usize,
+    remainder: u32,
+}
+
+impl SincResampler {
+    pub fn new(input_rate: u32, target_rate: u32) -> Self {
+        let input_rate = input_rate.max(1);
+        let target_rate = target_rate.max(1);
+        let cutoff = 0.5 * (target_rate as f64 / input_rate as f64).min(1.0) * SINC_ROLLOFF;
+        let half_width = (SINC_ZERO_CROSSINGS / (2.0 * cutoff)).ceil() as usize;
+        let taps = 2 * half_width;
+
+        let mut table = Vec::with_capacity((SINC_PHASES + 1) * taps);
+        for phase in 0..=SINC_PHASES {
+            let frac = phase as f64 / SINC_PHASES as f64;
+            for tap in 0..taps {
+                let x = tap as f64 - (half_width as f64 - 1.0) - frac;
+                table.push(windowed_sinc(x, cutoff, half_width as f64) as f32);
+            }
+        }
+
+        Self {
+            input_rate,
+            target_rate,
+            half_width,
+            table,
+            buffer: vec![0.0; half_width],
+            index: half_width,
+            remainder: 0,
+        }
+    }
+
+    /// Input samples held back before the first output can be produced.
+    pub fn latency_samples(&self) -> usize {
+        if self.input_rate == self.target_rate {
+            0
+        } else {
+            self.half_width
+        }
+    }
+
+    pub fn process(&mut self, input: &[f32]) -> Vec<f32> {
+        if self.input_rate == self.target_rate {
+            return input.to_vec();
+        }
+        self.buffer.extend_from_slice(input);
+        let output = self.drain_ready(usize::MAX);
+        self.compact();
+        output
+    }
+
+    pub fn flush(&mut self) -> Vec<f32> {
+        if self.input_rate == self.target_rate {
+            return Vec::new();
+        }
+        let end = self.buffer.len();
+        self.buffer
+            .extend(std::iter::repeat_n(0.0, self.half_width));
+        let output = self.drain_ready(end);
+        self.buffer.clear();
+        self.buffer.resize(self.half_width, 0.0);
+        self.index = self.half_width;
+        self.remainder = 0;
+        output
+    }
+
+    fn drain_ready(&mut self, end: usize) -> Vec<f32> {
+        let taps = 2 * self.half_width;
+        let step = (self.input_rate / self.target_rate) as usize;
+        let step_remainder = self.input_rate % self.target_rate;
+        let mut output = Vec::new();
+        while self.index < end && self.index + self.half_width < self.buffer.len() {
+            let center = self.index;
+            let scaled = self.remainder as f64 / self.target_rate as f64 * SINC_PHASES as f64;
+            let phase = (scaled.floor() as usize).min(SINC_PHASES - 1);
+            let blend = (scaled - phase as f64) as f32;
+            let lower = &self.table[phase * taps..(phase + 1) * taps];
+            let upper = &self.table[(phase + 1) * taps..(phase + 2) * taps];
+            let window = &self.buffer[center + 1 - self.half_width..=center + self.half_width];
+            let mut sample = 0.0f32;
+            for ((value, low), high) in window.iter().zip(lower).zip(upper) {
+                sample += value * (low + (high - low) * blend);
+            }
+            output.push(sample);
+            self.index += step;
+            self.remainder += step_remainder;
+            if self.remainder >= self.target_rate {
+                self.remainder -= self.target_rate;
+                self.index += 1;
+            }
+        }
+        output
+    }
+
+    fn compact(&mut self) {
+        let drop = self
+            .index
+            .saturating_sub(self.half_width)
+            .min(self.buffer.len());
+        if drop > 0 {
+            self.buffer.drain(0..drop);
+            self.index -= drop;
+        }
+    }
+}
+
+fn windowed_sinc(x: f64, cutoff: f64, half_width: f64) -> f64 {
+    if x.abs() >= half_width {
+        return 0.0;
+    }
+    let sinc = if x.abs() < 1e-9 {
+        1.0
+    } else {
+        let arg = std::f64::consts::PI * 2.0 * cutoff * x;
+        arg.sin() / arg
+    };
+    let n = (x + half_width) / (2.0 * half_width);
+    let window = 0.42 - 0.5 * (2.0 * std::f64::consts::PI * n).cos()
+        + 0.08 * (4.0 * std::f64::consts::PI * n).cos();
+    2.0 * cutoff * sinc * window
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,4 +291,90 @@ mod tests {
             }
         }
     }
+
+    fn sine(freq: f32, rate: u32, len: usize) -> Vec<f32> {
+        (0..len)
+            .map(|i| 0.5 * (2.0 * std::f32::consts::PI * freq * i as f32 / rate as f32).sin())
+            .collect()
+    }
+
+    fn steady_rms(samples: &[f32]) -> f32 {
+        let body = &samples[samples.len() / 4..samples.len() * 3 / 4];
+        (body.iter().map(|v| v * v).sum::<f32>() / body.len() as f32).sqrt()
+    }
+
+    #[test]
+    fn sinc_rejects_tones_above_target_nyquist() {
+        for (rate, tone) in [(48_000, 10_000.0), (44_100, 9_000.0)] {
+            let input = sine(tone, rate, rate as usize / 2);
+            let linear = LinearResampler::new(rate, 16_000).process(&input);
+            let mut sinc = SincResampler::new(rate, 16_000);
+            let mut band_limited = sinc.process(&input);
+            band_limited.extend(sinc.flush());
+
+            // Linear interpolation folds the tone back into the speech band
+            // almost unattenuated; the sinc filter keeps it below -50 dB.
+            assert!(steady_rms(&linear) > 0.2, "{rate}");
+            assert!(steady_rms(&band_limited) < 0.35 * 0.003, "{rate}");
+        }
+    }
+
+    #[test]
+    fn sinc_keeps_speech_band_level() {
+        let input = sine(1_000.0, 48_000, 24_000);
+        let mut sinc = SincResampler::new(48_000, 16_000);
+        let output = sinc.process(&input);
+        assert!((steady_rms(&output) - 0.5 / 2f32.sqrt()).abs() < 0.005);
+    }
+
+    #[test]
+    fn sinc_latency_is_bounded_and_output_is_time_aligned() {
+        let mut input = vec![0.0f32; 4_800];
+        input[1_500] = 1.0;
+        let linear = LinearResampler::new(48_000, 16_000).process(&input);
+        let mut sinc = SincResampler::new(48_000, 16_000);
+        let held_back = sinc.latency_samples();
+        let mut output = sinc.process(&input);
+        let streamed = output.len();
+        output.extend(sinc.flush());
+
+        let peak = |samples: &[f32]| {
+            samples
+                .iter()
+                .enumerate()
+                .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+                .map(|(index, _)| index)
+        };
+        assert_eq!(peak(&linear), Some(500));
+        assert_eq!(peak(&output), Some(500));
+        assert_eq!(output.len(), 1_600);
+        assert!(held_back as f32 / 48.0 < 2.0);
+        assert!(linear.len() - streamed <= held_back / 3 + 1);
+    }
+
+    #[test]
+    fn sinc_output_length_is_chunking_independent() {
+        let input = sine(440.0, 44_100, 44_100);
+        let mut whole = SincResampler::new(44_100, 16_000);
+        let mut expected = whole.process(&input);
+        expected.extend(whole.flush());
+
+        let mut chunked = SincResampler::new(44_100, 16_000);
+        let mut output = Vec::new();
+        for chunk in input.chunks(441) {
+            output.extend(chunked.process(chunk));
+        }
+        output.extend(chunked.flush());
+        assert_eq!(output.len(), expected.len());
+        assert!(output
+            .iter()
+            .zip(&expected)
+            .all(|(a, b)| (a - b).abs() < 1e-5));
+        assert_eq!(
+            Resampler::new(ResamplerKind::Sinc, 8_000, 16_000)
+                .kind()
+                .code(),
+            "sinc"
+        );
+    }
 }
@@ -1,5 +1,7 @@
-use std::fs::File;
-use std::io::{Seek, SeekFrom, Write};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+const WAV_HEADER_BYTES: u64 = 44;
 
 pub struct WavWriter {
     file: File,
@@ -37,6 +39,21 @@ impl WavWriter {
         Ok(())
     }
 
+    pub fn data_bytes(&self) -> u64 {
+        self.data_bytes
+    }
+
+    /// Patches the header with the bytes written so far so the draft stays
+    /// playable if the process dies before `finalize`.
+    pub fn checkpoint(&mut self) -> Result<(), String> {
+        self.write_header(self.data_bytes)?;
+        self.file
+            .seek(SeekFrom::End(0))
+            .map_err(|e| format!("wav_seek: {e}"))?;
+        self.file.flush().map_err(|e| format!("wav_flush: {e}"))?;
+        Ok(())
+    }
+
     pub fn finalize(mut self) -> Result<(), String> {
         self.write_header(self.data_bytes)?;
         self.file.flush().map_err(|e| format!("wav_flush: {e}"))?;
@@ -93,3 +110,39 @@ impl WavWriter {
         Ok(())
     }
 }
+
+/// Rewrites the size fields of a PCM16 WAV draft from its on-disk length,
+/// dropping any trailing partial frame. Returns the recovered data bytes.
+pub fn repair_wav_header(path: &std::path::Path) -> Result<u64, String> {
+    let mut file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path)
+        .map_err(|e| format!("wav_open: {e}"))?;
+    let mut header = [0u8; WAV_HEADER_BYTES as usize];
+    file.read_exact(&mut header)
+        .map_err(|_| "wav_header".to_string())?;
+    if &header[0..4] != b"RIFF" || &header[8..12] != b"WAVE" || &header[36..40] != b"data" {
+        return Err("wav_header".to_string());
+    }
+    let block_align = u16::from_le_bytes([header[32], header[33]]).max(1) as u64;
+    let file_len = file
+        .metadata()
+        .map_err(|e| format!("wav_metadata: {e}"))?
+        .len();
+    let available = file_len.saturating_sub(WAV_HEADER_BYTES);
+    let data_bytes = (available - available % block_align).min(u32::MAX as u64 - 36);
+
+    file.set_len(WAV_HEADER_BYTES + data_bytes)
+        .map_err(|e| format!("wav_truncate: {e}"))?;
+    file.seek(SeekFrom::Start(4))
+        .map_err(|e| format!("wav_seek: {e}"))?;
+    file.write_all(&((36 + data_bytes) as u32).to_le_bytes())
+        .map_err(|e| format!("wav_header: {e}"))?;
+    file.seek(SeekFrom::Start(40))
+        .map_err(|e| format!("wav_seek: {e}"))?;
+    file.write_all(&(data_bytes as u32).to_le_bytes())
+        .map_err(|e| format!("wav_header: {e}"))?;
+    file.flush().map_err(|e| format!("wav_flush: {e}"))?;
+    Ok(data_bytes)
+}
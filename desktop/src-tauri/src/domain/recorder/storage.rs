@@ -0,0 +1,239 @@
+use super::{decode_audio_pcm16_mono_16k, encode_wav_pcm16_mono, flac};
+use crate::platform::{artifacts, db, preferences};
+use serde_json::Value;
+
+pub const STORAGE_FORMAT_SETTING_KEY: &str = "lepupitre.audio.storage_format";
+/// Unprocessed companion of a recording, linked through `raw_artifact_id`.
+pub const RAW_AUDIO_ARTIFACT_TYPE: &str = "audio_raw";
+const SAMPLE_RATE_HZ: u32 = 16_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioStorageFormat {
+    Wav,
+    Flac,
+}
+
+impl AudioStorageFormat {
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim().to_ascii_lowercase().as_str() {
+            "wav" => Some(Self::Wav),
+            "flac" => Some(Self::Flac),
+            _ => None,
+        }
+    }
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Wav => "wav",
+            Self::Flac => "flac",
+        }
+    }
+
+    pub fn encode(self, samples: &[i16]) -> Result<Vec<u8>, String> {
+        match self {
+            Self::Wav => encode_wav_pcm16_mono(SAMPLE_RATE_HZ, 1, samples),
+            Self::Flac => flac::encode_flac_pcm16_mono(SAMPLE_RATE_HZ, samples),
+        }
+    }
+
+    pub fn detect(bytes: &[u8]) -> Self {
+        if bytes.starts_with(b"fLaC") {
+            Self::Flac
+        } else {
+            Self::Wav
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AudioMigrationSummary {
+    pub converted: usize,
+    pub skipped: usize,
+    pub failed: Vec<String>,
+    pub bytes_before: u64,
+    pub bytes_after: u64,
+}
+
+pub fn load_storage_format(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+) -> Result<AudioStorageFormat, String> {
+    let raw = preferences::preference_profile_get(app, profile_id, STORAGE_FORMAT_SETTING_KEY)?;
+    Ok(parse_storage_format(raw.as_deref()))
+}
+
+fn parse_storage_format(raw: Option<&str>) -> AudioStorageFormat {
+    raw.map(|value| serde_json::from_str::<String>(value).unwrap_or_else(|_| value.to_string()))
+        .and_then(|value| AudioStorageFormat::from_code(&value))
+        .unwrap_or(AudioStorageFormat::Wav)
+}
+
+/// Metadata for a mono 16 kHz audio artifact stored in `format`, keeping any
+/// extra keys (trim provenance, recovery flags) from `extra`.
+pub fn audio_metadata(format: AudioStorageFormat, extra: &Value) -> Value {
+    let mut metadata = serde_json::json!({
+        "format": format.code(),
+        "sample_rate_hz": SAMPLE_RATE_HZ,
+        "channels": 1
+    });
+    if let (Some(target), Some(source)) = (metadata.as_object_mut(), extra.as_object()) {
+        for (key, value) in source {
+            if !matches!(key.as_str(), "format" | "sample_rate_hz" | "channels") {
+                target.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    metadata
+}
+
+/// Re-encodes a finalized WAV draft in place when the profile stores FLAC.
+/// The returned draft points at the file that should be registered.
+pub fn compress_draft(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    draft: artifacts::ArtifactDraft,
+    format: AudioStorageFormat,
+) -> Result<artifacts::ArtifactDraft, String> {
+    if format == AudioStorageFormat::Wav {
+        return Ok(draft);
+    }
+    let wav = std::fs::read(&draft.abspath).map_err(|e| format!("audio_read: {e}"))?;
+    let samples = decode_audio_pcm16_mono_16k(&wav)?;
+    let encoded = format.encode(&samples)?;
+
+    let relpath = format!(
+        "artifacts/{}/{}.{}",
+        draft.artifact_type,
+        draft.id,
+        format.code()
+    );
+    let abspath = db::profile_dir(app, profile_id)?.join(&relpath);
+    std::fs::write(&abspath, encoded).map_err(|e| format!("audio_write: {e}"))?;
+    std::fs::remove_file(&draft.abspath).map_err(|e| format!("audio_remove: {e}"))?;
+    Ok(artifacts::ArtifactDraft {
+        relpath,
+        abspath,
+        ..draft
+    })
+}
+
+/// Reports `(processed, total)` after each artifact through `on_progress`.
+pub fn migrate_audio_artifacts(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    format: AudioStorageFormat,
+    mut on_progress: impl FnMut(usize, usize),
+) -> Result<AudioMigrationSummary, String> {
+    db::ensure_profile_exists(app, profile_id)?;
+    let profile_dir = db::profile_dir(app, profile_id)?;
+    let mut summary = AudioMigrationSummary::default();
+
+    let mut entries = artifacts::list_artifacts(app, profile_id, "audio")?;
+    entries.extend(artifacts::list_artifacts(
+        app,
+        profile_id,
+        RAW_AUDIO_ARTIFACT_TYPE,
+    )?);
+    let total = entries.len();
+    for (index, entry) in entries.into_iter().enumerate() {
+        if index > 0 {
+            on_progress(index, total);
+        }
+        let path = profile_dir.join(&entry.relpath);
+        let bytes = match std::fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                summary
+                    .failed
+                    .push(format!("{}: audio_read: {err}", entry.id));
+                continue;
+            }
+        };
+        if AudioStorageFormat::detect(&bytes) == format {
+            summary.skipped += 1;
+            continue;
+        }
+
+        match convert_artifact(app, profile_id, &entry, &bytes, format) {
+            Ok(new_bytes) => {
+                summary.converted += 1;
+                summary.bytes_before += bytes.len() as u64;
+                summary.bytes_after += new_bytes;
+            }
+            Err(err) => summary.failed.push(format!("{}: {err}", entry.id)),
+        }
+    }
+    on_progress(total, total);
+    Ok(summary)
+}
+
+fn convert_artifact(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    entry: &artifacts::ArtifactEntry,
+    bytes: &[u8],
+    format: AudioStorageFormat,
+) -> Result<u64, String> {
+    let samples = decode_audio_pcm16_mono_16k(bytes)?;
+    let encoded = format.encode(&samples)?;
+    if decode_audio_pcm16_mono_16k(&encoded)? != samples {
+        return Err("audio_migration_mismatch".to_string());
+    }
+    let mut metadata = audio_metadata(format, &entry.metadata);
+    if let Some(object) = metadata.as_object_mut() {
+        object.insert(
+            "migrated_from".to_string(),
+            Value::String(AudioStorageFormat::detect(bytes).code().to_string()),
+        );
+    }
+    let record = artifacts::replace_artifact_content(
+        app,
+        profile_id,
+        &entry.id,
+        format.code(),
+        &encoded,
+        &metadata,
+    )?;
+    Ok(record.bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn storage_format_defaults_to_wav() {
+        assert_eq!(parse_storage_format(None), AudioStorageFormat::Wav);
+        assert_eq!(
+            parse_storage_format(Some("\"flac\"")),
+            AudioStorageFormat::Flac
+        );
+        assert_eq!(parse_storage_format(Some("FLAC")), AudioStorageFormat::Flac);
+        assert_eq!(
+            parse_storage_format(Some("\"ogg\"")),
+            AudioStorageFormat::Wav
+        );
+    }
+
+    #[test]
+    fn both_formats_decode_to_the_same_samples() {
+        let samples: Vec<i16> = (0..5_000)
+            .map(|i| ((i * 37) % 2_000 - 1_000) as i16)
+            .collect();
+        let wav = AudioStorageFormat::Wav.encode(&samples).expect("wav");
+        let flac = AudioStorageFormat::Flac.encode(&samples).expect("flac");
+        assert_eq!(AudioStorageFormat::detect(&wav), AudioStorageFormat::Wav);
+        assert_eq!(AudioStorageFormat::detect(&flac), AudioStorageFormat::Flac);
+        assert_eq!(decode_audio_pcm16_mono_16k(&wav).expect("wav"), samples);
+        assert_eq!(decode_audio_pcm16_mono_16k(&flac).expect("flac"), samples);
+    }
+
+    #[test]
+    fn metadata_keeps_provenance_but_overrides_format() {
+        let extra = serde_json::json!({"format": "wav", "trim_kind": "range_ms"});
+        let metadata = audio_metadata(AudioStorageFormat::Flac, &extra);
+        assert_eq!(metadata["format"], "flac");
+        assert_eq!(metadata["trim_kind"], "range_ms");
+        assert_eq!(metadata["sample_rate_hz"], 16_000);
+    }
+}
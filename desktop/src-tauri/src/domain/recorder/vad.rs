@@ -14,6 +14,14 @@ impl VadConfig {
             energy_threshold: 0.02,
         }
     }
+
+    pub fn pause_analysis(energy_threshold: f32) -> Self {
+        Self {
+            speech_start_ms: 100,
+            speech_end_ms: 250,
+            energy_threshold,
+        }
+    }
 }
 
 #[derive(Default)]
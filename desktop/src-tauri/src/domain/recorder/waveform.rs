@@ -0,0 +1,261 @@
+use crate::kernel::models;
+use crate::platform::artifacts;
+
+pub const WAVEFORM_ARTIFACT_TYPE: &str = "waveform";
+const SCHEMA_VERSION: &str = "1.0.0";
+const SAMPLE_RATE_HZ: u32 = 16_000;
+const BASE_BIN_SAMPLES: usize = 160;
+const LEVEL_FACTOR: usize = 4;
+const MIN_LEVEL_BINS: usize = 64;
+const MAX_QUERY_BINS: usize = 8_192;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WaveformRange {
+    pub start_ms: i64,
+    pub end_ms: i64,
+    pub duration_ms: i64,
+    pub bin_ms: f64,
+    pub peaks: Vec<f32>,
+}
+
+/// Peak pyramid: 10 ms bins, each coarser level merging four bins of the
+/// previous one until a level fits in a few dozen bins.
+pub fn build_peak_levels(samples: &[i16]) -> Vec<models::WaveformLevel> {
+    let base: Vec<u8> = samples
+        .chunks(BASE_BIN_SAMPLES)
+        .map(|chunk| {
+            let peak = chunk
+                .iter()
+                .map(|sample| sample.unsigned_abs())
+                .max()
+                .unwrap_or(0);
+            quantize_peak(peak)
+        })
+        .collect();
+    let mut levels = vec![models::WaveformLevel {
+        bin_samples: BASE_BIN_SAMPLES as u32,
+        peaks: base,
+    }];
+    while let Some(last) = levels.last() {
+        if last.peaks.len() <= MIN_LEVEL_BINS {
+            break;
+        }
+        let next = models::WaveformLevel {
+            bin_samples: last.bin_samples * LEVEL_FACTOR as u32,
+            peaks: last
+                .peaks
+                .chunks(LEVEL_FACTOR)
+                .map(|chunk| chunk.iter().copied().max().unwrap_or(0))
+                .collect(),
+        };
+        levels.push(next);
+    }
+    levels
+}
+
+/// Rounds up so quiet but non-silent bins stay visible.
+fn quantize_peak(peak: u16) -> u8 {
+    ((peak.min(32_768) as u32 * 255).div_ceil(32_768)) as u8
+}
+
+pub fn store_waveform_peaks(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: &str,
+    samples: &[i16],
+) -> Result<models::WaveformPeaksV1, String> {
+    let peaks = models::WaveformPeaksV1 {
+        schema_version: SCHEMA_VERSION.to_string(),
+        source_audio_artifact_id: audio_artifact_id.to_string(),
+        sample_rate_hz: SAMPLE_RATE_HZ,
+        sample_count: samples.len() as u64,
+        duration_ms: super::duration_ms_from_sample_count(samples.len(), SAMPLE_RATE_HZ),
+        levels: build_peak_levels(samples),
+    };
+    let bytes = serde_json::to_vec(&peaks).map_err(|e| format!("waveform_json: {e}"))?;
+    let metadata = serde_json::json!({
+        "source_audio_artifact_id": audio_artifact_id,
+        "duration_ms": peaks.duration_ms,
+        "levels": peaks.levels.len(),
+    });
+    artifacts::store_bytes(
+        app,
+        profile_id,
+        WAVEFORM_ARTIFACT_TYPE,
+        "json",
+        &bytes,
+        &metadata,
+    )?;
+    Ok(peaks)
+}
+
+/// Peak data of an audio artifact, computed and stored on first use for
+/// recordings made before peaks were persisted.
+pub fn ensure_waveform_peaks(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: &str,
+) -> Result<models::WaveformPeaksV1, String> {
+    if let Some(peaks) = load_waveform_peaks(app, profile_id, audio_artifact_id)? {
+        return Ok(peaks);
+    }
+    let artifact = artifacts::get_artifact(app, profile_id, audio_artifact_id)?;
+    if artifact.artifact_type != "audio" {
+        return Err("artifact_not_audio".to_string());
+    }
+    let path = artifacts::resolve_profile_relpath_for_read(app, profile_id, &artifact.relpath)?;
+    let bytes = std::fs::read(path).map_err(|e| format!("audio_read: {e}"))?;
+    let samples = super::decode_audio_pcm16_mono_16k(&bytes)?;
+    store_waveform_peaks(app, profile_id, audio_artifact_id, &samples)
+}
+
+/// Called right after an audio artifact is finalized; a failure only costs
+/// the lazy computation on first display.
+pub fn index_waveform_peaks(app: &tauri::AppHandle, profile_id: &str, audio_artifact_id: &str) {
+    if let Err(err) = ensure_waveform_peaks(app, profile_id, audio_artifact_id) {
+        eprintln!("waveform peaks not stored for {audio_artifact_id}: {err}");
+    }
+}
+
+fn load_waveform_peaks(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    audio_artifact_id: &str,
+) -> Result<Option<models::WaveformPeaksV1>, String> {
+    let stored = artifacts::find_audio_derived::<models::WaveformPeaksV1>(
+        app,
+        profile_id,
+        WAVEFORM_ARTIFACT_TYPE,
+        audio_artifact_id,
+    )?;
+    Ok(stored
+        .map(|(_, peaks)| peaks)
+        .filter(|peaks| peaks.schema_version == SCHEMA_VERSION))
+}
+
+/// Resamples the stored pyramid to `bins` peaks over `[start_ms, end_ms)`,
+/// reading from the coarsest level that still has one bin per output bin.
+pub fn waveform_range(
+    peaks: &models::WaveformPeaksV1,
+    start_ms: Option<i64>,
+    end_ms: Option<i64>,
+    bins: usize,
+) -> Result<WaveformRange, String> {
+    if bins == 0 || bins > MAX_QUERY_BINS {
+        return Err("waveform_bins_invalid".to_string());
+    }
+    let start_ms = start_ms.unwrap_or(0).max(0);
+    let end_ms = end_ms.unwrap_or(peaks.duration_ms).min(peaks.duration_ms);
+    if start_ms >= end_ms {
+        return Err("waveform_range_invalid".to_string());
+    }
+
+    let rate = peaks.sample_rate_hz.max(1) as u64;
+    let start = start_ms as u64 * rate / 1000;
+    let end = (end_ms as u64 * rate)
+        .div_ceil(1000)
+        .min(peaks.sample_count);
+    let span = end.saturating_sub(start).max(1);
+    let Some(level) = peaks
+        .levels
+        .iter()
+        .rev()
+        .find(|level| level.bin_samples as u64 * bins as u64 <= span)
+        .or(peaks.levels.first())
+    else {
+        return Err("waveform_empty".to_string());
+    };
+
+    let bin_samples = level.bin_samples.max(1) as u64;
+    let values = (0..bins as u64)
+        .map(|idx| {
+            let from = start + span * idx / bins as u64;
+            let to = (start + span * (idx + 1) / bins as u64).max(from + 1);
+            let first = (from / bin_samples) as usize;
+            let last = (to.div_ceil(bin_samples) as usize).max(first + 1);
+            level
+                .peaks
+                .get(first..last.min(level.peaks.len()))
+                .and_then(|slice| slice.iter().copied().max())
+                .map(|peak| peak as f32 / 255.0)
+                .unwrap_or(0.0)
+        })
+        .collect();
+
+    Ok(WaveformRange {
+        start_ms,
+        end_ms,
+        duration_ms: peaks.duration_ms,
+        bin_ms: (end_ms - start_ms) as f64 / bins as f64,
+        peaks: values,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pyramid(samples: &[i16]) -> models::WaveformPeaksV1 {
+        models::WaveformPeaksV1 {
+            schema_version: SCHEMA_VERSION.to_string(),
+            source_audio_artifact_id: "audio_1".to_string(),
+            sample_rate_hz: SAMPLE_RATE_HZ,
+            sample_count: samples.len() as u64,
+            duration_ms: super::super::duration_ms_from_sample_count(samples.len(), SAMPLE_RATE_HZ),
+            levels: build_peak_levels(samples),
+        }
+    }
+
+    #[test]
+    fn levels_shrink_by_four_and_keep_maxima() {
+        let mut samples = vec![100i16; 16_000 * 60];
+        samples[500_000] = i16::MIN;
+        let levels = build_peak_levels(&samples);
+        assert_eq!(levels[0].bin_samples, 160);
+        assert_eq!(levels[0].peaks.len(), 6_000);
+        assert_eq!(levels[1].peaks.len(), 1_500);
+        assert!(levels.last().expect("level").peaks.len() <= MIN_LEVEL_BINS);
+        for level in &levels {
+            assert_eq!(level.peaks.iter().copied().max(), Some(255));
+            assert_eq!(level.peaks.iter().copied().min(), Some(1));
+        }
+    }
+
+    #[test]
+    fn range_query_picks_matching_resolution() {
+        let mut samples = vec![0i16; 16_000 * 10];
+        samples[64_000..76_800].fill(16_384);
+        let peaks = pyramid(&samples);
+
+        let whole = waveform_range(&peaks, None, None, 10).expect("whole");
+        assert_eq!((whole.start_ms, whole.end_ms), (0, 10_000));
+        assert_eq!(whole.bin_ms, 1_000.0);
+        let loud: Vec<usize> = whole
+            .peaks
+            .iter()
+            .enumerate()
+            .filter(|(_, peak)| **peak > 0.0)
+            .map(|(idx, _)| idx)
+            .collect();
+        assert_eq!(loud, vec![4]);
+        assert!((whole.peaks[4] - 0.5).abs() < 0.01);
+
+        let zoomed = waveform_range(&peaks, Some(4_700), Some(4_900), 20).expect("zoomed");
+        assert_eq!(zoomed.peaks.len(), 20);
+        assert!(zoomed.peaks[..10].iter().all(|peak| *peak > 0.0));
+        assert!(zoomed.peaks[10..].iter().all(|peak| *peak == 0.0));
+    }
+
+    #[test]
+    fn range_query_rejects_bad_input() {
+        let peaks = pyramid(&[0i16; 16_000]);
+        assert_eq!(
+            waveform_range(&peaks, None, None, 0).expect_err("bins"),
+            "waveform_bins_invalid"
+        );
+        assert_eq!(
+            waveform_range(&peaks, Some(2_000), None, 10).expect_err("range"),
+            "waveform_range_invalid"
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use super::repo;
 use super::types::RunAnalyzeResponse;
 use crate::domain::asr::transcript;
-use crate::domain::feedback::analysis;
+use crate::domain::feedback::{self, analysis, analyzers};
 use crate::kernel::{ids, time};
 use crate::platform::artifacts;
 use crate::platform::db;
@@ -11,18 +11,38 @@ pub fn run_analyze(
     app: &AppHandle,
     profile_id: &str,
     run_id: &str,
+) -> Result<RunAnalyzeResponse, String> {
+    analyze_run(app, profile_id, run_id, false)
+}
+
+pub fn run_reanalyze(
+    app: &AppHandle,
+    profile_id: &str,
+    run_id: &str,
+) -> Result<RunAnalyzeResponse, String> {
+    analyze_run(app, profile_id, run_id, true)
+}
+
+fn analyze_run(
+    app: &AppHandle,
+    profile_id: &str,
+    run_id: &str,
+    force: bool,
 ) -> Result<RunAnalyzeResponse, String> {
     db::ensure_profile_exists(app, profile_id)?;
     let mut conn = db::open_profile(app, profile_id)?;
 
-    let (transcript_id, existing_feedback_id) = repo::select_run_analysis_state(&conn, run_id)?;
-    if let Some(feedback_id) = existing_feedback_id {
-        return Ok(RunAnalyzeResponse { feedback_id });
+    let state = repo::select_run_analysis_state(&conn, run_id)?;
+    if let Some(feedback_id) = state.feedback_id.as_deref().filter(|_| !force) {
+        return Ok(RunAnalyzeResponse {
+            feedback_id: feedback_id.to_string(),
+        });
     }
 
-    let transcript_id = transcript_id.ok_or_else(|| "run_missing_transcript".to_string())?;
+    let transcript_id = state
+        .transcript_id
+        .ok_or_else(|| "run_missing_transcript".to_string())?;
     let transcript = transcript::load_transcript(app, profile_id, &transcript_id)?;
-    let text = transcript::transcript_text(&transcript)?;
     let estimated_sec = transcript::transcript_duration_ms(&transcript)
         .and_then(|ms| {
             if ms > 0 {
@@ -33,11 +53,29 @@ pub fn run_analyze(
         })
         .unwrap_or(600);
 
-    let feedback = analysis::build_feedback_from_text(&text, estimated_sec);
+    let talk = repo::select_run_talk_target(&conn, run_id)?;
+    let analyzers = analyzers::enabled_for_profile(app, profile_id)?;
+    let signals = feedback::audio_signals(
+        app,
+        profile_id,
+        state.audio_artifact_id.as_deref(),
+        &transcript.segments,
+        ("run", run_id),
+        analyzers::needs_pitch(&analyzers),
+    );
+    let lexicon = feedback::profile_lexicon(&conn, &transcript.language)?;
+    let mut input = analyzers::AnalysisInput::from_transcript(&transcript, estimated_sec, lexicon)?;
+    input.audio_pauses = signals.pauses.as_deref();
+    input.audio_loudness = signals.loudness.as_ref();
+    input.audio_pitch = signals.pitch.as_ref();
+    input.talk = Some(&talk);
+    let feedback = analysis::build_feedback(&input, &analyzers);
     let feedback_json = serde_json::to_vec(&feedback).map_err(|e| format!("feedback_json: {e}"))?;
     let metadata = serde_json::json!({
         "source": "transcript",
         "run_id": run_id,
+        "transcript_id": transcript_id,
+        "supersedes_feedback_id": state.feedback_id,
     });
     let record = artifacts::store_bytes(
         app,
@@ -5,7 +5,9 @@ mod repo;
 mod types;
 mod writes;
 
-pub use analyze::run_analyze;
+pub use analyze::{run_analyze, run_reanalyze};
 pub use reads::{run_get, run_get_latest, run_list};
 pub use types::{RunAnalyzeResponse, RunSummary};
-pub use writes::{run_create, run_finish, run_set_transcript};
+pub use writes::{
+    run_create, run_create_from_edit, run_create_with_audio, run_finish, run_set_transcript,
+};
@@ -1,6 +1,18 @@
 pub(super) const INSERT_RUN: &str =
     "INSERT INTO runs (id, project_id, created_at) VALUES (?1, ?2, ?3)";
 
+pub(super) const INSERT_RUN_WITH_AUDIO: &str =
+    "INSERT INTO runs (id, project_id, created_at, audio_artifact_id) VALUES (?1, ?2, ?3, ?4)";
+
+/// Copies the project of the latest run recorded with `?5`.
+pub(super) const INSERT_RUN_FROM_SOURCE_AUDIO: &str =
+    "INSERT INTO runs (id, project_id, created_at, audio_artifact_id, transcript_id)
+ SELECT ?1, project_id, ?2, ?3, ?4
+ FROM runs
+ WHERE audio_artifact_id = ?5
+ ORDER BY created_at DESC
+ LIMIT 1";
+
 pub(super) const UPDATE_RUN_AUDIO: &str = "UPDATE runs SET audio_artifact_id = ?1 WHERE id = ?2";
 
 pub(super) const UPDATE_RUN_TRANSCRIPT: &str = "UPDATE runs SET transcript_id = ?1 WHERE id = ?2";
@@ -25,7 +37,13 @@ pub(super) const SELECT_RUNS_BY_PROJECT: &str =
  LIMIT ?2";
 
 pub(super) const SELECT_RUN_ANALYSIS_STATE: &str =
-    "SELECT transcript_id, feedback_id FROM runs WHERE id = ?1";
+    "SELECT transcript_id, audio_artifact_id, feedback_id FROM runs WHERE id = ?1";
+
+pub(super) const SELECT_RUN_TALK_TARGET: &str = "SELECT p.duration_target_sec, o.outline_md
+ FROM runs r
+ JOIN talk_projects p ON p.id = r.project_id
+ LEFT JOIN talk_outlines o ON o.project_id = r.project_id
+ WHERE r.id = ?1";
 
 pub(super) const INSERT_RUN_FEEDBACK: &str = "INSERT INTO auto_feedback (id, subject_type, subject_id, created_at, feedback_json_artifact_id, overall_score)
  VALUES (?1, ?2, ?3, ?4, ?5, ?6)";
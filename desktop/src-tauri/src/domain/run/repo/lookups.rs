@@ -1,5 +1,6 @@
 use super::super::queries;
 use super::super::types::RunSummary;
+use crate::domain::feedback::analysis::TalkTarget;
 use rusqlite::{params, Connection, ErrorCode, OptionalExtension, Row};
 
 pub(in crate::domain::run) fn select_latest_run(
@@ -42,16 +43,39 @@ pub(in crate::domain::run) fn select_runs(
     Ok(runs)
 }
 
+pub(in crate::domain::run) struct RunAnalysisState {
+    pub(in crate::domain::run) transcript_id: Option<String>,
+    pub(in crate::domain::run) audio_artifact_id: Option<String>,
+    pub(in crate::domain::run) feedback_id: Option<String>,
+}
+
 pub(in crate::domain::run) fn select_run_analysis_state(
     conn: &Connection,
     run_id: &str,
-) -> Result<(Option<String>, Option<String>), String> {
+) -> Result<RunAnalysisState, String> {
     conn.query_row(queries::SELECT_RUN_ANALYSIS_STATE, params![run_id], |row| {
-        Ok((row.get(0)?, row.get(1)?))
+        Ok(RunAnalysisState {
+            transcript_id: row.get(0)?,
+            audio_artifact_id: row.get(1)?,
+            feedback_id: row.get(2)?,
+        })
     })
     .map_err(|e| format!("run_lookup: {e}"))
 }
 
+pub(in crate::domain::run) fn select_run_talk_target(
+    conn: &Connection,
+    run_id: &str,
+) -> Result<TalkTarget, String> {
+    conn.query_row(queries::SELECT_RUN_TALK_TARGET, params![run_id], |row| {
+        Ok(TalkTarget {
+            duration_target_sec: row.get(0)?,
+            outline_md: row.get(1)?,
+        })
+    })
+    .map_err(|e| format!("run_talk_lookup: {e}"))
+}
+
 pub(in crate::domain::run) fn ensure_project_exists(
     conn: &Connection,
     project_id: &str,
@@ -3,8 +3,9 @@ mod writes;
 
 pub(super) use lookups::{
     ensure_project_exists, is_audio_notnull_error, select_latest_run, select_run,
-    select_run_analysis_state, select_runs,
+    select_run_analysis_state, select_run_talk_target, select_runs,
 };
 pub(super) use writes::{
-    insert_run, persist_run_feedback_link, update_run_audio, update_run_transcript,
+    insert_run, insert_run_from_source_audio, insert_run_with_audio, persist_run_feedback_link,
+    update_run_audio, update_run_transcript,
 };
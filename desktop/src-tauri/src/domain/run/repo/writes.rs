@@ -11,6 +11,43 @@ pub(in crate::domain::run) fn insert_run(
     Ok(())
 }
 
+pub(in crate::domain::run) fn insert_run_with_audio(
+    conn: &Connection,
+    run_id: &str,
+    project_id: &str,
+    created_at: &str,
+    audio_artifact_id: &str,
+) -> Result<(), String> {
+    conn.execute(
+        queries::INSERT_RUN_WITH_AUDIO,
+        params![run_id, project_id, created_at, audio_artifact_id],
+    )
+    .map(|_| ())
+    .map_err(|e| format!("run_insert: {e}"))
+}
+
+pub(in crate::domain::run) fn insert_run_from_source_audio(
+    conn: &Connection,
+    run_id: &str,
+    created_at: &str,
+    audio_artifact_id: &str,
+    transcript_id: Option<&str>,
+    source_audio_artifact_id: &str,
+) -> Result<bool, String> {
+    conn.execute(
+        queries::INSERT_RUN_FROM_SOURCE_AUDIO,
+        params![
+            run_id,
+            created_at,
+            audio_artifact_id,
+            transcript_id,
+            source_audio_artifact_id
+        ],
+    )
+    .map(|inserted| inserted > 0)
+    .map_err(|e| format!("run_insert: {e}"))
+}
+
 pub(in crate::domain::run) fn update_run_audio(
     conn: &Connection,
     run_id: &str,
@@ -112,4 +149,61 @@ mod tests {
             .expect("count");
         assert_eq!(feedback_count, 0);
     }
+
+    #[test]
+    fn edited_audio_joins_the_project_of_its_source_run() {
+        let conn = Connection::open_in_memory().expect("open");
+        conn.execute_batch(
+            "CREATE TABLE runs (
+               id TEXT PRIMARY KEY,
+               project_id TEXT NOT NULL,
+               created_at TEXT NOT NULL,
+               audio_artifact_id TEXT,
+               transcript_id TEXT,
+               feedback_id TEXT
+             );
+             INSERT INTO runs (id, project_id, created_at, audio_artifact_id)
+             VALUES ('run_src', 'proj_1', '2026-03-01T00:00:00Z', 'art_src');",
+        )
+        .expect("schema");
+
+        let linked = insert_run_from_source_audio(
+            &conn,
+            "run_edit",
+            "2026-03-02T00:00:00Z",
+            "art_edit",
+            Some("tr_edit"),
+            "art_src",
+        )
+        .expect("insert");
+        assert!(linked);
+        let row: (String, String, Option<String>, Option<String>) = conn
+            .query_row(
+                "SELECT project_id, audio_artifact_id, transcript_id, feedback_id
+                 FROM runs WHERE id = 'run_edit'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+            )
+            .expect("edited run");
+        assert_eq!(
+            row,
+            (
+                "proj_1".to_string(),
+                "art_edit".to_string(),
+                Some("tr_edit".to_string()),
+                None
+            )
+        );
+
+        let unlinked = insert_run_from_source_audio(
+            &conn,
+            "run_other",
+            "2026-03-02T00:00:00Z",
+            "art_other",
+            None,
+            "art_memo",
+        )
+        .expect("insert");
+        assert!(!unlinked);
+    }
 }
@@ -26,6 +26,54 @@ pub fn run_create(app: &AppHandle, profile_id: &str, project_id: &str) -> Result
     Ok(id)
 }
 
+/// Creates a run that already holds its take, for audio that exists before
+/// the run does, so a failure never leaves an empty run behind.
+pub fn run_create_with_audio(
+    app: &AppHandle,
+    profile_id: &str,
+    project_id: &str,
+    audio_artifact_id: &str,
+) -> Result<String, String> {
+    db::ensure_profile_exists(app, profile_id)?;
+    let conn = db::open_profile(app, profile_id)?;
+    repo::ensure_project_exists(&conn, project_id)?;
+    ensure_audio_artifact(app, profile_id, audio_artifact_id)?;
+
+    let id = ids::new_id("run");
+    let now = time::now_rfc3339();
+    repo::insert_run_with_audio(&conn, &id, project_id, &now, audio_artifact_id)?;
+    Ok(id)
+}
+
+/// Records edited audio as a new run of the project its first linked source
+/// take belongs to, so the edit shows up next to the original takes.
+/// Returns `None` when no source was recorded as a run.
+pub fn run_create_from_edit(
+    app: &AppHandle,
+    profile_id: &str,
+    source_audio_artifact_ids: &[&str],
+    audio_artifact_id: &str,
+    transcript_id: Option<&str>,
+) -> Result<Option<String>, String> {
+    db::ensure_profile_exists(app, profile_id)?;
+    let conn = db::open_profile(app, profile_id)?;
+    let id = ids::new_id("run");
+    let now = time::now_rfc3339();
+    for source_id in source_audio_artifact_ids {
+        if repo::insert_run_from_source_audio(
+            &conn,
+            &id,
+            &now,
+            audio_artifact_id,
+            transcript_id,
+            source_id,
+        )? {
+            return Ok(Some(id));
+        }
+    }
+    Ok(None)
+}
+
 pub fn run_finish(
     app: &AppHandle,
     profile_id: &str,
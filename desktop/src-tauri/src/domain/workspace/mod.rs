@@ -22,6 +22,15 @@ pub fn profile_list(app: &AppHandle) -> Result<Vec<ProfileSummary>, String> {
     Ok(profiles)
 }
 
+/// Profile ids without the size and talk counts of `profile_list`.
+pub fn profile_ids(app: &AppHandle) -> Result<Vec<String>, String> {
+    let conn = db::open_global(app)?;
+    Ok(repo::select_profiles(&conn)?
+        .into_iter()
+        .map(|profile| profile.id)
+        .collect())
+}
+
 pub fn profile_create(app: &AppHandle, name: &str) -> Result<String, String> {
     let mut conn = db::open_global(app)?;
     let id = ids::new_id("prof");
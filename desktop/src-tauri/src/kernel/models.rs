@@ -59,9 +59,11 @@ pub struct AsrModelStatus {
     pub label: String,
     pub bundled: bool,
     pub installed: bool,
-    pub expected_bytes: u64,
-    pub expected_sha256: String,
-    pub source_url: String,
+    pub expected_bytes: Option<u64>,
+    pub expected_sha256: Option<String>,
+    pub source_url: Option<String>,
+    pub english_only: bool,
+    pub custom: bool,
     pub path: Option<String>,
     pub size_bytes: Option<u64>,
     pub checksum_ok: Option<bool>,
@@ -100,6 +102,17 @@ pub struct TranscriptSegment {
     pub t_end_ms: i64,
     pub text: String,
     pub confidence: Option<f32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub words: Vec<TranscriptWord>,
+}
+
+/// Word timing from the decoder; text keeps attached punctuation.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct TranscriptWord {
+    pub t_start_ms: i64,
+    pub t_end_ms: i64,
+    pub text: String,
+    pub confidence: Option<f32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -109,6 +122,15 @@ pub struct TranscriptV1 {
     pub model_id: Option<String>,
     pub duration_ms: Option<i64>,
     pub segments: Vec<TranscriptSegment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub markers: Vec<RecordingMarker>,
+}
+
+/// Bookmark dropped while recording, relative to the start of the audio.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RecordingMarker {
+    pub t_ms: i64,
+    pub label: String,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -130,7 +152,7 @@ pub struct FeedbackComment {
     pub suggestion: String,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Default, Serialize, Deserialize)]
 pub struct FeedbackMetrics {
     pub wpm: f64,
     pub filler_per_min: f64,
@@ -139,6 +161,155 @@ pub struct FeedbackMetrics {
     pub repeat_terms: Vec<String>,
     pub jargon_terms: Vec<String>,
     pub density_score: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pauses: Option<PauseMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pace: Option<PaceMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<DurationMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outline: Option<OutlineCoverage>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loudness: Option<LoudnessMetrics>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pitch: Option<PitchMetrics>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoudnessMetrics {
+    pub speech_mean_db: f64,
+    #[serde(default)]
+    pub variation_db: Option<f64>,
+    pub dynamic_range_db: f64,
+    pub monotone: bool,
+    pub trailing_off_count: i64,
+    pub clipping_count: i64,
+    #[serde(default)]
+    pub envelope_artifact_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LoudnessEnvelopeV1 {
+    pub schema_version: String,
+    pub source_audio_artifact_id: String,
+    pub step_ms: i64,
+    pub envelope_db: Vec<f32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PitchMetrics {
+    pub pitch_artifact_id: Option<String>,
+    pub median_hz: f64,
+    pub range_st: f64,
+    pub variability_st: f64,
+    pub voiced_ratio: f64,
+    pub flat_stretch_count: i64,
+    pub flat_ratio: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PitchAnalysisV1 {
+    pub schema_version: String,
+    pub source_audio_artifact_id: String,
+    pub hop_ms: i64,
+    pub f0_hz: Vec<Option<f32>>,
+    pub summary: PitchSummary,
+    pub segments: Vec<PitchSegmentStats>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PitchSummary {
+    pub voiced_ratio: f64,
+    pub median_hz: f64,
+    pub range_st: f64,
+    pub variability_st: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PitchSegmentStats {
+    pub t_start_ms: i64,
+    pub t_end_ms: i64,
+    pub median_hz: Option<f64>,
+    pub range_st: Option<f64>,
+    pub variability_st: Option<f64>,
+    pub flat: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WaveformPeaksV1 {
+    pub schema_version: String,
+    pub source_audio_artifact_id: String,
+    pub sample_rate_hz: u32,
+    pub sample_count: u64,
+    pub duration_ms: i64,
+    pub levels: Vec<WaveformLevel>,
+}
+
+/// Absolute peaks per bin, quantized to 0..=255, finest level first.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WaveformLevel {
+    pub bin_samples: u32,
+    pub peaks: Vec<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OutlineCoverage {
+    pub sections: Vec<OutlineSectionCoverage>,
+    pub covered_count: i64,
+    pub coverage_ratio: f64,
+    pub skipped: Vec<String>,
+    pub reordered: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OutlineSectionCoverage {
+    pub title: String,
+    pub covered: bool,
+    pub t_start_ms: Option<i64>,
+    pub duration_ms: i64,
+    pub bullets_total: i64,
+    pub bullets_covered: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DurationMetrics {
+    pub target_sec: i64,
+    pub actual_sec: i64,
+    pub projected_sec: i64,
+    pub delta_sec: i64,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PaceMetrics {
+    pub window_ms: i64,
+    pub step_ms: i64,
+    pub baseline_wpm: f64,
+    pub points: Vec<PacePoint>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PacePoint {
+    pub t_start_ms: i64,
+    pub t_end_ms: i64,
+    pub wpm: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PauseMetrics {
+    pub source: String,
+    pub count: i64,
+    pub mean_ms: f64,
+    pub max_ms: i64,
+    pub total_ms: i64,
+    pub histogram: Vec<PauseHistogramBin>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PauseHistogramBin {
+    pub min_ms: i64,
+    pub max_ms: Option<i64>,
+    pub count: i64,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -148,6 +319,10 @@ pub struct FeedbackV1 {
     pub top_actions: Vec<FeedbackAction>,
     pub comments: Vec<FeedbackComment>,
     pub metrics: FeedbackMetrics,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub analyzers: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub markers: Vec<RecordingMarker>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -9,27 +9,50 @@ pub fn run() {
     {
         tauri::Builder::default()
             .manage(commands::audio::RecordingManager::default())
+            .manage(platform::asr_sidecar::SidecarPool::default())
+            .manage(domain::asr::TranscriptionQueue::default())
+            .setup(|app| {
+                commands::transcription::resume_transcription_jobs(app.handle());
+                commands::audio::detect_orphan_drafts(app.handle());
+                Ok(())
+            })
             .plugin(tauri_plugin_dialog::init())
             .plugin(tauri_plugin_shell::init())
             .invoke_handler(tauri::generate_handler![
                 commands::audio::audio_reveal_wav,
+                commands::audio::audio_storage_migrate,
+                commands::audio::audio_import,
                 commands::audio::audio_trim_wav,
+                commands::audio::audio_edit,
+                commands::audio::audio_waveform_peaks,
                 commands::audio::recording_start,
                 commands::audio::recording_input_devices,
                 commands::audio::recording_telemetry_budget,
                 commands::audio::recording_status,
                 commands::audio::recording_pause,
                 commands::audio::recording_resume,
+                commands::audio::recording_mark,
                 commands::audio::recording_stop,
+                commands::audio::recording_drafts_list,
+                commands::audio::recording_draft_discard,
+                commands::audio::recording_draft_recover,
                 commands::audio::voice_memo_delete,
                 commands::coach::mascot_get_context_message,
                 commands::coach::progress_get_snapshot,
                 commands::coach::talks_get_blueprint,
                 commands::feedback::analyze_attempt,
+                commands::feedback::feedback_analyzers_list,
+                commands::feedback::feedback_compare,
                 commands::feedback::feedback_context_get,
                 commands::feedback::feedback_get,
+                commands::feedback::feedback_lexicon_add,
+                commands::feedback::feedback_lexicon_list,
+                commands::feedback::feedback_lexicon_remove,
+                commands::feedback::feedback_lexicon_reset,
                 commands::feedback::feedback_note_get,
                 commands::feedback::feedback_note_set,
+                commands::feedback::feedback_revisions_diff,
+                commands::feedback::feedback_revisions_list,
                 commands::feedback::feedback_timeline_list,
                 commands::outline::export_outline,
                 commands::outline::outline_get,
@@ -68,8 +91,11 @@ pub fn run() {
                 commands::run::run_get,
                 commands::run::run_get_latest,
                 commands::run::run_list,
+                commands::run::run_reanalyze,
                 commands::run::run_set_transcript,
                 commands::transcription::transcribe_audio,
+                commands::transcription::transcription_cancel,
+                commands::transcription::transcription_jobs_list,
                 commands::transcription::transcript_get,
                 commands::transcription::transcript_edit_save,
                 commands::transcription::transcript_export,
@@ -77,6 +103,7 @@ pub fn run() {
                 commands::transcription::asr_model_download,
                 commands::transcription::asr_model_remove,
                 commands::transcription::asr_model_verify,
+                commands::transcription::asr_model_register,
                 commands::transcription::asr_sidecar_status,
                 commands::transcription::asr_diagnostics_export,
                 commands::security::security_prepare_appdata_file,
@@ -90,27 +117,50 @@ pub fn run() {
     {
         tauri::Builder::default()
             .manage(commands::audio::RecordingManager::default())
+            .manage(platform::asr_sidecar::SidecarPool::default())
+            .manage(domain::asr::TranscriptionQueue::default())
+            .setup(|app| {
+                commands::transcription::resume_transcription_jobs(app.handle());
+                commands::audio::detect_orphan_drafts(app.handle());
+                Ok(())
+            })
             .plugin(tauri_plugin_dialog::init())
             .plugin(tauri_plugin_shell::init())
             .invoke_handler(tauri::generate_handler![
                 commands::audio::audio_reveal_wav,
+                commands::audio::audio_storage_migrate,
+                commands::audio::audio_import,
                 commands::audio::audio_trim_wav,
+                commands::audio::audio_edit,
+                commands::audio::audio_waveform_peaks,
                 commands::audio::recording_start,
                 commands::audio::recording_input_devices,
                 commands::audio::recording_telemetry_budget,
                 commands::audio::recording_status,
                 commands::audio::recording_pause,
                 commands::audio::recording_resume,
+                commands::audio::recording_mark,
                 commands::audio::recording_stop,
+                commands::audio::recording_drafts_list,
+                commands::audio::recording_draft_discard,
+                commands::audio::recording_draft_recover,
                 commands::audio::voice_memo_delete,
                 commands::coach::mascot_get_context_message,
                 commands::coach::progress_get_snapshot,
                 commands::coach::talks_get_blueprint,
                 commands::feedback::analyze_attempt,
+                commands::feedback::feedback_analyzers_list,
+                commands::feedback::feedback_compare,
                 commands::feedback::feedback_context_get,
                 commands::feedback::feedback_get,
+                commands::feedback::feedback_lexicon_add,
+                commands::feedback::feedback_lexicon_list,
+                commands::feedback::feedback_lexicon_remove,
+                commands::feedback::feedback_lexicon_reset,
                 commands::feedback::feedback_note_get,
                 commands::feedback::feedback_note_set,
+                commands::feedback::feedback_revisions_diff,
+                commands::feedback::feedback_revisions_list,
                 commands::feedback::feedback_timeline_list,
                 commands::outline::export_outline,
                 commands::outline::outline_get,
@@ -149,8 +199,11 @@ pub fn run() {
                 commands::run::run_get,
                 commands::run::run_get_latest,
                 commands::run::run_list,
+                commands::run::run_reanalyze,
                 commands::run::run_set_transcript,
                 commands::transcription::transcribe_audio,
+                commands::transcription::transcription_cancel,
+                commands::transcription::transcription_jobs_list,
                 commands::transcription::transcript_get,
                 commands::transcription::transcript_edit_save,
                 commands::transcription::transcript_export,
@@ -158,6 +211,7 @@ pub fn run() {
                 commands::transcription::asr_model_download,
                 commands::transcription::asr_model_remove,
                 commands::transcription::asr_model_verify,
+                commands::transcription::asr_model_register,
                 commands::transcription::asr_sidecar_status,
                 commands::transcription::asr_diagnostics_export
             ])
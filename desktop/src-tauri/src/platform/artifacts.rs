@@ -18,6 +18,13 @@ pub struct ArtifactInfo {
     pub relpath: String,
 }
 
+pub struct ArtifactEntry {
+    pub id: String,
+    pub relpath: String,
+    pub bytes: u64,
+    pub metadata: Value,
+}
+
 pub struct ArtifactDraft {
     pub id: String,
     pub artifact_type: String,
@@ -135,6 +142,65 @@ pub fn create_draft(
     })
 }
 
+pub fn reopen_draft(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    artifact_id: &str,
+    artifact_type: &str,
+    extension: &str,
+) -> Result<ArtifactDraft, String> {
+    let valid_id = !artifact_id.is_empty()
+        && artifact_id
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-');
+    if !valid_id {
+        return Err("artifact_id_invalid".to_string());
+    }
+    let relpath = format!("artifacts/{artifact_type}/{artifact_id}.{extension}");
+    let abspath = db::profile_dir(app, profile_id)?.join(&relpath);
+    if !abspath.is_file() {
+        return Err("artifact_draft_missing".to_string());
+    }
+    Ok(ArtifactDraft {
+        id: artifact_id.to_string(),
+        artifact_type: artifact_type.to_string(),
+        relpath,
+        abspath,
+    })
+}
+
+pub fn artifact_exists(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    artifact_id: &str,
+) -> Result<bool, String> {
+    let conn = db::open_profile(app, profile_id)?;
+    conn.query_row(
+        "SELECT 1 FROM artifacts WHERE id = ?1",
+        [artifact_id],
+        |_| Ok(()),
+    )
+    .optional()
+    .map(|row| row.is_some())
+    .map_err(|e| format!("artifact_lookup: {e}"))
+}
+
+pub fn artifact_metadata(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    artifact_id: &str,
+) -> Result<Value, String> {
+    let conn = db::open_profile(app, profile_id)?;
+    let metadata_json = conn
+        .query_row(
+            "SELECT metadata_json FROM artifacts WHERE id = ?1",
+            [artifact_id],
+            |row| row.get::<_, String>(0),
+        )
+        .map_err(|e| format!("artifact_lookup: {e}"))?;
+    Ok(serde_json::from_str(&metadata_json).unwrap_or(Value::Null))
+}
+
 pub fn finalize_draft(
     app: &tauri::AppHandle,
     profile_id: &str,
@@ -215,6 +281,137 @@ pub fn register_existing_file(
     })
 }
 
+pub fn list_artifacts(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    artifact_type: &str,
+) -> Result<Vec<ArtifactEntry>, String> {
+    let conn = db::open_profile(app, profile_id)?;
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, local_relpath, bytes, metadata_json FROM artifacts
+             WHERE type = ?1 ORDER BY created_at, id",
+        )
+        .map_err(|e| format!("artifact_list: {e}"))?;
+    let rows = stmt
+        .query_map([artifact_type], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, i64>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })
+        .map_err(|e| format!("artifact_list: {e}"))?;
+    let mut entries = Vec::new();
+    for row in rows {
+        let (id, relpath, bytes, metadata_json) = row.map_err(|e| format!("artifact_list: {e}"))?;
+        entries.push(ArtifactEntry {
+            id,
+            relpath,
+            bytes: bytes.max(0) as u64,
+            metadata: serde_json::from_str(&metadata_json).unwrap_or(Value::Null),
+        });
+    }
+    Ok(entries)
+}
+
+/// The most recent JSON artifact of `artifact_type` computed from
+/// `source_audio_artifact_id`, with its parsed content.
+pub fn find_audio_derived<T: serde::de::DeserializeOwned>(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    artifact_type: &str,
+    source_audio_artifact_id: &str,
+) -> Result<Option<(String, T)>, String> {
+    let conn = db::open_profile(app, profile_id)?;
+    let Some((id, relpath)) =
+        latest_audio_derived_with_conn(&conn, artifact_type, source_audio_artifact_id)?
+    else {
+        return Ok(None);
+    };
+    let path = resolve_profile_relpath_for_read(app, profile_id, &relpath)?;
+    let bytes = std::fs::read(path).map_err(|e| format!("artifact_read: {e}"))?;
+    let content = serde_json::from_slice(&bytes).map_err(|e| format!("artifact_parse: {e}"))?;
+    Ok(Some((id, content)))
+}
+
+fn latest_audio_derived_with_conn(
+    conn: &Connection,
+    artifact_type: &str,
+    source_audio_artifact_id: &str,
+) -> Result<Option<(String, String)>, String> {
+    conn.query_row(
+        "SELECT id, local_relpath FROM artifacts
+         WHERE type = ?1
+           AND CASE WHEN json_valid(metadata_json)
+                 THEN json_extract(metadata_json, '$.source_audio_artifact_id')
+               END = ?2
+         ORDER BY created_at DESC, id DESC
+         LIMIT 1",
+        params![artifact_type, source_audio_artifact_id],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )
+    .optional()
+    .map_err(|e| format!("artifact_lookup: {e}"))
+}
+
+/// Swaps the stored file of an existing artifact, keeping its id so every
+/// reference to it stays valid. The previous file is removed once the row
+/// points at the new one.
+pub fn replace_artifact_content(
+    app: &tauri::AppHandle,
+    profile_id: &str,
+    artifact_id: &str,
+    extension: &str,
+    bytes: &[u8],
+    metadata: &Value,
+) -> Result<ArtifactRecord, String> {
+    let info = get_artifact(app, profile_id, artifact_id)?;
+    let relpath = format!("artifacts/{}/{artifact_id}.{extension}", info.artifact_type);
+    let profile_dir = db::profile_dir(app, profile_id)?;
+    let abspath = profile_dir.join(&relpath);
+    let tmp_path = abspath.with_extension(format!("{extension}.tmp"));
+
+    let mut hasher = Sha256::new();
+    hasher.update(bytes);
+    let sha256 = to_hex(&hasher.finalize());
+    let byte_len = bytes.len() as u64;
+    let metadata_json =
+        serde_json::to_string(metadata).map_err(|e| format!("artifact_metadata: {e}"))?;
+
+    std::fs::write(&tmp_path, bytes).map_err(|e| format!("artifact_write: {e}"))?;
+    if let Err(err) = std::fs::rename(&tmp_path, &abspath) {
+        let _ = remove_file_if_exists(&tmp_path);
+        return Err(format!("artifact_write: {err}"));
+    }
+
+    let conn = db::open_profile(app, profile_id)?;
+    let updated = conn.execute(
+        "UPDATE artifacts SET local_relpath = ?2, sha256 = ?3, bytes = ?4, metadata_json = ?5
+         WHERE id = ?1",
+        params![artifact_id, relpath, sha256, byte_len as i64, metadata_json],
+    );
+    if let Err(err) = updated {
+        let persist_err = format!("artifact_update: {err}");
+        if relpath != info.relpath {
+            let _ = remove_file_if_exists(&abspath);
+        }
+        return Err(persist_err);
+    }
+
+    if relpath != info.relpath {
+        remove_file_if_exists(&profile_dir.join(&info.relpath))?;
+    }
+
+    Ok(ArtifactRecord {
+        id: artifact_id.to_string(),
+        abspath,
+        bytes: byte_len,
+        sha256,
+    })
+}
+
 pub fn delete_artifact(
     app: &tauri::AppHandle,
     profile_id: &str,
@@ -371,7 +568,7 @@ fn to_hex(bytes: &[u8]) -> String {
 mod tests {
     use super::{
         delete_artifact_with_conn, delete_artifacts_with_conn, insert_artifact_row_with_cleanup,
-        sha256_file, ArtifactInsertRow,
+        latest_audio_derived_with_conn, sha256_file, ArtifactInsertRow,
     };
     use rusqlite::{params, Connection};
     use std::time::{SystemTime, UNIX_EPOCH};
@@ -604,4 +801,40 @@ mod tests {
         assert_eq!(bytes, 3);
         let _ = std::fs::remove_file(file_path);
     }
+
+    #[test]
+    fn audio_derived_lookup_picks_latest_match_of_the_source() {
+        let conn = Connection::open_in_memory().expect("open");
+        conn.execute_batch(
+            "CREATE TABLE artifacts (
+               id TEXT PRIMARY KEY,
+               type TEXT NOT NULL,
+               local_relpath TEXT NOT NULL,
+               sha256 TEXT NOT NULL,
+               bytes INTEGER NOT NULL,
+               created_at TEXT NOT NULL,
+               metadata_json TEXT NOT NULL
+             );
+             INSERT INTO artifacts VALUES
+               ('p_old', 'pitch', 'a/p_old.json', '', 0, '2026-03-01T00:00:00Z',
+                '{\"source_audio_artifact_id\":\"art_a\"}'),
+               ('p_new', 'pitch', 'a/p_new.json', '', 0, '2026-03-02T00:00:00Z',
+                '{\"source_audio_artifact_id\":\"art_a\"}'),
+               ('p_other', 'pitch', 'a/p_other.json', '', 0, '2026-03-03T00:00:00Z',
+                '{\"source_audio_artifact_id\":\"art_b\"}'),
+               ('w_a', 'waveform', 'a/w_a.json', '', 0, '2026-03-04T00:00:00Z',
+                '{\"source_audio_artifact_id\":\"art_a\"}'),
+               ('p_bad', 'pitch', 'a/p_bad.json', '', 0, '2026-03-05T00:00:00Z', 'not json');",
+        )
+        .expect("schema");
+
+        assert_eq!(
+            latest_audio_derived_with_conn(&conn, "pitch", "art_a").expect("lookup"),
+            Some(("p_new".to_string(), "a/p_new.json".to_string()))
+        );
+        assert_eq!(
+            latest_audio_derived_with_conn(&conn, "pitch", "art_c").expect("lookup"),
+            None
+        );
+    }
 }
@@ -4,16 +4,25 @@ use serde::{Deserialize, Serialize};
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
 use std::process::{Child, ChildStdin, Command, Stdio};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, Receiver};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tauri::Manager;
 
+mod pool;
+
+pub use pool::{PooledDecoder, SidecarPool, SidecarWorkerStatus};
+
 const INIT_TIMEOUT: Duration = Duration::from_secs(10);
 const DECODE_TIMEOUT: Duration = Duration::from_secs(30);
+const CANCEL_POLL: Duration = Duration::from_millis(100);
+const CANCEL_GRACE: Duration = Duration::from_secs(2);
+pub const DECODE_CANCELLED: &str = "transcription_cancelled";
 pub const SIDECAR_PROTOCOL_VERSION: &str = "1.0.0";
 const REQUIRED_SIDECAR_CAPABILITIES: [&str; 3] =
     ["decode_window_f32le", "progress_events", "mode_live_final"];
+const CAPABILITY_CANCEL_DECODE: &str = "cancel_decode";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -50,6 +59,7 @@ pub struct SidecarStatus {
     pub git_commit: Option<String>,
     pub capabilities: Vec<String>,
     pub dependencies: SidecarDependencies,
+    pub workers: Vec<SidecarWorkerStatus>,
 }
 
 fn sidecar_basename() -> &'static str {
@@ -126,6 +136,10 @@ pub fn resolve_sidecar_status(app: &tauri::AppHandle) -> Result<SidecarStatus, S
         git_commit: doctor.git_commit.clone(),
         capabilities: doctor.capabilities.clone(),
         dependencies: doctor.dependencies,
+        workers: app
+            .try_state::<SidecarPool>()
+            .map(|pool| pool.worker_statuses())
+            .unwrap_or_default(),
     })
 }
 
@@ -198,6 +212,11 @@ enum SidecarRequest {
         audio_b64: String,
         #[serde(skip_serializing_if = "Option::is_none")]
         mode: Option<String>,
+        #[serde(skip_serializing_if = "std::ops::Not::not")]
+        word_timestamps: bool,
+    },
+    Cancel {
+        seq: u64,
     },
     Shutdown,
 }
@@ -235,6 +254,18 @@ struct SidecarSegment {
     t0_ms: i64,
     t1_ms: i64,
     text: String,
+    #[serde(default)]
+    confidence: Option<f32>,
+    #[serde(default)]
+    words: Vec<SidecarWord>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SidecarWord {
+    t0_ms: i64,
+    t1_ms: i64,
+    text: String,
+    p: f32,
 }
 
 pub struct SidecarDecoder {
@@ -242,10 +273,18 @@ pub struct SidecarDecoder {
     stdin: ChildStdin,
     rx: Receiver<SidecarResponse>,
     seq: u64,
+    /// Older sidecars do not understand `cancel` and keep decoding.
+    can_cancel: bool,
 }
 
 impl SidecarDecoder {
     pub fn spawn(path: &Path, model_path: &Path, language: &str) -> Result<Self, String> {
+        let can_cancel = run_sidecar_doctor(path).is_ok_and(|doctor| {
+            doctor
+                .capabilities
+                .iter()
+                .any(|capability| capability == CAPABILITY_CANCEL_DECODE)
+        });
         let mut child = Command::new(path)
             .stdin(Stdio::piped())
             .stdout(Stdio::piped())
@@ -289,6 +328,7 @@ impl SidecarDecoder {
             stdin,
             rx,
             seq: 0,
+            can_cancel,
         };
 
         decoder.send_request(SidecarRequest::Init {
@@ -321,6 +361,31 @@ impl SidecarDecoder {
         window_start_ms: i64,
         window_end_ms: i64,
         mode: DecodeMode,
+        on_progress: F,
+    ) -> Result<Vec<models::TranscriptSegment>, String>
+    where
+        F: FnMut(i64, i64),
+    {
+        self.decode_window_cancellable(
+            window,
+            window_start_ms,
+            window_end_ms,
+            mode,
+            &AtomicBool::new(false),
+            on_progress,
+        )
+    }
+
+    /// Once `cancel` is set the sidecar is asked to abort the decode. If it
+    /// cannot cancel, or does not answer within a short grace period, the
+    /// process is killed, so the caller must not reuse it.
+    pub fn decode_window_cancellable<F>(
+        &mut self,
+        window: &[f32],
+        window_start_ms: i64,
+        window_end_ms: i64,
+        mode: DecodeMode,
+        cancel: &AtomicBool,
         mut on_progress: F,
     ) -> Result<Vec<models::TranscriptSegment>, String>
     where
@@ -347,18 +412,40 @@ impl SidecarDecoder {
             encoding: "f32le".to_string(),
             audio_b64,
             mode: Some(mode_value.to_string()),
+            // Sidecars without the capability ignore the flag and return
+            // segment-level timings only.
+            word_timestamps: matches!(mode, DecodeMode::Final),
         })?;
 
-        let deadline = std::time::Instant::now() + DECODE_TIMEOUT;
+        let mut deadline = Instant::now() + DECODE_TIMEOUT;
+        let mut cancelling = false;
         loop {
-            let timeout = deadline.saturating_duration_since(std::time::Instant::now());
+            if !cancelling && cancel.load(Ordering::SeqCst) {
+                cancelling = true;
+                deadline = Instant::now() + CANCEL_GRACE;
+                if !self.can_cancel || self.send_request(SidecarRequest::Cancel { seq }).is_err() {
+                    self.kill();
+                    return Err(DECODE_CANCELLED.to_string());
+                }
+            }
+            let timeout = deadline.saturating_duration_since(Instant::now());
             if timeout.is_zero() {
+                if cancelling {
+                    self.kill();
+                    return Err(DECODE_CANCELLED.to_string());
+                }
                 return Err("sidecar_decode_timeout".to_string());
             }
-            let response = self
-                .rx
-                .recv_timeout(timeout)
-                .map_err(|_| "sidecar_decode_timeout".to_string())?;
+            let response = match self.rx.recv_timeout(timeout.min(CANCEL_POLL)) {
+                Ok(response) => response,
+                Err(mpsc::RecvTimeoutError::Timeout) => continue,
+                Err(mpsc::RecvTimeoutError::Disconnected) if cancelling => {
+                    return Err(DECODE_CANCELLED.to_string());
+                }
+                Err(mpsc::RecvTimeoutError::Disconnected) => {
+                    return Err("sidecar_exited".to_string());
+                }
+            };
             match response {
                 SidecarResponse::Segments {
                     seq: resp_seq,
@@ -367,13 +454,26 @@ impl SidecarDecoder {
                     if resp_seq != seq {
                         continue;
                     }
+                    if cancelling {
+                        return Err(DECODE_CANCELLED.to_string());
+                    }
                     let mapped = segments
                         .into_iter()
                         .map(|segment| models::TranscriptSegment {
                             t_start_ms: segment.t0_ms,
                             t_end_ms: segment.t1_ms,
                             text: segment.text,
-                            confidence: None,
+                            confidence: segment.confidence,
+                            words: segment
+                                .words
+                                .into_iter()
+                                .map(|word| models::TranscriptWord {
+                                    t_start_ms: word.t0_ms,
+                                    t_end_ms: word.t1_ms,
+                                    text: word.text,
+                                    confidence: Some(word.p),
+                                })
+                                .collect(),
                         })
                         .collect();
                     return Ok(mapped);
@@ -392,9 +492,15 @@ impl SidecarDecoder {
                     seq: resp_seq,
                     message,
                 } => {
-                    if resp_seq.is_some() && resp_seq != Some(seq) {
+                    // While cancelling only an answer to this decode counts
+                    // as the acknowledgement.
+                    let ours = resp_seq == Some(seq) || (resp_seq.is_none() && !cancelling);
+                    if !ours {
                         continue;
                     }
+                    if cancelling {
+                        return Err(DECODE_CANCELLED.to_string());
+                    }
                     return Err(format!("sidecar_decode: {message}"));
                 }
                 _ => continue,
@@ -402,6 +508,19 @@ impl SidecarDecoder {
         }
     }
 
+    pub fn pid(&self) -> u32 {
+        self._child.id()
+    }
+
+    pub fn is_alive(&mut self) -> bool {
+        matches!(self._child.try_wait(), Ok(None))
+    }
+
+    fn kill(&mut self) {
+        let _ = self._child.kill();
+        let _ = self._child.wait();
+    }
+
     fn send_request(&mut self, request: SidecarRequest) -> Result<(), String> {
         let payload = serde_json::to_string(&request).map_err(|e| format!("sidecar_json: {e}"))?;
         self.stdin
@@ -515,4 +634,25 @@ mod tests {
         let err = assert_sidecar_compatibility(&payload).expect_err("must fail");
         assert_eq!(err, "sidecar_unsupported_runtime_capability");
     }
+
+    #[test]
+    fn segments_parse_with_and_without_word_timings() {
+        let response: SidecarResponse = serde_json::from_str(
+            r#"{"type":"segments","seq":3,"segments":[
+                {"t0_ms":0,"t1_ms":900,"text":"hello"},
+                {"t0_ms":1000,"t1_ms":1800,"text":"hi there","confidence":0.7,
+                 "words":[{"t0_ms":1000,"t1_ms":1300,"text":"hi","p":0.8},
+                          {"t0_ms":1300,"t1_ms":1800,"text":"there","p":0.6}]}
+            ]}"#,
+        )
+        .expect("parse");
+        let SidecarResponse::Segments { segments, .. } = response else {
+            panic!("expected segments");
+        };
+        assert!(segments[0].words.is_empty());
+        assert_eq!(segments[0].confidence, None);
+        assert_eq!(segments[1].confidence, Some(0.7));
+        assert_eq!(segments[1].words.len(), 2);
+        assert_eq!(segments[1].words[1].text, "there");
+    }
 }
@@ -0,0 +1,502 @@
+use super::{DecodeMode, SidecarDecoder};
+use crate::kernel::models;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+use std::time::{Duration, Instant};
+
+const IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+const REAP_INTERVAL: Duration = Duration::from_secs(30);
+
+type WorkerKey = (String, String);
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SidecarWorkerStatus {
+    pub model_id: String,
+    pub language: String,
+    /// `busy`, `warm` or `stopped`.
+    pub state: String,
+    pub pid: Option<u32>,
+    pub queued_jobs: usize,
+    pub jobs_completed: u64,
+    pub restarts: u32,
+    pub idle_ms: Option<u64>,
+    pub last_error: Option<String>,
+}
+
+struct Worker {
+    decoder: Option<SidecarDecoder>,
+    sidecar_path: PathBuf,
+    model_path: PathBuf,
+    language: String,
+}
+
+/// Snapshot read by status queries without waiting for a running job.
+#[derive(Default)]
+struct WorkerHealth {
+    pid: Option<u32>,
+    busy: bool,
+    jobs_completed: u64,
+    restarts: u32,
+    last_used: Option<Instant>,
+    last_error: Option<String>,
+}
+
+struct WorkerSlot {
+    queued: AtomicUsize,
+    worker: Mutex<Worker>,
+    health: Mutex<WorkerHealth>,
+}
+
+impl WorkerSlot {
+    fn update_health(&self, apply: impl FnOnce(&mut WorkerHealth)) {
+        if let Ok(mut health) = self.health.lock() {
+            apply(&mut health);
+        }
+    }
+}
+
+/// Long-lived sidecar processes, one per `(model_id, language)`, so the
+/// model is loaded once instead of on every transcription. Jobs for the same
+/// key run one after another; a crashed process is restarted on next use and
+/// idle processes are shut down after a few minutes.
+#[derive(Default)]
+pub struct SidecarPool {
+    slots: Arc<Mutex<HashMap<WorkerKey, Arc<WorkerSlot>>>>,
+    reaper_started: AtomicBool,
+}
+
+impl SidecarPool {
+    pub fn run<T, F>(
+        &self,
+        model_id: &str,
+        language: &str,
+        sidecar_path: &Path,
+        model_path: &Path,
+        job: F,
+    ) -> Result<T, String>
+    where
+        F: FnOnce(&mut PooledDecoder) -> Result<T, String>,
+    {
+        self.ensure_reaper();
+        let slot = {
+            let mut slots = self.slots.lock().map_err(|_| "sidecar_pool_lock")?;
+            slots
+                .entry((model_id.to_string(), language.to_string()))
+                .or_insert_with(|| {
+                    Arc::new(WorkerSlot {
+                        queued: AtomicUsize::new(0),
+                        worker: Mutex::new(Worker {
+                            decoder: None,
+                            sidecar_path: sidecar_path.to_path_buf(),
+                            model_path: model_path.to_path_buf(),
+                            language: language.to_string(),
+                        }),
+                        health: Mutex::new(WorkerHealth::default()),
+                    })
+                })
+                .clone()
+        };
+
+        slot.queued.fetch_add(1, Ordering::SeqCst);
+        let worker = slot.worker.lock();
+        slot.queued.fetch_sub(1, Ordering::SeqCst);
+        let mut worker = worker.map_err(|_| "sidecar_pool_lock")?;
+        if worker.sidecar_path != sidecar_path || worker.model_path != model_path {
+            worker.decoder = None;
+            worker.sidecar_path = sidecar_path.to_path_buf();
+            worker.model_path = model_path.to_path_buf();
+        }
+
+        slot.update_health(|health| health.busy = true);
+        let mut pooled = PooledDecoder {
+            worker: &mut worker,
+            slot: &slot,
+        };
+        let result = job(&mut pooled);
+        let pid = worker.decoder.as_ref().map(SidecarDecoder::pid);
+        slot.update_health(|health| {
+            health.busy = false;
+            health.pid = pid;
+            health.last_used = Some(Instant::now());
+            match &result {
+                Ok(_) => health.jobs_completed += 1,
+                Err(err) => health.last_error = Some(err.clone()),
+            }
+        });
+        result
+    }
+
+    pub fn worker_statuses(&self) -> Vec<SidecarWorkerStatus> {
+        let Ok(slots) = self.slots.lock() else {
+            return Vec::new();
+        };
+        let mut statuses: Vec<SidecarWorkerStatus> = slots
+            .iter()
+            .filter_map(|((model_id, language), slot)| {
+                let queued_jobs = slot.queued.load(Ordering::SeqCst);
+                let health = slot.health.lock().ok()?;
+                let state = if health.busy {
+                    "busy"
+                } else if health.pid.is_some() {
+                    "warm"
+                } else {
+                    "stopped"
+                };
+                Some(SidecarWorkerStatus {
+                    model_id: model_id.clone(),
+                    language: language.clone(),
+                    state: state.to_string(),
+                    pid: health.pid,
+                    queued_jobs,
+                    jobs_completed: health.jobs_completed,
+                    restarts: health.restarts,
+                    idle_ms: (!health.busy)
+                        .then_some(health.last_used)
+                        .flatten()
+                        .map(|at| at.elapsed().as_millis() as u64),
+                    last_error: health.last_error.clone(),
+                })
+            })
+            .collect();
+        statuses.sort_by(|a, b| (&a.model_id, &a.language).cmp(&(&b.model_id, &b.language)));
+        statuses
+    }
+
+    /// Stops the idle processes of a model, e.g. once its file is removed.
+    /// Busy workers finish their job and are reaped later.
+    pub fn evict_model(&self, model_id: &str) {
+        let Ok(slots) = self.slots.lock() else {
+            return;
+        };
+        for ((slot_model_id, _), slot) in slots.iter() {
+            if slot_model_id == model_id {
+                stop_if_idle(slot, Duration::ZERO);
+            }
+        }
+    }
+
+    fn ensure_reaper(&self) {
+        if self.reaper_started.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let slots = Arc::downgrade(&self.slots);
+        thread::spawn(move || loop {
+            thread::sleep(REAP_INTERVAL);
+            if reap_idle(&slots, IDLE_TIMEOUT).is_none() {
+                break;
+            }
+        });
+    }
+}
+
+/// Returns `None` once the pool is gone, otherwise how many processes were
+/// stopped.
+fn reap_idle(
+    slots: &Weak<Mutex<HashMap<WorkerKey, Arc<WorkerSlot>>>>,
+    idle_timeout: Duration,
+) -> Option<usize> {
+    let slots = slots.upgrade()?;
+    let slots: Vec<Arc<WorkerSlot>> = match slots.lock() {
+        Ok(slots) => slots.values().cloned().collect(),
+        Err(_) => return Some(0),
+    };
+    Some(
+        slots
+            .iter()
+            .filter(|slot| stop_if_idle(slot, idle_timeout))
+            .count(),
+    )
+}
+
+fn stop_if_idle(slot: &WorkerSlot, idle_timeout: Duration) -> bool {
+    if slot.queued.load(Ordering::SeqCst) > 0 {
+        return false;
+    }
+    let Ok(mut worker) = slot.worker.try_lock() else {
+        return false;
+    };
+    if worker.decoder.is_none() {
+        return false;
+    }
+    let idle = slot
+        .health
+        .lock()
+        .ok()
+        .and_then(|health| health.last_used)
+        .is_none_or(|at| at.elapsed() >= idle_timeout);
+    if !idle {
+        return false;
+    }
+    worker.decoder = None;
+    slot.update_health(|health| health.pid = None);
+    true
+}
+
+/// Decoder handle lent to a pool job; spawns the process on demand and
+/// replaces it when it died mid-job.
+pub struct PooledDecoder<'a> {
+    worker: &'a mut Worker,
+    slot: &'a WorkerSlot,
+}
+
+impl PooledDecoder<'_> {
+    pub fn decode_window_cancellable<F>(
+        &mut self,
+        window: &[f32],
+        window_start_ms: i64,
+        window_end_ms: i64,
+        mode: DecodeMode,
+        cancel: &AtomicBool,
+        mut on_progress: F,
+    ) -> Result<Vec<models::TranscriptSegment>, String>
+    where
+        F: FnMut(i64, i64),
+    {
+        let mut restarted = false;
+        loop {
+            let decoder = self.ensure_decoder()?;
+            let result = decoder.decode_window_cancellable(
+                window,
+                window_start_ms,
+                window_end_ms,
+                mode,
+                cancel,
+                &mut on_progress,
+            );
+            let err = match result {
+                Ok(segments) => return Ok(segments),
+                Err(err) => err,
+            };
+            if err == super::DECODE_CANCELLED {
+                if !decoder.is_alive() {
+                    self.worker.decoder = None;
+                    self.slot.update_health(|health| health.pid = None);
+                }
+                return Err(err);
+            }
+            let crashed = err == "sidecar_exited" || !decoder.is_alive();
+            if crashed || err == "sidecar_decode_timeout" {
+                // A timed-out process may still be busy with the request.
+                self.worker.decoder = None;
+                self.slot.update_health(|health| health.pid = None);
+            }
+            if !crashed || restarted {
+                return Err(err);
+            }
+            eprintln!("asr sidecar exited during decode, restarting");
+            self.slot.update_health(|health| health.restarts += 1);
+            restarted = true;
+        }
+    }
+
+    fn ensure_decoder(&mut self) -> Result<&mut SidecarDecoder, String> {
+        let alive = self
+            .worker
+            .decoder
+            .as_mut()
+            .is_some_and(SidecarDecoder::is_alive);
+        if !alive {
+            let crashed = self.worker.decoder.take().is_some();
+            let decoder = SidecarDecoder::spawn(
+                &self.worker.sidecar_path,
+                &self.worker.model_path,
+                &self.worker.language,
+            )?;
+            let pid = decoder.pid();
+            self.slot.update_health(|health| {
+                health.pid = Some(pid);
+                if crashed {
+                    health.restarts += 1;
+                }
+            });
+            self.worker.decoder = Some(decoder);
+        }
+        self.worker
+            .decoder
+            .as_mut()
+            .ok_or_else(|| "sidecar_missing".to_string())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::os::unix::fs::PermissionsExt;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    /// Speaks just enough of the sidecar protocol; exits instead of answering
+    /// the first decode after `crash_once` appears next to it, and leaves a
+    /// decode pending until it is cancelled while `hang_once` exists. With
+    /// `no_cancel` it behaves like an older sidecar that rejects `cancel`.
+    const FAKE_SIDECAR: &str = r#"#!/bin/sh
+dir=$(dirname "$0")
+if [ "$1" = "doctor" ]; then
+  caps='"decode_window_f32le","progress_events","mode_live_final"'
+  if [ ! -f "$dir/no_cancel" ]; then caps="$caps,\"cancel_decode\""; fi
+  echo "{\"schemaVersion\":\"1.0.0\",\"sidecarVersion\":\"0.1.0\",\"protocolVersion\":\"1.0.0\",\"targetTriple\":\"test\",\"capabilities\":[$caps],\"dependencies\":{\"whisperRs\":\"0\",\"whisperCpp\":\"0\",\"whisperRuntime\":\"0\",\"ggml\":\"0\"}}"
+  exit 0
+fi
+while read -r line; do
+  seq=$(echo "$line" | sed 's/.*"seq":\([0-9]*\).*/\1/')
+  case "$line" in
+    *'"type":"init"'*) echo '{"type":"ready"}' ;;
+    *'"type":"decode"'*)
+      if [ -f "$dir/crash_once" ]; then rm "$dir/crash_once"; exit 1; fi
+      if [ -f "$dir/hang_once" ]; then rm "$dir/hang_once"; continue; fi
+      echo "{\"type\":\"segments\",\"seq\":$seq,\"segments\":[{\"t0_ms\":0,\"t1_ms\":500,\"text\":\"pid $$\"}]}" ;;
+    *'"type":"cancel"'*)
+      if [ -f "$dir/no_cancel" ]; then
+        echo '{"type":"error","seq":null,"message":"invalid request"}'
+      else
+        echo "{\"type\":\"error\",\"seq\":$seq,\"message\":\"cancelled\"}"
+      fi ;;
+    *'"type":"shutdown"'*) exit 0 ;;
+  esac
+done
+"#;
+
+    fn fake_sidecar_dir() -> PathBuf {
+        let nonce = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("time")
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!("lepupitre-sidecar-pool-{nonce}"));
+        std::fs::create_dir_all(&dir).expect("dir");
+        let script = dir.join("fake-asr");
+        std::fs::write(&script, FAKE_SIDECAR).expect("script");
+        std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).expect("chmod");
+        dir
+    }
+
+    fn decode_text(pool: &SidecarPool, dir: &Path, language: &str) -> Result<String, String> {
+        decode_text_cancellable(pool, dir, language, &AtomicBool::new(false))
+    }
+
+    fn decode_text_cancellable(
+        pool: &SidecarPool,
+        dir: &Path,
+        language: &str,
+        cancel: &AtomicBool,
+    ) -> Result<String, String> {
+        pool.run(
+            "tiny",
+            language,
+            &dir.join("fake-asr"),
+            &dir.join("model.bin"),
+            |decoder| {
+                let segments = decoder.decode_window_cancellable(
+                    &[0.0; 160],
+                    0,
+                    10,
+                    DecodeMode::Final,
+                    cancel,
+                    |_, _| {},
+                )?;
+                Ok(segments[0].text.clone())
+            },
+        )
+    }
+
+    #[test]
+    fn workers_stay_warm_and_restart_after_a_crash() {
+        let dir = fake_sidecar_dir();
+        let pool = SidecarPool::default();
+
+        let first = decode_text(&pool, &dir, "fr").expect("first");
+        assert_eq!(decode_text(&pool, &dir, "fr").expect("second"), first);
+        let other = decode_text(&pool, &dir, "en").expect("other language");
+        assert_ne!(other, first);
+
+        std::fs::write(dir.join("crash_once"), b"").expect("crash flag");
+        let restarted = decode_text(&pool, &dir, "fr").expect("restarted");
+        assert_ne!(restarted, first);
+
+        let statuses = pool.worker_statuses();
+        assert_eq!(statuses.len(), 2);
+        let fr = statuses
+            .iter()
+            .find(|status| status.language == "fr")
+            .expect("fr");
+        assert_eq!(fr.state, "warm");
+        assert_eq!(fr.jobs_completed, 3);
+        assert_eq!(fr.restarts, 1);
+        assert_eq!(fr.queued_jobs, 0);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn cancelled_decode_keeps_the_worker_warm() {
+        let dir = fake_sidecar_dir();
+        let pool = SidecarPool::default();
+        let warm = decode_text(&pool, &dir, "fr").expect("warm");
+
+        std::fs::write(dir.join("hang_once"), b"").expect("hang flag");
+        let cancel = Arc::new(AtomicBool::new(false));
+        let trigger = cancel.clone();
+        let canceller = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(200));
+            trigger.store(true, Ordering::SeqCst);
+        });
+        let err = decode_text_cancellable(&pool, &dir, "fr", &cancel).expect_err("cancelled");
+        canceller.join().expect("join");
+        assert_eq!(err, super::super::DECODE_CANCELLED);
+
+        assert_eq!(decode_text(&pool, &dir, "fr").expect("after cancel"), warm);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn cancelling_an_old_sidecar_replaces_the_worker() {
+        let dir = fake_sidecar_dir();
+        std::fs::write(dir.join("no_cancel"), b"").expect("old sidecar");
+        let pool = SidecarPool::default();
+        let warm = decode_text(&pool, &dir, "fr").expect("warm");
+
+        std::fs::write(dir.join("hang_once"), b"").expect("hang flag");
+        let cancel = Arc::new(AtomicBool::new(false));
+        let trigger = cancel.clone();
+        let canceller = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(200));
+            trigger.store(true, Ordering::SeqCst);
+        });
+        let err = decode_text_cancellable(&pool, &dir, "fr", &cancel).expect_err("cancelled");
+        canceller.join().expect("join");
+        assert_eq!(err, super::super::DECODE_CANCELLED);
+        assert!(pool.worker_statuses()[0].pid.is_none());
+
+        assert_ne!(decode_text(&pool, &dir, "fr").expect("after cancel"), warm);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn idle_workers_are_stopped() {
+        let dir = fake_sidecar_dir();
+        let pool = SidecarPool::default();
+        decode_text(&pool, &dir, "fr").expect("decode");
+        decode_text(&pool, &dir, "en").expect("decode");
+
+        let slots = Arc::downgrade(&pool.slots);
+        assert_eq!(reap_idle(&slots, Duration::from_secs(60)), Some(0));
+        pool.evict_model("other");
+        assert!(pool
+            .worker_statuses()
+            .iter()
+            .all(|status| status.state == "warm"));
+
+        assert_eq!(reap_idle(&slots, Duration::ZERO), Some(2));
+        assert!(pool
+            .worker_statuses()
+            .iter()
+            .all(|status| status.state == "stopped" && status.pid.is_none()));
+        decode_text(&pool, &dir, "fr").expect("respawned");
+
+        drop(pool);
+        assert_eq!(reap_idle(&slots, Duration::ZERO), None);
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
@@ -1,6 +1,6 @@
 use crate::kernel::time;
 use crate::platform::db_helpers;
-use crate::platform::seed::QuestSeedFile;
+use crate::platform::seed::{self, QuestSeedFile};
 use chrono::Utc;
 use rusqlite::{params, Connection, ErrorCode};
 use std::path::{Path, PathBuf};
@@ -12,6 +12,8 @@ use tauri::Manager;
 const SQLITE_BUSY_TIMEOUT_MS: u64 = 2000;
 const DB_SNAPSHOT_RETENTION_COUNT: usize = 5;
 const GLOBAL_MIGRATION: &str = include_str!("../../../../migrations/global/0001_init.sql");
+const GLOBAL_TRANSCRIPTION_JOBS_MIGRATION: &str =
+    include_str!("../../../../migrations/global/0002_transcription_jobs.sql");
 const PROFILE_MIGRATION: &str = include_str!("../../../../migrations/profile/0001_init.sql");
 const QUESTS_SEED: &str = include_str!("../../../../seed/quests.v1.json");
 const PROFILE_FK_MIGRATION_SQL: &str = "
@@ -241,10 +243,16 @@ pub struct DbDiagnostics {
     pub foreign_key_violations: usize,
 }
 
-const GLOBAL_MIGRATIONS: &[Migration] = &[Migration {
-    version: "0001_init",
-    apply: migration_global_0001_init,
-}];
+const GLOBAL_MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: "0001_init",
+        apply: migration_global_0001_init,
+    },
+    Migration {
+        version: "0002_transcription_jobs",
+        apply: migration_global_0002_transcription_jobs,
+    },
+];
 
 const PROFILE_MIGRATIONS: &[Migration] = &[
     Migration {
@@ -275,6 +283,10 @@ const PROFILE_MIGRATIONS: &[Migration] = &[
         version: "0007_fk_constraints",
         apply: migration_profile_0007_fk_constraints,
     },
+    Migration {
+        version: "0008_feedback_lexicons",
+        apply: migration_profile_0008_feedback_lexicons,
+    },
 ];
 
 pub fn open_global(app: &tauri::AppHandle) -> Result<Connection, String> {
@@ -486,6 +498,11 @@ fn migration_global_0001_init(conn: &mut Connection) -> Result<(), String> {
         .map_err(|e| format!("migrate_global_0001: {e}"))
 }
 
+fn migration_global_0002_transcription_jobs(conn: &mut Connection) -> Result<(), String> {
+    conn.execute_batch(GLOBAL_TRANSCRIPTION_JOBS_MIGRATION)
+        .map_err(|e| format!("migrate_global_0002: {e}"))
+}
+
 fn migration_profile_0001_init(conn: &mut Connection) -> Result<(), String> {
     conn.execute_batch(PROFILE_MIGRATION)
         .map_err(|e| format!("migrate_profile_0001: {e}"))
@@ -519,6 +536,11 @@ fn migration_profile_0007_fk_constraints(conn: &mut Connection) -> Result<(), St
     verify_foreign_key_integrity(conn)
 }
 
+fn migration_profile_0008_feedback_lexicons(conn: &mut Connection) -> Result<(), String> {
+    ensure_feedback_lexicon_table(conn)?;
+    seed_feedback_lexicons(conn)
+}
+
 fn apply_migrations(
     conn: &mut Connection,
     db_path: &Path,
@@ -801,6 +823,29 @@ fn seed_quests(conn: &mut Connection) -> Result<(), String> {
     Ok(())
 }
 
+fn seed_feedback_lexicons(conn: &mut Connection) -> Result<(), String> {
+    let seed = seed::lexicon_seed()?;
+    let now = time::now_rfc3339();
+    let tx = conn.transaction().map_err(|e| format!("tx: {e}"))?;
+    for lexicon in seed.lexicons {
+        let terms = lexicon
+            .fillers
+            .iter()
+            .map(|term| ("filler", term))
+            .chain(lexicon.stopwords.iter().map(|term| ("stopword", term)));
+        for (kind, term) in terms {
+            tx.execute(
+                "INSERT OR IGNORE INTO feedback_lexicon_terms (language, kind, term, created_at)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![lexicon.language, kind, term, now],
+            )
+            .map_err(|e| format!("insert_lexicon_term: {e}"))?;
+        }
+    }
+    tx.commit().map_err(|e| format!("commit: {e}"))?;
+    Ok(())
+}
+
 fn configure_connection_pragmas(conn: &Connection) -> Result<(), String> {
     conn.busy_timeout(Duration::from_millis(SQLITE_BUSY_TIMEOUT_MS))
         .map_err(|e| format!("busy_timeout: {e}"))?;
@@ -994,6 +1039,21 @@ fn ensure_profile_settings_table(conn: &mut Connection) -> Result<(), String> {
     Ok(())
 }
 
+fn ensure_feedback_lexicon_table(conn: &mut Connection) -> Result<(), String> {
+    conn.execute(
+        "CREATE TABLE IF NOT EXISTS feedback_lexicon_terms (
+           language TEXT NOT NULL,
+           kind TEXT NOT NULL CHECK (kind IN ('filler', 'stopword', 'jargon_ignore')),
+           term TEXT NOT NULL,
+           created_at TEXT NOT NULL,
+           PRIMARY KEY (language, kind, term)
+         )",
+        [],
+    )
+    .map_err(|e| format!("feedback_lexicon_table: {e}"))?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1106,7 +1166,7 @@ mod tests {
         assert_eq!(applied.first().map(String::as_str), Some("0001_init"));
         assert_eq!(
             applied.last().map(String::as_str),
-            Some("0007_fk_constraints")
+            Some("0008_feedback_lexicons")
         );
     }
 
@@ -1122,7 +1182,13 @@ mod tests {
         .expect("migrate");
 
         let applied = load_applied_migrations(&conn).expect("applied");
-        assert_eq!(applied, vec!["0001_init".to_string()]);
+        assert_eq!(
+            applied,
+            vec![
+                "0001_init".to_string(),
+                "0002_transcription_jobs".to_string()
+            ]
+        );
     }
 
     #[test]
@@ -1310,7 +1376,7 @@ mod tests {
         assert_eq!(applied[1], "0002_outline_and_settings");
         assert_eq!(
             applied.last().map(String::as_str),
-            Some("0007_fk_constraints")
+            Some("0008_feedback_lexicons")
         );
     }
 
@@ -1481,6 +1547,32 @@ mod tests {
         assert!(index_exists(&conn, "idx_artifacts_sha"));
     }
 
+    #[test]
+    fn feedback_lexicons_are_seeded_per_language() {
+        let mut conn = Connection::open_in_memory().expect("open");
+        apply_migrations(
+            &mut conn,
+            Path::new(":memory:"),
+            "profile",
+            PROFILE_MIGRATIONS,
+        )
+        .expect("migrate");
+
+        let count_terms = |language: &str, kind: &str, term: &str| -> i64 {
+            conn.query_row(
+                "SELECT COUNT(*) FROM feedback_lexicon_terms
+                 WHERE language = ?1 AND kind = ?2 AND term = ?3",
+                params![language, kind, term],
+                |row| row.get(0),
+            )
+            .expect("lexicon count")
+        };
+        assert_eq!(count_terms("en", "filler", "you know"), 1);
+        assert_eq!(count_terms("fr", "filler", "du coup"), 1);
+        assert_eq!(count_terms("fr", "stopword", "l'"), 1);
+        assert_eq!(count_terms("en", "filler", "du coup"), 0);
+    }
+
     #[test]
     fn run_hot_query_plan_uses_project_time_index() {
         let mut conn = Connection::open_in_memory().expect("open");
@@ -16,3 +16,27 @@ pub struct QuestSeed {
     pub output_type: String,
     pub targets_issues: Vec<String>,
 }
+
+const LEXICONS_SEED: &str = include_str!("../../../../seed/lexicons.v1.json");
+
+#[derive(Debug, Deserialize)]
+pub struct LexiconSeedFile {
+    pub schema_version: String,
+    pub lexicons: Vec<LexiconSeed>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LexiconSeed {
+    pub language: String,
+    pub fillers: Vec<String>,
+    pub stopwords: Vec<String>,
+}
+
+pub fn lexicon_seed() -> Result<LexiconSeedFile, String> {
+    let seed: LexiconSeedFile =
+        serde_json::from_str(LEXICONS_SEED).map_err(|e| format!("lexicon_seed_parse: {e}"))?;
+    if seed.schema_version != "1.0.0" {
+        return Err("lexicon_seed_version_mismatch".to_string());
+    }
+    Ok(seed)
+}
@@ -2,6 +2,8 @@ import { invokeChecked } from "@/composables/useIpc";
 import {
   AsrModelDownloadPayloadSchema,
   AsrModelDownloadResultSchema,
+  AsrModelRegisterPayloadSchema,
+  AsrModelRegisterResultSchema,
   AsrModelRemovePayloadSchema,
   AsrModelsListSchema,
   AsrModelVerifyPayloadSchema,
@@ -17,6 +19,9 @@ import {
   TranscriptV1Schema,
   TranscribeAudioPayloadSchema,
   TranscribeResponseSchema,
+  TranscriptionCancelPayloadSchema,
+  TranscriptionJobsListPayloadSchema,
+  TranscriptionJobsListResponseSchema,
   VoidResponseSchema,
 } from "@/schemas/ipc";
 
@@ -64,6 +69,18 @@ export async function asrModelDownload(modelId: string) {
   );
 }
 
+/**
+ * Implements asr model register behavior.
+ */
+export async function asrModelRegister(payload: { path: string; sha256: string; label?: string }) {
+  return invokeChecked(
+    "asr_model_register",
+    AsrModelRegisterPayloadSchema,
+    AsrModelRegisterResultSchema,
+    payload
+  );
+}
+
 /**
  * Implements transcribe audio behavior.
  */
@@ -84,6 +101,30 @@ export async function transcribeAudio(payload: {
   );
 }
 
+/**
+ * Implements transcription cancel behavior.
+ */
+export async function transcriptionCancel(jobId: string) {
+  return invokeChecked(
+    "transcription_cancel",
+    TranscriptionCancelPayloadSchema,
+    VoidResponseSchema,
+    { jobId }
+  );
+}
+
+/**
+ * Implements transcription jobs list behavior.
+ */
+export async function transcriptionJobsList(profileId?: string) {
+  return invokeChecked(
+    "transcription_jobs_list",
+    TranscriptionJobsListPayloadSchema,
+    TranscriptionJobsListResponseSchema,
+    profileId ? { profileId } : {}
+  );
+}
+
 /**
  * Implements transcript get behavior.
  */
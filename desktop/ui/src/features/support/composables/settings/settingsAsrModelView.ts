@@ -12,10 +12,12 @@ type Translate = (key: string) => string;
 export function createAsrModelView(t: Translate, state: AsrModelState) {
   const modelOptions = computed(() =>
     state.models.value.map((model) => {
-      const label =
-        model.id === "tiny"
-          ? t("settings.transcription.model_tiny")
-          : t("settings.transcription.model_base");
+      let label = model.label;
+      if (model.id === "tiny") {
+        label = t("settings.transcription.model_tiny");
+      } else if (model.id === "base") {
+        label = t("settings.transcription.model_base");
+      }
       let statusKey = "settings.transcription.model_status_missing";
       if (model.checksum_ok === false) {
         statusKey = "settings.transcription.model_status_invalid";
@@ -30,9 +32,9 @@ export function createAsrModelView(t: Translate, state: AsrModelState) {
         id: model.id,
         label,
         installed: model.installed,
-        expectedBytes: model.expected_bytes,
-        checksum: model.expected_sha256,
-        sourceUrl: model.source_url,
+        expectedBytes: model.expected_bytes ?? model.size_bytes ?? 0,
+        checksum: model.expected_sha256 ?? "",
+        sourceUrl: model.source_url ?? "",
         checksumOk: model.checksum_ok,
         status: t(statusKey),
       };
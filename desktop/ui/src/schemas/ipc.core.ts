@@ -13,6 +13,19 @@ export const AsrSidecarDependenciesSchema = z
     ggml: z.string().min(1),
   })
   .strict();
+export const AsrSidecarWorkerStatusSchema = z
+  .object({
+    modelId: z.string().min(1),
+    language: z.string().min(1),
+    state: z.enum(["busy", "warm", "stopped"]),
+    pid: z.number().int().nonnegative().optional().nullable(),
+    queuedJobs: z.number().int().nonnegative(),
+    jobsCompleted: z.number().int().nonnegative(),
+    restarts: z.number().int().nonnegative(),
+    idleMs: z.number().int().nonnegative().optional().nullable(),
+    lastError: OptionalString,
+  })
+  .strict();
 
 export const AsrSidecarStatusResponseSchema = z
   .object({
@@ -26,6 +39,7 @@ export const AsrSidecarStatusResponseSchema = z
     gitCommit: z.string().min(1).optional().nullable(),
     capabilities: z.array(z.string().min(1)),
     dependencies: AsrSidecarDependenciesSchema,
+    workers: z.array(AsrSidecarWorkerStatusSchema).optional(),
   })
   .strict();
 export const AsrDiagnosticsExportResponseSchema = z
@@ -191,6 +205,12 @@ export const PackInspectResponseSchema = z.object({
   projectId: IdSchema,
   runId: IdSchema,
   durationMs: z.number(),
+  markers: z.array(
+    z.object({
+      t_ms: z.number().int().nonnegative(),
+      label: z.string().min(1),
+    }),
+  ),
   reviewerTag: z.string().min(1).nullable(),
   files: z.array(PackFileSummarySchema),
 });
@@ -24,6 +24,60 @@ export const AudioTrimResponseSchema = AudioSaveResponseSchema.extend({
   durationMs: z.number().int().nonnegative(),
 });
 
+const AudioEditRangeSchema = z
+  .object({
+    startMs: z.number().int().nonnegative(),
+    endMs: z.number().int().nonnegative(),
+  })
+  .strict()
+  .refine((value) => value.endMs > value.startMs, {
+    message: "endMs must be greater than startMs",
+  });
+
+export const AudioEditPayloadSchema = z
+  .object({
+    profileId: IdSchema,
+    sources: z
+      .array(
+        z
+          .object({
+            audioArtifactId: IdSchema,
+            startMs: z.number().int().nonnegative().optional(),
+            endMs: z.number().int().nonnegative().optional(),
+            cuts: z.array(AudioEditRangeSchema).optional(),
+            transcriptId: IdSchema.optional(),
+          })
+          .strict(),
+      )
+      .min(1),
+  })
+  .strict();
+
+export const AudioEditResponseSchema = AudioSaveResponseSchema.extend({
+  durationMs: z.number().int().nonnegative(),
+  transcriptId: IdSchema.nullable(),
+  runId: IdSchema.nullable(),
+});
+
+export const AudioWaveformPeaksPayloadSchema = z
+  .object({
+    profileId: IdSchema,
+    audioArtifactId: IdSchema,
+    startMs: z.number().int().nonnegative().optional(),
+    endMs: z.number().int().nonnegative().optional(),
+    bins: z.number().int().min(1).max(8192),
+  })
+  .strict();
+
+export const AudioWaveformPeaksResponseSchema = z.object({
+  audioArtifactId: IdSchema,
+  startMs: z.number().int().nonnegative(),
+  endMs: z.number().int().nonnegative(),
+  durationMs: z.number().int().nonnegative(),
+  binMs: z.number().positive(),
+  peaks: z.array(z.number().min(0).max(1)),
+});
+
 export const AudioRevealWavPayloadSchema = z.object({
   path: z.string().min(1),
 });
@@ -35,9 +89,15 @@ export const SecurityProbeFsPayloadSchema = z.object({
 export const SecurityProbeFsResponseSchema = z.string().min(1);
 export const SecurityPrepareAppdataFileResponseSchema = z.string().min(1);
 
+export const AsrModelIdSchema = z
+  .string()
+  .min(1)
+  .max(64)
+  .regex(/^[a-z0-9][a-z0-9.-]*$/);
+
 export const RecordingAsrSettingsSchema = z
   .object({
-    model: z.enum(["tiny", "base"]).optional(),
+    model: AsrModelIdSchema.optional(),
     mode: z.enum(["auto", "live+final", "final-only"]).optional(),
     language: z.enum(["auto", "en", "fr"]).optional(),
   })
@@ -45,7 +105,7 @@ export const RecordingAsrSettingsSchema = z
 
 export const TranscribeAsrSettingsSchema = z
   .object({
-    model: z.enum(["tiny", "base"]).optional(),
+    model: AsrModelIdSchema.optional(),
     language: z.enum(["auto", "en", "fr"]).optional(),
     spokenPunctuation: z.boolean().optional(),
   })
@@ -53,18 +113,31 @@ export const TranscribeAsrSettingsSchema = z
 
 export const AsrSettingsSchema = z
   .object({
-  model: z.enum(["tiny", "base"]).optional(),
+  model: AsrModelIdSchema.optional(),
   mode: z.enum(["auto", "live+final", "final-only"]).optional(),
   language: z.enum(["auto", "en", "fr"]).optional(),
   spokenPunctuation: z.boolean().optional(),
   })
   .strict();
 
+export const RecordingDspSettingsSchema = z
+  .object({
+    preset: z.enum(["voice", "raw", "broadcast"]).optional(),
+    highPass: z.boolean().optional(),
+    noiseGate: z.boolean().optional(),
+    agc: z.boolean().optional(),
+    limiter: z.boolean().optional(),
+    resampler: z.enum(["linear", "sinc"]).optional(),
+    keepRaw: z.boolean().optional(),
+  })
+  .strict();
+
 export const RecordingStartPayloadSchema = z
   .object({
     profileId: IdSchema,
     asrSettings: RecordingAsrSettingsSchema.optional(),
     inputDeviceId: z.string().min(1).optional().nullable(),
+    dspSettings: RecordingDspSettingsSchema.optional(),
   })
   .strict();
 
@@ -111,6 +184,18 @@ export const RecordingResumePayloadSchema = z.object({
   recordingId: IdSchema,
 });
 
+export const RecordingMarkPayloadSchema = z
+  .object({
+    recordingId: IdSchema,
+    label: z.string().max(80).optional(),
+  })
+  .strict();
+
+export const RecordingMarkResponseSchema = z.object({
+  t_ms: z.number().int().nonnegative(),
+  label: z.string().min(1),
+});
+
 export const RecordingStopPayloadSchema = z.object({
   profileId: IdSchema,
   recordingId: IdSchema,
@@ -118,6 +203,62 @@ export const RecordingStopPayloadSchema = z.object({
 
 export const RecordingStopResponseSchema = AudioSaveResponseSchema.extend({
   durationMs: z.number().int().nonnegative(),
+  rawArtifactId: IdSchema.nullable().optional(),
+});
+
+export const AudioStorageMigratePayloadSchema = z.object({
+  profileId: IdSchema,
+  format: z.enum(["wav", "flac"]).optional(),
+});
+
+export const AudioStorageMigrationResponseSchema = z.object({
+  format: z.enum(["wav", "flac"]),
+  converted: z.number().int().nonnegative(),
+  skipped: z.number().int().nonnegative(),
+  failed: z.array(z.string()),
+  bytesBefore: z.number().int().nonnegative(),
+  bytesAfter: z.number().int().nonnegative(),
+});
+
+export const AudioStorageMigrationProgressEventSchema = z.object({
+  schemaVersion: z.literal("1.0.0"),
+  profileId: IdSchema,
+  processed: z.number().int().nonnegative(),
+  total: z.number().int().nonnegative(),
+});
+
+export const AudioImportPayloadSchema = z.object({
+  profileId: IdSchema,
+  path: z.string().min(1),
+  projectId: IdSchema,
+  questCode: z.string().min(1).optional(),
+  resampler: z.enum(["linear", "sinc"]).optional(),
+});
+
+export const AudioImportResponseSchema = AudioSaveResponseSchema.extend({
+  durationMs: z.number().int().nonnegative(),
+  sourceFormat: z.enum(["wav", "flac", "ogg", "mp3"]),
+  runId: IdSchema.nullable(),
+  attemptId: IdSchema.nullable(),
+});
+
+export const RecordingDraftsListPayloadSchema = z.object({
+  profileId: IdSchema,
+});
+
+export const RecordingDraftSchema = z.object({
+  artifactId: IdSchema,
+  path: z.string().min(1),
+  bytes: z.number().int().nonnegative(),
+  durationMs: z.number().int().nonnegative(),
+  recordingId: IdSchema.nullable(),
+  startedAt: z.string().nullable(),
+  updatedAt: z.string().nullable(),
+});
+
+export const RecordingDraftActionPayloadSchema = z.object({
+  profileId: IdSchema,
+  artifactId: IdSchema,
 });
 
 export const TranscribeAudioPayloadSchema = z
@@ -133,11 +274,52 @@ export const TranscribeResponseSchema = z.object({
   jobId: IdSchema.optional(),
 });
 
+export const TranscriptionCancelPayloadSchema = z
+  .object({
+    jobId: IdSchema,
+  })
+  .strict();
+
+export const TranscriptionJobsListPayloadSchema = z
+  .object({
+    profileId: IdSchema.optional(),
+  })
+  .strict();
+
+export const TranscriptionJobStatusSchema = z.object({
+  jobId: IdSchema,
+  profileId: IdSchema,
+  audioArtifactId: IdSchema,
+  modelId: z.string().min(1).optional().nullable(),
+  language: z.string().min(1).optional().nullable(),
+  status: z.enum(["queued", "running", "completed", "failed", "cancelled"]),
+  transcriptId: IdSchema.optional().nullable(),
+  errorCode: z.string().min(1).optional().nullable(),
+  interruptedCount: z.number().int().nonnegative(),
+  createdAt: z.string().min(1),
+  updatedAt: z.string().min(1),
+});
+
+export const TranscriptionJobsListResponseSchema = z.array(TranscriptionJobStatusSchema);
+
+export const TranscriptWordSchema = z.object({
+  t_start_ms: z.number().int().nonnegative(),
+  t_end_ms: z.number().int().nonnegative(),
+  text: z.string().min(1),
+  confidence: z.number().min(0).max(1).optional().nullable(),
+});
+
 export const TranscriptSegmentSchema = z.object({
   t_start_ms: z.number().int().nonnegative(),
   t_end_ms: z.number().int().nonnegative(),
   text: z.string().min(1),
   confidence: z.number().min(0).max(1).optional().nullable(),
+  words: z.array(TranscriptWordSchema).optional(),
+});
+
+export const RecordingMarkerSchema = z.object({
+  t_ms: z.number().int().nonnegative(),
+  label: z.string().min(1),
 });
 
 export const TranscriptV1Schema = z.object({
@@ -146,6 +328,7 @@ export const TranscriptV1Schema = z.object({
   model_id: z.string().min(1).optional().nullable(),
   duration_ms: z.number().int().nonnegative().optional().nullable(),
   segments: z.array(TranscriptSegmentSchema).min(1),
+  markers: z.array(RecordingMarkerSchema).optional(),
 });
 
 export const AsrPartialEventSchema = z.object({
@@ -212,9 +395,11 @@ export const AsrModelStatusSchema = z.object({
   label: z.string().min(1),
   bundled: z.boolean(),
   installed: z.boolean(),
-  expected_bytes: z.number().nonnegative(),
-  expected_sha256: z.string().min(1),
-  source_url: z.string().min(1),
+  expected_bytes: z.number().nonnegative().optional().nullable(),
+  expected_sha256: z.string().min(1).optional().nullable(),
+  source_url: z.string().min(1).optional().nullable(),
+  english_only: z.boolean().optional(),
+  custom: z.boolean().optional(),
   path: z.string().min(1).optional().nullable(),
   size_bytes: z.number().nonnegative().optional().nullable(),
   checksum_ok: z.boolean().optional().nullable(),
@@ -236,6 +421,16 @@ export const AsrModelVerifyPayloadSchema = z.object({
 
 export const AsrModelVerifyResultSchema = AsrModelStatusSchema;
 
+export const AsrModelRegisterPayloadSchema = z
+  .object({
+    path: z.string().min(1),
+    sha256: z.string().regex(/^[0-9a-fA-F]{64}$/),
+    label: z.string().min(1).optional(),
+  })
+  .strict();
+
+export const AsrModelRegisterResultSchema = AsrModelStatusSchema;
+
 export const AsrModelDownloadResultSchema = z.object({
   modelId: z.string().min(1),
   path: z.string().min(1),
@@ -326,6 +521,79 @@ export const FeedbackCommentSchema = z.object({
   suggestion: z.string().min(1),
 });
 
+export const PauseHistogramBinSchema = z.object({
+  min_ms: z.number().int().nonnegative(),
+  max_ms: z.number().int().nonnegative().nullable(),
+  count: z.number().int().nonnegative(),
+});
+
+export const PauseMetricsSchema = z.object({
+  source: z.enum(["audio", "segments"]),
+  count: z.number().int().nonnegative(),
+  mean_ms: z.number().nonnegative(),
+  max_ms: z.number().int().nonnegative(),
+  total_ms: z.number().int().nonnegative(),
+  histogram: z.array(PauseHistogramBinSchema),
+});
+
+export const PacePointSchema = z.object({
+  t_start_ms: z.number().int().nonnegative(),
+  t_end_ms: z.number().int().nonnegative(),
+  wpm: z.number().nonnegative(),
+});
+
+export const PaceMetricsSchema = z.object({
+  window_ms: z.number().int().positive(),
+  step_ms: z.number().int().positive(),
+  baseline_wpm: z.number().nonnegative(),
+  points: z.array(PacePointSchema),
+});
+
+export const DurationMetricsSchema = z.object({
+  target_sec: z.number().int().positive(),
+  actual_sec: z.number().int().nonnegative(),
+  projected_sec: z.number().int().nonnegative(),
+  delta_sec: z.number().int(),
+  status: z.enum(["on_target", "overrun", "underrun"]),
+});
+
+export const OutlineSectionCoverageSchema = z.object({
+  title: z.string().min(1),
+  covered: z.boolean(),
+  t_start_ms: z.number().int().nonnegative().nullable(),
+  duration_ms: z.number().int().nonnegative(),
+  bullets_total: z.number().int().nonnegative(),
+  bullets_covered: z.number().int().nonnegative(),
+});
+
+export const OutlineCoverageSchema = z.object({
+  sections: z.array(OutlineSectionCoverageSchema),
+  covered_count: z.number().int().nonnegative(),
+  coverage_ratio: z.number().min(0).max(1),
+  skipped: z.array(z.string()),
+  reordered: z.array(z.string()),
+});
+
+export const LoudnessMetricsSchema = z.object({
+  speech_mean_db: z.number(),
+  variation_db: z.number().nonnegative().nullable().optional(),
+  dynamic_range_db: z.number(),
+  monotone: z.boolean(),
+  trailing_off_count: z.number().int().nonnegative(),
+  clipping_count: z.number().int().nonnegative(),
+  envelope_artifact_id: z.string().nullable().optional(),
+});
+
+export const PitchMetricsSchema = z.object({
+  pitch_artifact_id: z.string().nullable(),
+  median_hz: z.number().nonnegative(),
+  range_st: z.number().nonnegative(),
+  variability_st: z.number().nonnegative(),
+  voiced_ratio: z.number().min(0).max(1),
+  flat_stretch_count: z.number().int().nonnegative(),
+  flat_ratio: z.number().min(0).max(1),
+});
+
 export const FeedbackMetricsSchema = z.object({
   wpm: z.number().nonnegative(),
   filler_per_min: z.number().nonnegative(),
@@ -334,14 +602,99 @@ export const FeedbackMetricsSchema = z.object({
   repeat_terms: z.array(z.string().min(1)).max(10),
   jargon_terms: z.array(z.string().min(1)).optional().nullable(),
   density_score: z.number().nonnegative().optional().nullable(),
+  pauses: PauseMetricsSchema.optional().nullable(),
+  pace: PaceMetricsSchema.optional().nullable(),
+  duration: DurationMetricsSchema.optional().nullable(),
+  outline: OutlineCoverageSchema.optional().nullable(),
+  loudness: LoudnessMetricsSchema.optional().nullable(),
+  pitch: PitchMetricsSchema.optional().nullable(),
 });
 
 export const FeedbackV1Schema = z.object({
-  schema_version: z.literal("1.0.0"),
+  schema_version: z.enum(["1.0.0", "1.1.0"]),
   overall_score: z.number().int().min(0).max(100),
   top_actions: z.array(FeedbackActionSchema).max(2),
   comments: z.array(FeedbackCommentSchema).max(7),
   metrics: FeedbackMetricsSchema,
+  analyzers: z.array(z.string()).optional(),
+  markers: z.array(RecordingMarkerSchema).optional(),
+});
+
+export const FeedbackAnalyzerStatusSchema = z.object({
+  id: z.string(),
+  enabled: z.boolean(),
+});
+
+export const FeedbackAnalyzerListResponseSchema = z.array(FeedbackAnalyzerStatusSchema);
+
+export const FeedbackRevisionSchema = z.object({
+  feedback_id: z.string(),
+  revision: z.number().int().min(1),
+  created_at: z.string(),
+  overall_score: z.number().int(),
+  is_current: z.boolean(),
 });
 
+export const FeedbackRevisionListResponseSchema = z.array(FeedbackRevisionSchema);
+
+export const MetricDeltaSchema = z.object({
+  metric: z.string(),
+  base: z.number().nullable(),
+  target: z.number().nullable(),
+  delta: z.number().nullable(),
+  verdict: z.enum(["improved", "regressed", "unchanged", "changed", "unavailable"]),
+});
+
+export const TermsDeltaSchema = z.object({
+  added: z.array(z.string()),
+  removed: z.array(z.string()),
+});
+
+export const FeedbackRevisionDiffSchema = z.object({
+  base_feedback_id: z.string(),
+  target_feedback_id: z.string(),
+  metrics: z.array(MetricDeltaSchema),
+  repeat_terms: TermsDeltaSchema,
+  jargon_terms: TermsDeltaSchema,
+  top_actions: TermsDeltaSchema,
+});
+
+export const ComparedSubjectSchema = z.object({
+  subject_id: z.string(),
+  created_at: z.string(),
+  feedback_id: z.string(),
+  overall_score: z.number().int(),
+  duration_sec: z.number().int().nullable(),
+});
+
+export const MetricComparisonSchema = z.object({
+  metric: z.string(),
+  values: z.array(z.number().nullable()),
+  deltas: z.array(z.number().nullable()),
+  delta: z.number().nullable(),
+  verdict: z.enum(["improved", "regressed", "unchanged", "changed", "unavailable"]),
+});
+
+export const OutlineSectionComparisonSchema = z.object({
+  title: z.string(),
+  covered: z.array(z.boolean().nullable()),
+});
+
+export const FeedbackComparisonSchema = z.object({
+  subject_type: z.enum(["run", "quest_attempt"]),
+  project_id: z.string(),
+  subjects: z.array(ComparedSubjectSchema).min(2),
+  metrics: z.array(MetricComparisonSchema),
+  outline_sections: z.array(OutlineSectionComparisonSchema),
+});
+
+export const FeedbackLexiconTermSchema = z.object({
+  language: z.string(),
+  kind: z.enum(["filler", "stopword", "jargon_ignore"]),
+  term: z.string(),
+  created_at: z.string(),
+});
+
+export const FeedbackLexiconListResponseSchema = z.array(FeedbackLexiconTermSchema);
+
 
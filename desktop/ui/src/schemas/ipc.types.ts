@@ -1,6 +1,6 @@
 import { z } from "zod";
 import { ExportResultSchema, MascotMessageSchema, OutlineDocSchema, PackInspectResponseSchema, PeerReviewDetailSchema, PeerReviewSummarySchema, ProfileSummarySchema, ProgressSnapshotSchema, ProjectListItemSchema, ProjectSummarySchema, ProjectUpdatePayloadSchema, QuestAttemptSummarySchema, QuestDailySchema, QuestReportItemSchema, QuestSchema, RunSummarySchema, TalksBlueprintSchema } from "./ipc.core";
-import { AsrCommitEventSchema, AsrFinalProgressEventSchema, AsrFinalResultEventSchema, AsrModelDownloadProgressEventSchema, AsrModelDownloadResultSchema, AsrModelStatusSchema, AsrPartialEventSchema, FeedbackContextSchema, FeedbackTimelineItemSchema, FeedbackV1Schema, RecordingInputDeviceSchema, RecordingStartResponseSchema, RecordingStatusResponseSchema, RecordingStopResponseSchema, RecordingTelemetryBudgetResponseSchema, RecordingTelemetryEventSchema, TranscriptEditSaveResponseSchema, TranscriptExportFormatSchema, TranscriptSegmentSchema, TranscriptV1Schema } from "./ipc.runtime";
+import { AsrCommitEventSchema, AsrFinalProgressEventSchema, AsrFinalResultEventSchema, AsrModelDownloadProgressEventSchema, AsrModelDownloadResultSchema, AsrModelStatusSchema, AsrPartialEventSchema, FeedbackContextSchema, FeedbackTimelineItemSchema, FeedbackV1Schema, RecordingInputDeviceSchema, RecordingStartResponseSchema, RecordingStatusResponseSchema, RecordingStopResponseSchema, RecordingTelemetryBudgetResponseSchema, RecordingTelemetryEventSchema, TranscriptEditSaveResponseSchema, TranscriptExportFormatSchema, TranscriptSegmentSchema, TranscriptV1Schema, TranscriptWordSchema } from "./ipc.runtime";
 export type AsrPartialEvent = z.infer<typeof AsrPartialEventSchema>;
 export type AsrCommitEvent = z.infer<typeof AsrCommitEventSchema>;
 export type AsrFinalProgressEvent = z.infer<typeof AsrFinalProgressEventSchema>;
@@ -32,6 +32,7 @@ export type TalksBlueprint = z.infer<typeof TalksBlueprintSchema>;
 export type QuestAttemptSummary = z.infer<typeof QuestAttemptSummarySchema>;
 export type QuestReportItem = z.infer<typeof QuestReportItemSchema>;
 export type TranscriptSegment = z.infer<typeof TranscriptSegmentSchema>;
+export type TranscriptWord = z.infer<typeof TranscriptWordSchema>;
 export type TranscriptV1 = z.infer<typeof TranscriptV1Schema>;
 export type TranscriptExportFormat = z.infer<typeof TranscriptExportFormatSchema>;
 export type AsrModelStatus = z.infer<typeof AsrModelStatusSchema>;
@@ -0,0 +1,15 @@
+CREATE TABLE IF NOT EXISTS transcription_jobs (
+  id TEXT PRIMARY KEY,
+  profile_id TEXT NOT NULL,
+  audio_artifact_id TEXT NOT NULL,
+  settings_json TEXT NOT NULL,
+  status TEXT NOT NULL,
+  transcript_id TEXT,
+  error_code TEXT,
+  interrupted_count INTEGER NOT NULL DEFAULT 0,
+  created_at TEXT NOT NULL,
+  updated_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_transcription_jobs_status
+  ON transcription_jobs (status, created_at);